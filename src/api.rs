@@ -14,9 +14,11 @@
 //! - [x] async support
 //!
 //! Potential breaking changes ahead
-//! - [ ] use Enum for sort column
+//! - [x] use Enum for sort column
 //! - [ ] typed ids
+//!   - [x] issue endpoints (IssueId, ProjectId, UserId)
 //! - [ ] change project_id_or_name to Enum
+//!   - [x] project endpoints (ProjectIdentifier)
 //! - [ ] extra filter expressions I overlooked/did not know about
 //! - [ ] parameters that are more flexible than they appear
 
@@ -55,10 +57,17 @@ use serde::Serialize;
 use reqwest::Method;
 use std::borrow::Cow;
 
+use futures::StreamExt;
 use reqwest::Url;
-use tracing::{debug, error, trace};
+use tracing::{debug, error, trace, warn};
 
 /// main API client object (sync)
+///
+/// all requests of one [Redmine] go through the same blocking reqwest client
+/// which owns a single background runtime and a connection pool, so
+/// sequential requests like the pages of
+/// [Redmine::json_response_body_all_pages] reuse the keep-alive connection.
+/// Create the [Redmine] once and share it instead of creating one per request.
 #[derive(derive_more::Debug)]
 pub struct Redmine {
     /// the reqwest client we use to perform our API requests
@@ -68,10 +77,43 @@ pub struct Redmine {
     /// a redmine API key, usually 40 hex digits where the letters (a-f) are lower case
     #[debug(skip)]
     api_key: String,
-    /// the user id we want to impersonate, only works if the API key we use has admin privileges
-    impersonate_user_id: Option<u64>,
+    /// the user we want to impersonate, only works if the API key we use has admin privileges
+    impersonation: Option<Impersonation>,
+    /// the policy for retrying requests that failed with a transient error status, if any
+    retry_policy: Option<RetryPolicy>,
+    /// the timeout for requests to Redmine, overrides the timeout of the client if set
+    timeout: Option<std::time::Duration>,
+    /// additional headers sent with every request
+    extra_headers: reqwest::header::HeaderMap,
+    /// the issue statuses, fetched on first use by the status name helpers
+    issue_statuses: std::sync::OnceLock<Vec<issue_statuses::IssueStatusEssentials>>,
+}
+
+/// the headers set by the client itself which extra headers must not replace
+const RESERVED_HEADERS: [&str; 2] = ["x-redmine-api-key", "x-redmine-switch-user"];
+
+/// helper to add an extra header unless it would replace one of the
+/// headers set by the client itself
+fn add_extra_header(
+    extra_headers: &mut reqwest::header::HeaderMap,
+    name: reqwest::header::HeaderName,
+    value: reqwest::header::HeaderValue,
+) {
+    if RESERVED_HEADERS.contains(&name.as_str()) {
+        warn!(%name, "Ignoring extra header which would replace a header set by the client");
+        return;
+    }
+    extra_headers.insert(name, value);
 }
 
+/// the maximum number of concurrent requests sent by the batch helpers of
+/// [RedmineAsync] (e.g. [RedmineAsync::create_issues])
+pub const MAX_CONCURRENT_REQUESTS: usize = 8;
+
+/// the page size used by [Redmine::count] and [RedmineAsync::count],
+/// Redmine replaces a limit of 0 with its default page size
+const COUNT_LIMIT: u64 = 1;
+
 /// main API client object (async)
 #[derive(derive_more::Debug)]
 pub struct RedmineAsync {
@@ -82,8 +124,16 @@ pub struct RedmineAsync {
     /// a redmine API key, usually 40 hex digits where the letters (a-f) are lower case
     #[debug(skip)]
     api_key: String,
-    /// the user id we want to impersonate, only works if the API key we use has admin privileges
-    impersonate_user_id: Option<u64>,
+    /// the user we want to impersonate, only works if the API key we use has admin privileges
+    impersonation: Option<Impersonation>,
+    /// the policy for retrying requests that failed with a transient error status, if any
+    retry_policy: Option<RetryPolicy>,
+    /// the timeout for requests to Redmine, overrides the timeout of the client if set
+    timeout: Option<std::time::Duration>,
+    /// additional headers sent with every request
+    extra_headers: reqwest::header::HeaderMap,
+    /// the issue statuses, fetched on first use by the status name helpers
+    issue_statuses: std::sync::OnceLock<Vec<issue_statuses::IssueStatusEssentials>>,
 }
 
 /// helper function to parse the redmine URL in the environment variable
@@ -107,6 +157,125 @@ struct EnvOptions {
     redmine_url: url::Url,
 }
 
+/// the user to impersonate in API calls, sent as the X-Redmine-Switch-User header
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Impersonation {
+    /// impersonate the user with this id
+    UserId(u64),
+    /// impersonate the user with this login name
+    Login(String),
+}
+
+impl std::fmt::Display for Impersonation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Impersonation::UserId(id) => {
+                write!(f, "{}", id)
+            }
+            Impersonation::Login(login) => {
+                write!(f, "{}", login)
+            }
+        }
+    }
+}
+
+/// Policy for automatically retrying requests which failed with a transient
+/// error status (429 Too Many Requests, 502 Bad Gateway, 503 Service Unavailable
+/// or 504 Gateway Timeout)
+///
+/// 502 and 504 are only retried for idempotent methods (GET, HEAD, PUT, DELETE)
+/// and requests with an Idempotency-Key header (see the idempotency_key of the
+/// create endpoints) since a proxy returning those might have forwarded the
+/// request already and retrying a POST could e.g. create an issue twice
+///
+/// other server errors (e.g. 500) are not retried since the request might have
+/// been partially processed already
+///
+/// if the response contains a Retry-After header that delay is used instead of
+/// the computed backoff, either way the delay is capped at max_delay
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// the maximum number of attempts, including the first one
+    pub max_attempts: u32,
+    /// the delay before the first retry
+    pub base_delay: std::time::Duration,
+    /// the factor the delay is multiplied with for each further retry
+    /// (1 for a constant delay, 2 for exponential backoff)
+    pub backoff_factor: u32,
+    /// the longest delay to wait before a retry, this also limits the delay
+    /// requested by the server in a Retry-After header
+    pub max_delay: std::time::Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: std::time::Duration::from_secs(1),
+            backoff_factor: 2,
+            max_delay: std::time::Duration::from_secs(60),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// returns the delay before the next attempt if the request with the given
+    /// method and headers should be retried after the given (1-based) attempt
+    /// returned the given status
+    fn retry_delay(
+        &self,
+        attempt: u32,
+        method: &reqwest::Method,
+        headers: &reqwest::header::HeaderMap,
+        status: reqwest::StatusCode,
+        retry_after: Option<std::time::Duration>,
+    ) -> Option<std::time::Duration> {
+        let idempotent = matches!(
+            *method,
+            reqwest::Method::GET
+                | reqwest::Method::HEAD
+                | reqwest::Method::PUT
+                | reqwest::Method::DELETE
+        ) || headers.contains_key(IDEMPOTENCY_KEY_HEADER);
+        let transient = match status {
+            reqwest::StatusCode::TOO_MANY_REQUESTS | reqwest::StatusCode::SERVICE_UNAVAILABLE => {
+                true
+            }
+            reqwest::StatusCode::BAD_GATEWAY | reqwest::StatusCode::GATEWAY_TIMEOUT => idempotent,
+            _ => false,
+        };
+        if !transient || attempt >= self.max_attempts {
+            return None;
+        }
+        let delay = retry_after.unwrap_or_else(|| {
+            self.base_delay
+                .saturating_mul(self.backoff_factor.saturating_pow(attempt - 1))
+        });
+        Some(delay.min(self.max_delay))
+    }
+}
+
+/// helper to parse the Retry-After header which can either contain a number
+/// of seconds or an HTTP date
+fn parse_retry_after(headers: &reqwest::header::HeaderMap) -> Option<std::time::Duration> {
+    let value = headers
+        .get(reqwest::header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(std::time::Duration::from_secs(seconds));
+    }
+    let date =
+        time::OffsetDateTime::parse(value, &time::format_description::well_known::Rfc2822).ok()?;
+    // a date in the past means we can retry right away
+    Some(
+        (date - time::OffsetDateTime::now_utc())
+            .try_into()
+            .unwrap_or_default(),
+    )
+}
+
 /// Return value from paged requests, includes the actual value as well as
 /// pagination data
 #[derive(Debug, Clone)]
@@ -121,6 +290,254 @@ pub struct ResponsePage<T> {
     pub limit: u64,
 }
 
+impl<T> ResponsePage<T> {
+    /// returns true if there are more values after this page
+    #[must_use]
+    pub fn has_next_page(&self) -> bool {
+        self.limit > 0 && self.offset + self.limit < self.total_count
+    }
+
+    /// returns the offset of the next page if there is one
+    #[must_use]
+    pub fn next_offset(&self) -> Option<u64> {
+        if self.has_next_page() {
+            Some(self.offset + self.limit)
+        } else {
+            None
+        }
+    }
+
+    /// returns the number of pages of this size needed for all values,
+    /// zero if the limit is zero
+    #[must_use]
+    pub fn page_count(&self) -> u64 {
+        if self.limit == 0 {
+            0
+        } else {
+            self.total_count.div_ceil(self.limit)
+        }
+    }
+
+    /// returns the cursor for the next page if there is one, it uses the
+    /// limit Redmine reported for this page
+    #[must_use]
+    pub fn next_cursor(&self) -> Option<PageCursor> {
+        self.next_offset().map(|offset| PageCursor {
+            offset,
+            limit: self.limit,
+        })
+    }
+}
+
+/// the position of a page in a paginated response, can be persisted to
+/// resume fetching pages later, e.g. for incremental syncs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct PageCursor {
+    /// The offset from the start (zero-based)
+    pub offset: u64,
+    /// How many entries to request
+    pub limit: u64,
+}
+
+impl PageCursor {
+    /// a cursor for the first page with the given limit
+    #[must_use]
+    pub fn first(limit: u64) -> Self {
+        Self { offset: 0, limit }
+    }
+}
+
+/// metadata of a response returned along with the parsed body by
+/// [Redmine::json_response_with_meta] and [RedmineAsync::json_response_with_meta]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResponseMeta {
+    /// the HTTP status code
+    pub status: reqwest::StatusCode,
+    /// the ETag header, can be used for conditional requests
+    pub etag: Option<reqwest::header::HeaderValue>,
+    /// the Last-Modified header, can be used for conditional requests
+    pub last_modified: Option<reqwest::header::HeaderValue>,
+}
+
+impl ResponseMeta {
+    /// picks the metadata we keep from the response status and headers
+    fn new(status: reqwest::StatusCode, headers: &reqwest::header::HeaderMap) -> Self {
+        Self {
+            status,
+            etag: headers.get(reqwest::header::ETAG).cloned(),
+            last_modified: headers.get(reqwest::header::LAST_MODIFIED).cloned(),
+        }
+    }
+}
+
+/// the validators of an earlier response to send with a conditional request
+/// via [Redmine::json_response_body_conditional] or
+/// [RedmineAsync::json_response_body_conditional]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConditionalRequest {
+    /// sent as If-None-Match, usually the ETag of the earlier response
+    pub if_none_match: Option<reqwest::header::HeaderValue>,
+    /// sent as If-Modified-Since, usually the Last-Modified of the earlier response
+    pub if_modified_since: Option<reqwest::header::HeaderValue>,
+}
+
+impl From<&ResponseMeta> for ConditionalRequest {
+    fn from(meta: &ResponseMeta) -> Self {
+        Self {
+            if_none_match: meta.etag.clone(),
+            if_modified_since: meta.last_modified.clone(),
+        }
+    }
+}
+
+impl ConditionalRequest {
+    /// the request headers for the validators which are set
+    fn headers(&self) -> reqwest::header::HeaderMap {
+        let mut headers = reqwest::header::HeaderMap::new();
+        if let Some(if_none_match) = &self.if_none_match {
+            headers.insert(reqwest::header::IF_NONE_MATCH, if_none_match.clone());
+        }
+        if let Some(if_modified_since) = &self.if_modified_since {
+            headers.insert(
+                reqwest::header::IF_MODIFIED_SINCE,
+                if_modified_since.clone(),
+            );
+        }
+        headers
+    }
+}
+
+/// builder for [Redmine] objects
+#[derive(derive_more::Debug)]
+pub struct RedmineBuilder {
+    /// the reqwest client to use, if none is set a default client is created
+    client: Option<reqwest::blocking::Client>,
+    /// the redmine base url
+    redmine_url: Url,
+    /// a redmine API key, usually 40 hex digits where the letters (a-f) are lower case
+    #[debug(skip)]
+    api_key: String,
+    /// the user we want to impersonate, only works if the API key we use has admin privileges
+    impersonation: Option<Impersonation>,
+    /// the policy for retrying requests that failed with a transient error status, if any
+    retry_policy: Option<RetryPolicy>,
+    /// the timeout for requests to Redmine, overrides the timeout of the client if set
+    timeout: Option<std::time::Duration>,
+    /// additional headers sent with every request
+    extra_headers: reqwest::header::HeaderMap,
+}
+
+impl RedmineBuilder {
+    /// create a builder for a [Redmine] object with the given base URL and API key
+    #[must_use]
+    pub fn new(redmine_url: url::Url, api_key: &str) -> Self {
+        Self {
+            client: None,
+            redmine_url,
+            api_key: api_key.to_string(),
+            impersonation: None,
+            retry_policy: None,
+            timeout: None,
+            extra_headers: reqwest::header::HeaderMap::new(),
+        }
+    }
+
+    /// use this reqwest client instead of creating a default one
+    ///
+    /// responses are only decompressed if the gzip or deflate features of
+    /// reqwest are enabled and the client was not built with them disabled
+    #[must_use]
+    pub fn client(mut self, client: reqwest::blocking::Client) -> Self {
+        self.client = Some(client);
+        self
+    }
+
+    /// impersonate the user with this id in all API calls
+    ///
+    /// this requires Redmine admin privileges
+    #[must_use]
+    pub fn impersonate_user(mut self, id: u64) -> Self {
+        self.impersonation = Some(Impersonation::UserId(id));
+        self
+    }
+
+    /// impersonate the user with this login in all API calls
+    ///
+    /// this requires Redmine admin privileges
+    #[must_use]
+    pub fn impersonate_login(mut self, login: impl Into<String>) -> Self {
+        self.impersonation = Some(Impersonation::Login(login.into()));
+        self
+    }
+
+    /// retry requests that failed with a transient error status according to this policy
+    #[must_use]
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(retry_policy);
+        self
+    }
+
+    /// use this timeout for requests to Redmine instead of the one set on the client
+    #[must_use]
+    pub fn timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// send this additional header with every request
+    ///
+    /// headers which would replace the API key or impersonation headers are
+    /// ignored with a warning
+    #[must_use]
+    pub fn header(
+        mut self,
+        name: reqwest::header::HeaderName,
+        value: reqwest::header::HeaderValue,
+    ) -> Self {
+        add_extra_header(&mut self.extra_headers, name, value);
+        self
+    }
+
+    /// create the [Redmine] object
+    ///
+    /// # Errors
+    ///
+    /// This will return [`crate::Error::ReqwestError`] if no client was set and
+    /// initialization of the default Reqwest client fails.
+    pub fn build(self) -> Result<Redmine, crate::Error> {
+        let RedmineBuilder {
+            client,
+            redmine_url,
+            api_key,
+            impersonation,
+            retry_policy,
+            timeout,
+            extra_headers,
+        } = self;
+        let client = if let Some(client) = client {
+            client
+        } else {
+            #[cfg(not(feature = "rustls-tls"))]
+            let client = reqwest::blocking::Client::new();
+            #[cfg(feature = "rustls-tls")]
+            let client = reqwest::blocking::Client::builder()
+                .use_rustls_tls()
+                .build()?;
+            client
+        };
+        Ok(Redmine {
+            client,
+            redmine_url,
+            api_key,
+            impersonation,
+            retry_policy,
+            timeout,
+            extra_headers,
+            issue_statuses: std::sync::OnceLock::new(),
+        })
+    }
+}
+
 impl Redmine {
     /// create a [Redmine] object
     ///
@@ -128,19 +545,15 @@ impl Redmine {
     ///
     /// This will return [`crate::Error::ReqwestError`] if initialization of Reqwest client is failed.
     pub fn new(redmine_url: url::Url, api_key: &str) -> Result<Self, crate::Error> {
-        #[cfg(not(feature = "rustls-tls"))]
-        let client = reqwest::blocking::Client::new();
-        #[cfg(feature = "rustls-tls")]
-        let client = reqwest::blocking::Client::builder()
-            .use_rustls_tls()
-            .build()?;
+        Self::builder(redmine_url, api_key).build()
+    }
 
-        Ok(Self {
-            client,
-            redmine_url,
-            api_key: api_key.to_string(),
-            impersonate_user_id: None,
-        })
+    /// create a builder for a [Redmine] object, use this to set a custom reqwest
+    /// client, impersonation, a retry policy, a timeout or extra headers at
+    /// construction time
+    #[must_use]
+    pub fn builder(redmine_url: url::Url, api_key: &str) -> RedmineBuilder {
+        RedmineBuilder::new(redmine_url, api_key)
     }
 
     /// create a [Redmine] object from the environment variables
@@ -165,7 +578,44 @@ impl Redmine {
     ///
     /// this requires Redmine admin privileges
     pub fn impersonate_user(&mut self, id: u64) {
-        self.impersonate_user_id = Some(id);
+        self.impersonation = Some(Impersonation::UserId(id));
+    }
+
+    /// Sets the login of a user to impersonate in all future API calls
+    ///
+    /// this requires Redmine admin privileges
+    pub fn impersonate_login(&mut self, login: impl Into<String>) {
+        self.impersonation = Some(Impersonation::Login(login.into()));
+    }
+
+    /// Stops impersonating another user, all future API calls are performed
+    /// as the owner of the API key again
+    pub fn stop_impersonating(&mut self) {
+        self.impersonation = None;
+    }
+
+    /// Sets the policy used to retry requests that failed with a transient
+    /// error status (see [RetryPolicy]), None disables retries (the default)
+    pub fn set_retry_policy(&mut self, retry_policy: Option<RetryPolicy>) {
+        self.retry_policy = retry_policy;
+    }
+
+    /// Sets the timeout for requests to Redmine which overrides the timeout set
+    /// on the reqwest client, None uses the timeout of the client (the default)
+    pub fn set_timeout(&mut self, timeout: Option<std::time::Duration>) {
+        self.timeout = timeout;
+    }
+
+    /// Adds a header which is sent with every future API call
+    ///
+    /// headers which would replace the API key or impersonation headers are
+    /// ignored with a warning
+    pub fn add_header(
+        &mut self,
+        name: reqwest::header::HeaderName,
+        value: reqwest::header::HeaderValue,
+    ) {
+        add_extra_header(&mut self.extra_headers, name, value);
     }
 
     /// returns the issue URL for a given issue id
@@ -181,6 +631,57 @@ impl Redmine {
         redmine_url.join(&format!("/issues/{}", issue_id)).unwrap()
     }
 
+    /// returns the web UI URL of the project with the given identifier
+    ///
+    /// like [Redmine::issue_url] this works entirely on the client
+    #[must_use]
+    pub fn project_url(&self, identifier: &str) -> Url {
+        web_url(&self.redmine_url, &["projects", identifier])
+    }
+
+    /// returns the web UI URL of the version with the given id
+    ///
+    /// like [Redmine::issue_url] this works entirely on the client
+    #[must_use]
+    pub fn version_url(&self, version_id: u64) -> Url {
+        web_url(&self.redmine_url, &["versions", &version_id.to_string()])
+    }
+
+    /// returns the web UI URL of the user with the given id
+    ///
+    /// like [Redmine::issue_url] this works entirely on the client
+    #[must_use]
+    pub fn user_url(&self, user_id: u64) -> Url {
+        web_url(&self.redmine_url, &["users", &user_id.to_string()])
+    }
+
+    /// returns the web UI URL of the wiki page with the given title in the
+    /// project with the given identifier, characters like spaces and slashes
+    /// in the title are percent-encoded
+    ///
+    /// like [Redmine::issue_url] this works entirely on the client
+    #[must_use]
+    pub fn wiki_page_url(&self, project: &str, title: &str) -> Url {
+        web_url(&self.redmine_url, &["projects", project, "wiki", title])
+    }
+
+    /// internal helper to create a request with the API key, impersonation
+    /// and extra headers and the timeout set
+    fn request(&self, method: reqwest::Method, url: Url) -> reqwest::blocking::RequestBuilder {
+        let mut req = self
+            .client
+            .request(method, url)
+            .headers(self.extra_headers.clone())
+            .header("x-redmine-api-key", &self.api_key);
+        if let Some(impersonation) = &self.impersonation {
+            req = req.header("X-Redmine-Switch-User", impersonation.to_string());
+        }
+        if let Some(timeout) = self.timeout {
+            req = req.timeout(timeout);
+        }
+        req
+    }
+
     /// internal method for shared logic between the methods below which
     /// diff in how they parse the response body and how often they call this
     fn rest(
@@ -189,63 +690,74 @@ impl Redmine {
         endpoint: &str,
         parameters: QueryParams,
         mime_type_and_body: Option<(&str, Vec<u8>)>,
-    ) -> Result<(reqwest::StatusCode, bytes::Bytes), crate::Error> {
+        headers: reqwest::header::HeaderMap,
+    ) -> Result<
+        (
+            reqwest::StatusCode,
+            reqwest::header::HeaderMap,
+            bytes::Bytes,
+        ),
+        crate::Error,
+    > {
         let Redmine {
-            client,
             redmine_url,
-            api_key,
-            impersonate_user_id,
+            retry_policy,
+            ..
         } = self;
         let mut url = redmine_url.join(endpoint)?;
         parameters.add_to_url(&mut url);
-        debug!(%url, %method, "Calling redmine");
-        let req = client
-            .request(method.clone(), url.clone())
-            .header("x-redmine-api-key", api_key);
-        let req = if let Some(user_id) = impersonate_user_id {
-            req.header("X-Redmine-Switch-User", format!("{}", user_id))
+        let mime_type_and_body = if let Some((mime, data)) = mime_type_and_body {
+            trace_request_body(mime, &data);
+            Some((mime, bytes::Bytes::from(data)))
         } else {
-            req
+            None
         };
-        let req = if let Some((mime, data)) = mime_type_and_body {
-            if let Ok(request_body) = from_utf8(&data) {
-                trace!("Request body (Content-Type: {}):\n{}", mime, request_body);
+        let mut attempt = 1;
+        loop {
+            debug!(%url, %method, "Calling redmine");
+            let req = self
+                .request(method.clone(), url.clone())
+                .headers(headers.clone());
+            let req = if let Some((mime, data)) = &mime_type_and_body {
+                req.body(data.clone()).header("Content-Type", *mime)
             } else {
-                trace!(
-                    "Request body (Content-Type: {}) could not be parsed as UTF-8:\n{:?}",
-                    mime,
-                    data
+                req
+            };
+            let result = req.send();
+            if let Err(ref e) = result {
+                error!(%url, %method, "Redmine send error: {:?}", e);
+            }
+            let result = result?;
+            let status = result.status();
+            let retry_after = parse_retry_after(result.headers());
+            let response_headers = result.headers().clone();
+            let response_body = result.bytes()?;
+            trace_response_body(&response_body);
+            if let Some(delay) = retry_policy.as_ref().and_then(|retry_policy| {
+                retry_policy.retry_delay(attempt, &method, &headers, status, retry_after)
+            }) {
+                warn!(
+                    %url,
+                    %method,
+                    "Redmine status {:?} on attempt {}, retrying in {:?}",
+                    status,
+                    attempt,
+                    delay
                 );
+                std::thread::sleep(delay);
+                attempt += 1;
+                continue;
             }
-            req.body(data).header("Content-Type", mime)
-        } else {
-            req
-        };
-        let result = req.send();
-        if let Err(ref e) = result {
-            error!(%url, %method, "Redmine send error: {:?}", e);
-        }
-        let result = result?;
-        let status = result.status();
-        let response_body = result.bytes()?;
-        match from_utf8(&response_body) {
-            Ok(response_body) => {
-                trace!("Response body:\n{}", &response_body);
+            if status.is_client_error() {
+                error!(%url, %method, "Redmine status error (client error): {:?}", status);
+            } else if status.is_server_error() {
+                error!(%url, %method, "Redmine status error (server error): {:?}", status);
             }
-            Err(e) => {
-                trace!(
-                    "Response body that could not be parsed as utf8 because of {}:\n{:?}",
-                    &e,
-                    &response_body
-                );
+            if status.is_client_error() || status.is_server_error() {
+                return Err(http_error_response(status, retry_after, &response_body));
             }
+            return Ok((status, response_headers, response_body));
         }
-        if status.is_client_error() {
-            error!(%url, %method, "Redmine status error (client error): {:?}", status);
-        } else if status.is_server_error() {
-            error!(%url, %method, "Redmine status error (server error): {:?}", status);
-        }
-        Ok((status, response_body))
     }
 
     /// use this with endpoints that have no response body, e.g. those just deleting
@@ -254,7 +766,7 @@ impl Redmine {
     /// # Errors
     ///
     /// This can return an error if the endpoint returns an error when creating the request
-    /// body or when the web request fails
+    /// body, when the web request fails or when Redmine returns an HTTP error status
     pub fn ignore_response_body<E>(&self, endpoint: &E) -> Result<(), crate::Error>
     where
         E: Endpoint,
@@ -263,10 +775,84 @@ impl Redmine {
         let url = endpoint.endpoint();
         let parameters = endpoint.parameters();
         let mime_type_and_body = endpoint.body()?;
-        self.rest(method, &url, parameters, mime_type_and_body)?;
+        self.rest(
+            method,
+            &url,
+            parameters,
+            mime_type_and_body,
+            endpoint.headers(),
+        )?;
         Ok(())
     }
 
+    /// downloads the content of an attachment and returns the raw bytes
+    ///
+    /// the content_url of the attachment can be absolute or relative to the
+    /// Redmine URL, the response body is not parsed in any way
+    ///
+    /// absolute content URLs on a different origin than the Redmine URL are
+    /// rejected so the API key is never sent to another host
+    ///
+    /// # Errors
+    ///
+    /// This can return an error if the content URL can not be parsed or is not
+    /// on the origin of the Redmine URL, when the web request fails or when
+    /// Redmine returns an HTTP error status
+    pub fn download_attachment(
+        &self,
+        attachment: &attachments::Attachment,
+    ) -> Result<bytes::Bytes, crate::Error> {
+        let url = attachment_content_url(&self.redmine_url, &attachment.content_url)?;
+        let (_, _, body) = self.rest(
+            Method::GET,
+            url.as_str(),
+            QueryParams::default(),
+            None,
+            reqwest::header::HeaderMap::new(),
+        )?;
+        Ok(body)
+    }
+
+    /// uploads a file to Redmine, streaming the content from a reader instead
+    /// of reading the whole file into memory first like
+    /// [UploadFile](crate::api::uploads::UploadFile) does
+    ///
+    /// the returned token can be used in
+    /// [UploadedAttachment](crate::api::issues::UploadedAttachment)
+    ///
+    /// since the body can only be consumed once the request is not retried,
+    /// even if a retry policy is set
+    ///
+    /// # Errors
+    ///
+    /// This can return an error if the web request fails, when Redmine returns an
+    /// HTTP error status or when the response can not be parsed as an upload token
+    pub fn upload_file_from_reader<R>(
+        &self,
+        reader: R,
+        filename: &str,
+    ) -> Result<uploads::FileUploadToken, crate::Error>
+    where
+        R: std::io::Read + Send + 'static,
+    {
+        let url = upload_url(&self.redmine_url, filename)?;
+        let method = Method::POST;
+        debug!(%url, %method, "Calling redmine with streamed upload");
+        let result = self
+            .request(method.clone(), url.clone())
+            .header("Content-Type", "application/octet-stream")
+            .body(reqwest::blocking::Body::new(reader))
+            .send();
+        if let Err(ref e) = result {
+            error!(%url, %method, "Redmine send error: {:?}", e);
+        }
+        let result = result?;
+        let status = result.status();
+        let retry_after = parse_retry_after(result.headers());
+        let response_body = result.bytes()?;
+        upload_token_from_response(status, retry_after, &response_body)
+    }
+
     /// use this with endpoints which return a JSON response but do not support pagination
     ///
     /// you can use it with those that support pagination but they will only return the first page
@@ -274,8 +860,8 @@ impl Redmine {
     /// # Errors
     ///
     /// This can return an error if the endpoint returns an error when creating the request body,
-    /// when the web request fails or when the response can not be parsed as a JSON object
-    /// into the result type
+    /// when the web request fails, when Redmine returns an HTTP error status or when the
+    /// response can not be parsed as a JSON object into the result type
     pub fn json_response_body<E, R>(&self, endpoint: &E) -> Result<R, crate::Error>
     where
         E: Endpoint + ReturnsJsonResponse,
@@ -285,83 +871,214 @@ impl Redmine {
         let url = endpoint.endpoint();
         let parameters = endpoint.parameters();
         let mime_type_and_body = endpoint.body()?;
-        let (status, response_body) = self.rest(method, &url, parameters, mime_type_and_body)?;
-        if response_body.is_empty() {
-            Err(crate::Error::EmptyResponseBody(status))
-        } else {
-            let result = serde_json::from_slice::<R>(&response_body);
-            if let Ok(ref parsed_response_body) = result {
-                trace!("Parsed response body:\n{:#?}", parsed_response_body);
-            }
-            Ok(result?)
-        }
+        let (status, _, response_body) = self.rest(
+            method,
+            &url,
+            parameters,
+            mime_type_and_body,
+            endpoint.headers(),
+        )?;
+        parse_json_response_body(status, &response_body)?
+            .ok_or(crate::Error::EmptyResponseBody(status))
     }
 
-    /// use this to get a single page of a paginated JSON response
+    /// like [Redmine::json_response_body] but also returns the status and
+    /// selected headers of the response
+    ///
     /// # Errors
     ///
-    /// This can return an error if the endpoint returns an error when creating the
-    /// request body, when the web request fails, when the response can not be parsed
-    /// as a JSON object, when any of the pagination keys or the value key are missing
-    /// in the JSON object or when the values can not be parsed as the result type.
-    pub fn json_response_body_page<E, R>(
+    /// see [Redmine::json_response_body]
+    pub fn json_response_with_meta<E, R>(
         &self,
         endpoint: &E,
-        offset: u64,
-        limit: u64,
-    ) -> Result<ResponsePage<R>, crate::Error>
+    ) -> Result<(R, ResponseMeta), crate::Error>
     where
-        E: Endpoint + ReturnsJsonResponse + Pageable,
+        E: Endpoint + ReturnsJsonResponse,
         R: DeserializeOwned + std::fmt::Debug,
     {
         let method = endpoint.method();
         let url = endpoint.endpoint();
-        let mut parameters = endpoint.parameters();
-        parameters.push("offset", offset);
-        parameters.push("limit", limit);
+        let parameters = endpoint.parameters();
         let mime_type_and_body = endpoint.body()?;
-        let (status, response_body) = self.rest(method, &url, parameters, mime_type_and_body)?;
-        if response_body.is_empty() {
-            Err(crate::Error::EmptyResponseBody(status))
-        } else {
-            let json_value_response_body: serde_json::Value =
-                serde_json::from_slice(&response_body)?;
-            let json_object_response_body = json_value_response_body.as_object();
-            if let Some(json_object_response_body) = json_object_response_body {
-                let total_count = json_object_response_body
-                    .get("total_count")
-                    .ok_or_else(|| crate::Error::PaginationKeyMissing("total_count".to_string()))?
-                    .as_u64()
-                    .ok_or_else(|| {
-                        crate::Error::PaginationKeyHasWrongType("total_count".to_string())
-                    })?;
-                let offset = json_object_response_body
-                    .get("offset")
-                    .ok_or_else(|| crate::Error::PaginationKeyMissing("offset".to_string()))?
-                    .as_u64()
-                    .ok_or_else(|| {
-                        crate::Error::PaginationKeyHasWrongType("total_count".to_string())
-                    })?;
-                let limit = json_object_response_body
-                    .get("limit")
-                    .ok_or_else(|| crate::Error::PaginationKeyMissing("limit".to_string()))?
-                    .as_u64()
-                    .ok_or_else(|| {
-                        crate::Error::PaginationKeyHasWrongType("total_count".to_string())
-                    })?;
-                let response_wrapper_key = endpoint.response_wrapper_key();
-                let inner_response_body = json_object_response_body
-                    .get(&response_wrapper_key)
-                    .ok_or(crate::Error::PaginationKeyMissing(response_wrapper_key))?;
-                let result = serde_json::from_value::<Vec<R>>(inner_response_body.to_owned());
-                if let Ok(ref parsed_response_body) = result {
-                    trace!(%total_count, %offset, %limit, "Parsed response body:\n{:?}", parsed_response_body);
-                }
-                Ok(ResponsePage {
-                    values: result?,
-                    total_count,
-                    offset,
-                    limit,
+        let (status, headers, response_body) = self.rest(
+            method,
+            &url,
+            parameters,
+            mime_type_and_body,
+            endpoint.headers(),
+        )?;
+        let result = parse_json_response_body(status, &response_body)?
+            .ok_or(crate::Error::EmptyResponseBody(status))?;
+        Ok((result, ResponseMeta::new(status, &headers)))
+    }
+
+    /// like [Redmine::json_response_with_meta] but sends the validators of an
+    /// earlier response and returns `Ok(None)` if Redmine responds with
+    /// 304 Not Modified
+    ///
+    /// the returned [ResponseMeta] contains the validators to use for the
+    /// next request, e.g. via `ConditionalRequest::from(&meta)`
+    ///
+    /// # Errors
+    ///
+    /// see [Redmine::json_response_body]
+    pub fn json_response_body_conditional<E, R>(
+        &self,
+        endpoint: &E,
+        conditional: &ConditionalRequest,
+    ) -> Result<Option<(R, ResponseMeta)>, crate::Error>
+    where
+        E: Endpoint + ReturnsJsonResponse,
+        R: DeserializeOwned + std::fmt::Debug,
+    {
+        let method = endpoint.method();
+        let url = endpoint.endpoint();
+        let parameters = endpoint.parameters();
+        let mime_type_and_body = endpoint.body()?;
+        let mut request_headers = endpoint.headers();
+        request_headers.extend(conditional.headers());
+        let (status, headers, response_body) = self.rest(
+            method,
+            &url,
+            parameters,
+            mime_type_and_body,
+            request_headers,
+        )?;
+        if status == reqwest::StatusCode::NOT_MODIFIED {
+            return Ok(None);
+        }
+        let result = parse_json_response_body(status, &response_body)?
+            .ok_or(crate::Error::EmptyResponseBody(status))?;
+        Ok(Some((result, ResponseMeta::new(status, &headers))))
+    }
+
+    /// like [Redmine::json_response_body] but returns `Ok(None)` if Redmine
+    /// responds with 404 Not Found (e.g. for an issue that was deleted)
+    ///
+    /// # Errors
+    ///
+    /// This can return an error if the endpoint returns an error when creating the request body,
+    /// when the web request fails, when Redmine returns an HTTP error status other than 404 or
+    /// when the response can not be parsed as a JSON object into the result type
+    pub fn json_response_body_optional<E, R>(&self, endpoint: &E) -> Result<Option<R>, crate::Error>
+    where
+        E: Endpoint + ReturnsJsonResponse,
+        R: DeserializeOwned + std::fmt::Debug,
+    {
+        match self.json_response_body(endpoint) {
+            Ok(result) => Ok(Some(result)),
+            Err(e) if e.is_not_found() => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// like [Redmine::json_response_body] but returns `Ok(None)` if Redmine
+    /// responds with 204 No Content, use this for endpoints which only return
+    /// a JSON body in some cases
+    ///
+    /// an empty body with any other success status is still reported as
+    /// [crate::Error::EmptyResponseBody] since JSON was expected
+    ///
+    /// # Errors
+    ///
+    /// This can return an error if the endpoint returns an error when creating the request body,
+    /// when the web request fails, when Redmine returns an HTTP error status, when the body is
+    /// empty with a status other than 204 or when the response can not be parsed as a JSON
+    /// object into the result type
+    pub fn json_response_body_or_no_content<E, R>(
+        &self,
+        endpoint: &E,
+    ) -> Result<Option<R>, crate::Error>
+    where
+        E: Endpoint + ReturnsJsonResponse,
+        R: DeserializeOwned + std::fmt::Debug,
+    {
+        let method = endpoint.method();
+        let url = endpoint.endpoint();
+        let parameters = endpoint.parameters();
+        let mime_type_and_body = endpoint.body()?;
+        let (status, _, response_body) = self.rest(
+            method,
+            &url,
+            parameters,
+            mime_type_and_body,
+            endpoint.headers(),
+        )?;
+        parse_json_response_body(status, &response_body)
+    }
+
+    /// use this to get a single page of a paginated JSON response
+    /// # Errors
+    ///
+    /// This can return an error if the endpoint returns an error when creating the
+    /// request body, when the web request fails, when Redmine returns an HTTP error
+    /// status, when the response can not be parsed as a JSON object, when any of the
+    /// pagination keys or the value key are missing in the JSON object or when the
+    /// values can not be parsed as the result type.
+    pub fn json_response_body_page<E, R>(
+        &self,
+        endpoint: &E,
+        offset: u64,
+        limit: u64,
+    ) -> Result<ResponsePage<R>, crate::Error>
+    where
+        E: Endpoint + ReturnsJsonResponse + Pageable,
+        R: DeserializeOwned + std::fmt::Debug,
+    {
+        self.json_response_body_page_with_parameters(endpoint, endpoint.parameters(), offset, limit)
+    }
+
+    /// like [Redmine::json_response_body_page] but with the query parameters
+    /// passed in instead of taken from the endpoint
+    ///
+    /// # Errors
+    ///
+    /// see [Redmine::json_response_body_page]
+    fn json_response_body_page_with_parameters<E, R>(
+        &self,
+        endpoint: &E,
+        mut parameters: QueryParams<'_>,
+        offset: u64,
+        limit: u64,
+    ) -> Result<ResponsePage<R>, crate::Error>
+    where
+        E: Endpoint + ReturnsJsonResponse + Pageable,
+        R: DeserializeOwned + std::fmt::Debug,
+    {
+        let method = endpoint.method();
+        let url = endpoint.endpoint();
+        parameters.push("offset", offset);
+        parameters.push("limit", limit);
+        let mime_type_and_body = endpoint.body()?;
+        let (status, _, response_body) = self.rest(
+            method,
+            &url,
+            parameters,
+            mime_type_and_body,
+            endpoint.headers(),
+        )?;
+        if response_body.is_empty() {
+            Err(crate::Error::EmptyResponseBody(status))
+        } else {
+            let json_value_response_body: serde_json::Value =
+                serde_json::from_slice(&response_body)?;
+            let json_object_response_body = json_value_response_body.as_object();
+            if let Some(json_object_response_body) = json_object_response_body {
+                let (total_count, offset, limit) =
+                    pagination_keys(json_object_response_body, offset, limit)?;
+                let response_wrapper_key = endpoint.response_wrapper_key();
+                let inner_response_body = json_object_response_body
+                    .get(&response_wrapper_key)
+                    .ok_or(crate::Error::PaginationKeyMissing(response_wrapper_key))?;
+                let result = serde_json::from_value::<Vec<R>>(inner_response_body.to_owned());
+                if let Ok(ref parsed_response_body) = result {
+                    trace!(%total_count, %offset, %limit, "Parsed response body:\n{:?}", parsed_response_body);
+                }
+                Ok(ResponsePage {
+                    values: result?,
+                    total_count,
+                    offset,
+                    limit,
                 })
             } else {
                 Err(crate::Error::NonObjectResponseBody(status))
@@ -369,6 +1086,44 @@ impl Redmine {
         }
     }
 
+    /// fetches the page at the cursor and returns it together with the cursor
+    /// for the next page, which is None after the last page
+    ///
+    /// # Errors
+    ///
+    /// see [Redmine::json_response_body_page]
+    pub fn json_response_body_page_at<E, R>(
+        &self,
+        endpoint: &E,
+        cursor: PageCursor,
+    ) -> Result<(ResponsePage<R>, Option<PageCursor>), crate::Error>
+    where
+        E: Endpoint + ReturnsJsonResponse + Pageable,
+        R: DeserializeOwned + std::fmt::Debug,
+    {
+        let page = self.json_response_body_page(endpoint, cursor.offset, cursor.limit)?;
+        let next_cursor = page.next_cursor();
+        Ok((page, next_cursor))
+    }
+
+    /// returns only the total_count of a paginated endpoint, e.g. the number
+    /// of issues matching a filter
+    ///
+    /// this requests a single row since Redmine treats a limit of 0 as the
+    /// default page size and that row is skipped instead of parsed
+    ///
+    /// # Errors
+    ///
+    /// see [Redmine::json_response_body_page]
+    pub fn count<E>(&self, endpoint: &E) -> Result<u64, crate::Error>
+    where
+        E: Endpoint + ReturnsJsonResponse + Pageable,
+    {
+        let page =
+            self.json_response_body_page::<E, serde::de::IgnoredAny>(endpoint, 0, COUNT_LIMIT)?;
+        Ok(page.total_count)
+    }
+
     /// use this to get the results for all pages of a paginated JSON response
     ///
     /// # Errors
@@ -379,6 +1134,46 @@ impl Redmine {
     /// in the JSON object or when the values can not be parsed as the result type.
     ///
     pub fn json_response_body_all_pages<E, R>(&self, endpoint: &E) -> Result<Vec<R>, crate::Error>
+    where
+        E: Endpoint + ReturnsJsonResponse + Pageable,
+        R: DeserializeOwned + std::fmt::Debug,
+    {
+        self.json_response_body_all_pages_with_limit(endpoint, 100)
+    }
+
+    /// use this to get the results for all pages of a paginated JSON response
+    /// as an iterator of values instead of collecting them all into a Vec first
+    ///
+    /// pages are requested on demand when the values of the previous page
+    /// have been consumed
+    pub fn json_response_body_all_pages_iter<'a, E, R>(
+        &'a self,
+        endpoint: &'a E,
+    ) -> AllPages<'a, E, R>
+    where
+        E: Endpoint + ReturnsJsonResponse + Pageable,
+        R: DeserializeOwned + std::fmt::Debug,
+    {
+        AllPages::new(self, endpoint)
+    }
+
+    /// use this to get the results for all pages of a paginated JSON response
+    /// with the given number of results per request
+    ///
+    /// the limit is clamped to the range 1 to 100 since Redmine ignores larger limits
+    ///
+    /// # Errors
+    ///
+    /// This can return an error if the endpoint returns an error when creating the
+    /// request body, when any of the web requests fails, when the response can not be
+    /// parsed as a JSON object, when any of the pagination keys or the value key are missing
+    /// in the JSON object or when the values can not be parsed as the result type.
+    ///
+    pub fn json_response_body_all_pages_with_limit<E, R>(
+        &self,
+        endpoint: &E,
+        limit: u64,
+    ) -> Result<Vec<R>, crate::Error>
     where
         E: Endpoint + ReturnsJsonResponse + Pageable,
         R: DeserializeOwned + std::fmt::Debug,
@@ -386,15 +1181,20 @@ impl Redmine {
         let method = endpoint.method();
         let url = endpoint.endpoint();
         let mut offset = 0;
-        let limit = 100;
+        let limit = limit.clamp(1, 100);
         let mut total_results = vec![];
         loop {
-            let mut page_parameters = endpoint.parameters();
+            let mut page_parameters = all_pages_parameters(endpoint);
             page_parameters.push("offset", offset);
             page_parameters.push("limit", limit);
             let mime_type_and_body = endpoint.body()?;
-            let (status, response_body) =
-                self.rest(method.clone(), &url, page_parameters, mime_type_and_body)?;
+            let (status, _, response_body) = self.rest(
+                method.clone(),
+                &url,
+                page_parameters,
+                mime_type_and_body,
+                endpoint.headers(),
+            )?;
             if response_body.is_empty() {
                 return Err(crate::Error::EmptyResponseBody(status));
             }
@@ -402,27 +1202,8 @@ impl Redmine {
                 serde_json::from_slice(&response_body)?;
             let json_object_response_body = json_value_response_body.as_object();
             if let Some(json_object_response_body) = json_object_response_body {
-                let total_count: u64 = json_object_response_body
-                    .get("total_count")
-                    .ok_or_else(|| crate::Error::PaginationKeyMissing("total_count".to_string()))?
-                    .as_u64()
-                    .ok_or_else(|| {
-                        crate::Error::PaginationKeyHasWrongType("total_count".to_string())
-                    })?;
-                let response_offset: u64 = json_object_response_body
-                    .get("offset")
-                    .ok_or_else(|| crate::Error::PaginationKeyMissing("offset".to_string()))?
-                    .as_u64()
-                    .ok_or_else(|| {
-                        crate::Error::PaginationKeyHasWrongType("total_count".to_string())
-                    })?;
-                let response_limit: u64 = json_object_response_body
-                    .get("limit")
-                    .ok_or_else(|| crate::Error::PaginationKeyMissing("limit".to_string()))?
-                    .as_u64()
-                    .ok_or_else(|| {
-                        crate::Error::PaginationKeyHasWrongType("total_count".to_string())
-                    })?;
+                let (total_count, response_offset, response_limit) =
+                    pagination_keys(json_object_response_body, offset, limit)?;
                 let response_wrapper_key = endpoint.response_wrapper_key();
                 let inner_response_body = json_object_response_body
                     .get(&response_wrapper_key)
@@ -444,6 +1225,265 @@ impl Redmine {
     }
 }
 
+/// logs a request body at trace level
+///
+/// the level is checked first since converting multi-MB bodies to UTF-8 is
+/// wasteful when the message is discarded anyway
+fn trace_request_body(mime: &str, data: &[u8]) {
+    if !tracing::enabled!(tracing::Level::TRACE) {
+        return;
+    }
+    if let Ok(request_body) = from_utf8(data) {
+        trace!("Request body (Content-Type: {}):\n{}", mime, request_body);
+    } else {
+        trace!(
+            "Request body (Content-Type: {}) could not be parsed as UTF-8:\n{:?}",
+            mime,
+            data
+        );
+    }
+}
+
+/// logs a response body at trace level
+///
+/// the level is checked first for the same reason as in [trace_request_body]
+fn trace_response_body(response_body: &[u8]) {
+    if !tracing::enabled!(tracing::Level::TRACE) {
+        return;
+    }
+    match from_utf8(response_body) {
+        Ok(response_body) => {
+            trace!("Response body:\n{}", response_body);
+        }
+        Err(e) => {
+            trace!(
+                "Response body that could not be parsed as utf8 because of {}:\n{:?}",
+                &e,
+                response_body
+            );
+        }
+    }
+}
+
+/// parses a non-paginated JSON response body
+///
+/// returns None for an empty body with status 204 No Content
+///
+/// # Errors
+///
+/// This returns [crate::Error::EmptyResponseBody] if the body is empty with
+/// any other status and an error if the body can not be parsed as the
+/// result type
+fn parse_json_response_body<R>(
+    status: reqwest::StatusCode,
+    response_body: &[u8],
+) -> Result<Option<R>, crate::Error>
+where
+    R: DeserializeOwned + std::fmt::Debug,
+{
+    if response_body.is_empty() {
+        if status == reqwest::StatusCode::NO_CONTENT {
+            return Ok(None);
+        }
+        return Err(crate::Error::EmptyResponseBody(status));
+    }
+    let result = serde_json::from_slice::<R>(response_body);
+    if let Ok(ref parsed_response_body) = result {
+        trace!("Parsed response body:\n{:#?}", parsed_response_body);
+    }
+    Ok(Some(result?))
+}
+
+/// the query parameters of the endpoint for requests that walk all pages,
+/// with the [Pageable::default_stable_sort] of the endpoint added if the
+/// endpoint has no explicit sort
+fn all_pages_parameters<E>(endpoint: &E) -> QueryParams<'_>
+where
+    E: Endpoint + Pageable,
+{
+    let mut parameters = endpoint.parameters();
+    if !parameters.contains_key("sort") {
+        parameters.push_opt("sort", endpoint.default_stable_sort());
+    }
+    parameters
+}
+
+/// reads total_count, offset and limit from a paginated JSON response
+///
+/// total_count is required but offset and limit fall back to the values
+/// from the request if they are missing since some plugin endpoints do not
+/// return them
+///
+/// # Errors
+///
+/// This returns [crate::Error::PaginationKeyMissing] if total_count is
+/// missing and [crate::Error::PaginationKeyHasWrongType] if any of the keys
+/// is not an unsigned integer
+fn pagination_keys(
+    json_object_response_body: &serde_json::Map<String, serde_json::Value>,
+    requested_offset: u64,
+    requested_limit: u64,
+) -> Result<(u64, u64, u64), crate::Error> {
+    let key = |name: &str, default: Option<u64>| -> Result<u64, crate::Error> {
+        match json_object_response_body.get(name) {
+            Some(value) => value
+                .as_u64()
+                .ok_or_else(|| crate::Error::PaginationKeyHasWrongType(name.to_string())),
+            None => default.ok_or_else(|| crate::Error::PaginationKeyMissing(name.to_string())),
+        }
+    };
+    Ok((
+        key("total_count", None)?,
+        key("offset", Some(requested_offset))?,
+        key("limit", Some(requested_limit))?,
+    ))
+}
+
+/// the web UI URL for the path segments below the Redmine URL,
+/// each segment is percent-encoded
+///
+/// the segments are appended to the path of the Redmine URL so a Redmine
+/// running in a sub-path (e.g. `https://example.com/redmine/`) works, the URL
+/// is returned unchanged if it can not have a path (which no Redmine URL does)
+fn web_url(redmine_url: &Url, segments: &[&str]) -> Url {
+    let mut url = redmine_url.clone();
+    url.set_query(None);
+    url.set_fragment(None);
+    if let Ok(mut path) = url.path_segments_mut() {
+        path.pop_if_empty().extend(segments);
+    }
+    url
+}
+
+/// builder for [RedmineAsync] objects
+#[derive(derive_more::Debug)]
+pub struct RedmineAsyncBuilder {
+    /// the reqwest client to use, if none is set a default client is created
+    client: Option<reqwest::Client>,
+    /// the redmine base url
+    redmine_url: Url,
+    /// a redmine API key, usually 40 hex digits where the letters (a-f) are lower case
+    #[debug(skip)]
+    api_key: String,
+    /// the user we want to impersonate, only works if the API key we use has admin privileges
+    impersonation: Option<Impersonation>,
+    /// the policy for retrying requests that failed with a transient error status, if any
+    retry_policy: Option<RetryPolicy>,
+    /// the timeout for requests to Redmine, overrides the timeout of the client if set
+    timeout: Option<std::time::Duration>,
+    /// additional headers sent with every request
+    extra_headers: reqwest::header::HeaderMap,
+}
+
+impl RedmineAsyncBuilder {
+    /// create a builder for a [RedmineAsync] object with the given base URL and API key
+    #[must_use]
+    pub fn new(redmine_url: url::Url, api_key: &str) -> Self {
+        Self {
+            client: None,
+            redmine_url,
+            api_key: api_key.to_string(),
+            impersonation: None,
+            retry_policy: None,
+            timeout: None,
+            extra_headers: reqwest::header::HeaderMap::new(),
+        }
+    }
+
+    /// use this reqwest client instead of creating a default one
+    ///
+    /// responses are only decompressed if the gzip or deflate features of
+    /// reqwest are enabled and the client was not built with them disabled
+    #[must_use]
+    pub fn client(mut self, client: reqwest::Client) -> Self {
+        self.client = Some(client);
+        self
+    }
+
+    /// impersonate the user with this id in all API calls
+    ///
+    /// this requires Redmine admin privileges
+    #[must_use]
+    pub fn impersonate_user(mut self, id: u64) -> Self {
+        self.impersonation = Some(Impersonation::UserId(id));
+        self
+    }
+
+    /// impersonate the user with this login in all API calls
+    ///
+    /// this requires Redmine admin privileges
+    #[must_use]
+    pub fn impersonate_login(mut self, login: impl Into<String>) -> Self {
+        self.impersonation = Some(Impersonation::Login(login.into()));
+        self
+    }
+
+    /// retry requests that failed with a transient error status according to this policy
+    #[must_use]
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(retry_policy);
+        self
+    }
+
+    /// use this timeout for requests to Redmine instead of the one set on the client
+    #[must_use]
+    pub fn timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// send this additional header with every request
+    ///
+    /// headers which would replace the API key or impersonation headers are
+    /// ignored with a warning
+    #[must_use]
+    pub fn header(
+        mut self,
+        name: reqwest::header::HeaderName,
+        value: reqwest::header::HeaderValue,
+    ) -> Self {
+        add_extra_header(&mut self.extra_headers, name, value);
+        self
+    }
+
+    /// create the [RedmineAsync] object
+    ///
+    /// # Errors
+    ///
+    /// This will return [`crate::Error::ReqwestError`] if no client was set and
+    /// initialization of the default Reqwest client fails.
+    pub fn build(self) -> Result<RedmineAsync, crate::Error> {
+        let RedmineAsyncBuilder {
+            client,
+            redmine_url,
+            api_key,
+            impersonation,
+            retry_policy,
+            timeout,
+            extra_headers,
+        } = self;
+        let client = if let Some(client) = client {
+            client
+        } else {
+            #[cfg(not(feature = "rustls-tls"))]
+            let client = reqwest::Client::new();
+            #[cfg(feature = "rustls-tls")]
+            let client = reqwest::Client::builder().use_rustls_tls().build()?;
+            client
+        };
+        Ok(RedmineAsync {
+            client,
+            redmine_url,
+            api_key,
+            impersonation,
+            retry_policy,
+            timeout,
+            extra_headers,
+            issue_statuses: std::sync::OnceLock::new(),
+        })
+    }
+}
+
 impl RedmineAsync {
     /// create a [RedmineAsync] object
     ///
@@ -451,17 +1491,15 @@ impl RedmineAsync {
     ///
     /// This will return [`crate::Error::ReqwestError`] if initialization of Reqwest client is failed.
     pub fn new(redmine_url: url::Url, api_key: &str) -> Result<Self, crate::Error> {
-        #[cfg(not(feature = "rustls-tls"))]
-        let client = reqwest::Client::new();
-        #[cfg(feature = "rustls-tls")]
-        let client = reqwest::Client::builder().use_rustls_tls().build()?;
+        Self::builder(redmine_url, api_key).build()
+    }
 
-        Ok(Self {
-            client,
-            redmine_url,
-            api_key: api_key.to_string(),
-            impersonate_user_id: None,
-        })
+    /// create a builder for a [RedmineAsync] object, use this to set a custom reqwest
+    /// client, impersonation, a retry policy, a timeout or extra headers at
+    /// construction time
+    #[must_use]
+    pub fn builder(redmine_url: url::Url, api_key: &str) -> RedmineAsyncBuilder {
+        RedmineAsyncBuilder::new(redmine_url, api_key)
     }
 
     /// create a [RedmineAsync] object from the environment variables
@@ -486,7 +1524,44 @@ impl RedmineAsync {
     ///
     /// this requires Redmine admin privileges
     pub fn impersonate_user(&mut self, id: u64) {
-        self.impersonate_user_id = Some(id);
+        self.impersonation = Some(Impersonation::UserId(id));
+    }
+
+    /// Sets the login of a user to impersonate in all future API calls
+    ///
+    /// this requires Redmine admin privileges
+    pub fn impersonate_login(&mut self, login: impl Into<String>) {
+        self.impersonation = Some(Impersonation::Login(login.into()));
+    }
+
+    /// Stops impersonating another user, all future API calls are performed
+    /// as the owner of the API key again
+    pub fn stop_impersonating(&mut self) {
+        self.impersonation = None;
+    }
+
+    /// Sets the policy used to retry requests that failed with a transient
+    /// error status (see [RetryPolicy]), None disables retries (the default)
+    pub fn set_retry_policy(&mut self, retry_policy: Option<RetryPolicy>) {
+        self.retry_policy = retry_policy;
+    }
+
+    /// Sets the timeout for requests to Redmine which overrides the timeout set
+    /// on the reqwest client, None uses the timeout of the client (the default)
+    pub fn set_timeout(&mut self, timeout: Option<std::time::Duration>) {
+        self.timeout = timeout;
+    }
+
+    /// Adds a header which is sent with every future API call
+    ///
+    /// headers which would replace the API key or impersonation headers are
+    /// ignored with a warning
+    pub fn add_header(
+        &mut self,
+        name: reqwest::header::HeaderName,
+        value: reqwest::header::HeaderValue,
+    ) {
+        add_extra_header(&mut self.extra_headers, name, value);
     }
 
     /// returns the issue URL for a given issue id
@@ -502,6 +1577,57 @@ impl RedmineAsync {
         redmine_url.join(&format!("/issues/{}", issue_id)).unwrap()
     }
 
+    /// returns the web UI URL of the project with the given identifier
+    ///
+    /// like [RedmineAsync::issue_url] this works entirely on the client
+    #[must_use]
+    pub fn project_url(&self, identifier: &str) -> Url {
+        web_url(&self.redmine_url, &["projects", identifier])
+    }
+
+    /// returns the web UI URL of the version with the given id
+    ///
+    /// like [RedmineAsync::issue_url] this works entirely on the client
+    #[must_use]
+    pub fn version_url(&self, version_id: u64) -> Url {
+        web_url(&self.redmine_url, &["versions", &version_id.to_string()])
+    }
+
+    /// returns the web UI URL of the user with the given id
+    ///
+    /// like [RedmineAsync::issue_url] this works entirely on the client
+    #[must_use]
+    pub fn user_url(&self, user_id: u64) -> Url {
+        web_url(&self.redmine_url, &["users", &user_id.to_string()])
+    }
+
+    /// returns the web UI URL of the wiki page with the given title in the
+    /// project with the given identifier, characters like spaces and slashes
+    /// in the title are percent-encoded
+    ///
+    /// like [RedmineAsync::issue_url] this works entirely on the client
+    #[must_use]
+    pub fn wiki_page_url(&self, project: &str, title: &str) -> Url {
+        web_url(&self.redmine_url, &["projects", project, "wiki", title])
+    }
+
+    /// internal helper to create a request with the API key, impersonation
+    /// and extra headers and the timeout set
+    fn request(&self, method: reqwest::Method, url: Url) -> reqwest::RequestBuilder {
+        let mut req = self
+            .client
+            .request(method, url)
+            .headers(self.extra_headers.clone())
+            .header("x-redmine-api-key", &self.api_key);
+        if let Some(impersonation) = &self.impersonation {
+            req = req.header("X-Redmine-Switch-User", impersonation.to_string());
+        }
+        if let Some(timeout) = self.timeout {
+            req = req.timeout(timeout);
+        }
+        req
+    }
+
     /// internal method for shared logic between the methods below which
     /// diff in how they parse the response body and how often they call this
     async fn rest(
@@ -510,63 +1636,74 @@ impl RedmineAsync {
         endpoint: &str,
         parameters: QueryParams<'_>,
         mime_type_and_body: Option<(&str, Vec<u8>)>,
-    ) -> Result<(reqwest::StatusCode, bytes::Bytes), crate::Error> {
+        headers: reqwest::header::HeaderMap,
+    ) -> Result<
+        (
+            reqwest::StatusCode,
+            reqwest::header::HeaderMap,
+            bytes::Bytes,
+        ),
+        crate::Error,
+    > {
         let RedmineAsync {
-            client,
             redmine_url,
-            api_key,
-            impersonate_user_id,
+            retry_policy,
+            ..
         } = self;
         let mut url = redmine_url.join(endpoint)?;
         parameters.add_to_url(&mut url);
-        debug!(%url, %method, "Calling redmine");
-        let req = client
-            .request(method.clone(), url.clone())
-            .header("x-redmine-api-key", api_key);
-        let req = if let Some(user_id) = impersonate_user_id {
-            req.header("X-Redmine-Switch-User", format!("{}", user_id))
+        let mime_type_and_body = if let Some((mime, data)) = mime_type_and_body {
+            trace_request_body(mime, &data);
+            Some((mime, bytes::Bytes::from(data)))
         } else {
-            req
+            None
         };
-        let req = if let Some((mime, data)) = mime_type_and_body {
-            if let Ok(request_body) = from_utf8(&data) {
-                trace!("Request body (Content-Type: {}):\n{}", mime, request_body);
+        let mut attempt = 1;
+        loop {
+            debug!(%url, %method, "Calling redmine");
+            let req = self
+                .request(method.clone(), url.clone())
+                .headers(headers.clone());
+            let req = if let Some((mime, data)) = &mime_type_and_body {
+                req.body(data.clone()).header("Content-Type", *mime)
             } else {
-                trace!(
-                    "Request body (Content-Type: {}) could not be parsed as UTF-8:\n{:?}",
-                    mime,
-                    data
+                req
+            };
+            let result = req.send().await;
+            if let Err(ref e) = result {
+                error!(%url, %method, "Redmine send error: {:?}", e);
+            }
+            let result = result?;
+            let status = result.status();
+            let retry_after = parse_retry_after(result.headers());
+            let response_headers = result.headers().clone();
+            let response_body = result.bytes().await?;
+            trace_response_body(&response_body);
+            if let Some(delay) = retry_policy.as_ref().and_then(|retry_policy| {
+                retry_policy.retry_delay(attempt, &method, &headers, status, retry_after)
+            }) {
+                warn!(
+                    %url,
+                    %method,
+                    "Redmine status {:?} on attempt {}, retrying in {:?}",
+                    status,
+                    attempt,
+                    delay
                 );
+                tokio::time::sleep(delay).await;
+                attempt += 1;
+                continue;
             }
-            req.body(data).header("Content-Type", mime)
-        } else {
-            req
-        };
-        let result = req.send().await;
-        if let Err(ref e) = result {
-            error!(%url, %method, "Redmine send error: {:?}", e);
-        }
-        let result = result?;
-        let status = result.status();
-        let response_body = result.bytes().await?;
-        match from_utf8(&response_body) {
-            Ok(response_body) => {
-                trace!("Response body:\n{}", &response_body);
+            if status.is_client_error() {
+                error!(%url, %method, "Redmine status error (client error): {:?}", status);
+            } else if status.is_server_error() {
+                error!(%url, %method, "Redmine status error (server error): {:?}", status);
             }
-            Err(e) => {
-                trace!(
-                    "Response body that could not be parsed as utf8 because of {}:\n{:?}",
-                    &e,
-                    &response_body
-                );
+            if status.is_client_error() || status.is_server_error() {
+                return Err(http_error_response(status, retry_after, &response_body));
             }
+            return Ok((status, response_headers, response_body));
         }
-        if status.is_client_error() {
-            error!(%url, %method, "Redmine status error (client error): {:?}", status);
-        } else if status.is_server_error() {
-            error!(%url, %method, "Redmine status error (server error): {:?}", status);
-        }
-        Ok((status, response_body))
     }
 
     /// use this with endpoints that have no response body, e.g. those just deleting
@@ -575,7 +1712,7 @@ impl RedmineAsync {
     /// # Errors
     ///
     /// This can return an error if the endpoint returns an error when creating the request
-    /// body or when the web request fails
+    /// body, when the web request fails or when Redmine returns an HTTP error status
     pub async fn ignore_response_body<E>(&self, endpoint: &E) -> Result<(), crate::Error>
     where
         E: Endpoint,
@@ -584,11 +1721,93 @@ impl RedmineAsync {
         let url = endpoint.endpoint();
         let parameters = endpoint.parameters();
         let mime_type_and_body = endpoint.body()?;
-        self.rest(method, &url, parameters, mime_type_and_body)
-            .await?;
+        self.rest(
+            method,
+            &url,
+            parameters,
+            mime_type_and_body,
+            endpoint.headers(),
+        )
+        .await?;
         Ok(())
     }
 
+    /// downloads the content of an attachment and returns the raw bytes
+    ///
+    /// the content_url of the attachment can be absolute or relative to the
+    /// Redmine URL, the response body is not parsed in any way
+    ///
+    /// absolute content URLs on a different origin than the Redmine URL are
+    /// rejected so the API key is never sent to another host
+    ///
+    /// # Errors
+    ///
+    /// This can return an error if the content URL can not be parsed or is not
+    /// on the origin of the Redmine URL, when the web request fails or when
+    /// Redmine returns an HTTP error status
+    pub async fn download_attachment(
+        &self,
+        attachment: &attachments::Attachment,
+    ) -> Result<bytes::Bytes, crate::Error> {
+        let url = attachment_content_url(&self.redmine_url, &attachment.content_url)?;
+        let (_, _, body) = self
+            .rest(
+                Method::GET,
+                url.as_str(),
+                QueryParams::default(),
+                None,
+                reqwest::header::HeaderMap::new(),
+            )
+            .await?;
+        Ok(body)
+    }
+
+    /// uploads a file to Redmine, streaming the content from a reader (e.g. a
+    /// tokio::fs::File) instead of reading the whole file into memory first like
+    /// [UploadFile](crate::api::uploads::UploadFile) does
+    ///
+    /// the returned token can be used in
+    /// [UploadedAttachment](crate::api::issues::UploadedAttachment)
+    ///
+    /// since the body can only be consumed once the request is not retried,
+    /// even if a retry policy is set
+    ///
+    /// # Errors
+    ///
+    /// This can return an error if reading fails, when the web request fails,
+    /// when Redmine returns an HTTP error status or when the response can not be
+    /// parsed as an upload token
+    pub async fn upload_file_from_reader<R>(
+        &self,
+        reader: R,
+        filename: &str,
+    ) -> Result<uploads::FileUploadToken, crate::Error>
+    where
+        R: tokio::io::AsyncRead + Send + Sync + 'static,
+    {
+        let url = upload_url(&self.redmine_url, filename)?;
+        let method = Method::POST;
+        debug!(%url, %method, "Calling redmine with streamed upload");
+        let body = http_body_util::StreamBody::new(
+            tokio_util::io::ReaderStream::new(reader)
+                .map(|chunk| chunk.map(http_body::Frame::data)),
+        );
+        let result = self
+            .request(method.clone(), url.clone())
+            .header("Content-Type", "application/octet-stream")
+            .body(reqwest::Body::wrap(body))
+            .send()
+            .await;
+        if let Err(ref e) = result {
+            error!(%url, %method, "Redmine send error: {:?}", e);
+        }
+        let result = result?;
+        let status = result.status();
+        let retry_after = parse_retry_after(result.headers());
+        let response_body = result.bytes().await?;
+        upload_token_from_response(status, retry_after, &response_body)
+    }
+
     /// use this with endpoints which return a JSON response but do not support pagination
     ///
     /// you can use it with those that support pagination but they will only return the first page
@@ -596,8 +1815,8 @@ impl RedmineAsync {
     /// # Errors
     ///
     /// This can return an error if the endpoint returns an error when creating the request body,
-    /// when the web request fails or when the response can not be parsed as a JSON object
-    /// into the result type
+    /// when the web request fails, when Redmine returns an HTTP error status or when the
+    /// response can not be parsed as a JSON object into the result type
     pub async fn json_response_body<E, R>(&self, endpoint: &E) -> Result<R, crate::Error>
     where
         E: Endpoint + ReturnsJsonResponse,
@@ -607,45 +1826,205 @@ impl RedmineAsync {
         let url = endpoint.endpoint();
         let parameters = endpoint.parameters();
         let mime_type_and_body = endpoint.body()?;
-        let (status, response_body) = self
-            .rest(method, &url, parameters, mime_type_and_body)
+        let (status, _, response_body) = self
+            .rest(
+                method,
+                &url,
+                parameters,
+                mime_type_and_body,
+                endpoint.headers(),
+            )
             .await?;
-        if response_body.is_empty() {
-            Err(crate::Error::EmptyResponseBody(status))
-        } else {
-            let result = serde_json::from_slice::<R>(&response_body);
-            if let Ok(ref parsed_response_body) = result {
-                trace!("Parsed response body:\n{:#?}", parsed_response_body);
-            }
-            Ok(result?)
+        parse_json_response_body(status, &response_body)?
+            .ok_or(crate::Error::EmptyResponseBody(status))
+    }
+
+    /// like [RedmineAsync::json_response_body] but also returns the status and
+    /// selected headers of the response
+    ///
+    /// # Errors
+    ///
+    /// see [RedmineAsync::json_response_body]
+    pub async fn json_response_with_meta<E, R>(
+        &self,
+        endpoint: &E,
+    ) -> Result<(R, ResponseMeta), crate::Error>
+    where
+        E: Endpoint + ReturnsJsonResponse,
+        R: DeserializeOwned + std::fmt::Debug,
+    {
+        let method = endpoint.method();
+        let url = endpoint.endpoint();
+        let parameters = endpoint.parameters();
+        let mime_type_and_body = endpoint.body()?;
+        let (status, headers, response_body) = self
+            .rest(
+                method,
+                &url,
+                parameters,
+                mime_type_and_body,
+                endpoint.headers(),
+            )
+            .await?;
+        let result = parse_json_response_body(status, &response_body)?
+            .ok_or(crate::Error::EmptyResponseBody(status))?;
+        Ok((result, ResponseMeta::new(status, &headers)))
+    }
+
+    /// like [RedmineAsync::json_response_with_meta] but sends the validators of an
+    /// earlier response and returns `Ok(None)` if Redmine responds with
+    /// 304 Not Modified
+    ///
+    /// the returned [ResponseMeta] contains the validators to use for the
+    /// next request, e.g. via `ConditionalRequest::from(&meta)`
+    ///
+    /// # Errors
+    ///
+    /// see [RedmineAsync::json_response_body]
+    pub async fn json_response_body_conditional<E, R>(
+        &self,
+        endpoint: &E,
+        conditional: &ConditionalRequest,
+    ) -> Result<Option<(R, ResponseMeta)>, crate::Error>
+    where
+        E: Endpoint + ReturnsJsonResponse,
+        R: DeserializeOwned + std::fmt::Debug,
+    {
+        let method = endpoint.method();
+        let url = endpoint.endpoint();
+        let parameters = endpoint.parameters();
+        let mime_type_and_body = endpoint.body()?;
+        let mut request_headers = endpoint.headers();
+        request_headers.extend(conditional.headers());
+        let (status, headers, response_body) = self
+            .rest(
+                method,
+                &url,
+                parameters,
+                mime_type_and_body,
+                request_headers,
+            )
+            .await?;
+        if status == reqwest::StatusCode::NOT_MODIFIED {
+            return Ok(None);
+        }
+        let result = parse_json_response_body(status, &response_body)?
+            .ok_or(crate::Error::EmptyResponseBody(status))?;
+        Ok(Some((result, ResponseMeta::new(status, &headers))))
+    }
+
+    /// like [RedmineAsync::json_response_body] but returns `Ok(None)` if Redmine
+    /// responds with 404 Not Found (e.g. for an issue that was deleted)
+    ///
+    /// # Errors
+    ///
+    /// This can return an error if the endpoint returns an error when creating the request body,
+    /// when the web request fails, when Redmine returns an HTTP error status other than 404 or
+    /// when the response can not be parsed as a JSON object into the result type
+    pub async fn json_response_body_optional<E, R>(
+        &self,
+        endpoint: &E,
+    ) -> Result<Option<R>, crate::Error>
+    where
+        E: Endpoint + ReturnsJsonResponse,
+        R: DeserializeOwned + std::fmt::Debug,
+    {
+        match self.json_response_body(endpoint).await {
+            Ok(result) => Ok(Some(result)),
+            Err(e) if e.is_not_found() => Ok(None),
+            Err(e) => Err(e),
         }
     }
 
+    /// like [RedmineAsync::json_response_body] but returns `Ok(None)` if Redmine
+    /// responds with 204 No Content, use this for endpoints which only return
+    /// a JSON body in some cases
+    ///
+    /// an empty body with any other success status is still reported as
+    /// [crate::Error::EmptyResponseBody] since JSON was expected
+    ///
+    /// # Errors
+    ///
+    /// This can return an error if the endpoint returns an error when creating the request body,
+    /// when the web request fails, when Redmine returns an HTTP error status, when the body is
+    /// empty with a status other than 204 or when the response can not be parsed as a JSON
+    /// object into the result type
+    pub async fn json_response_body_or_no_content<E, R>(
+        &self,
+        endpoint: &E,
+    ) -> Result<Option<R>, crate::Error>
+    where
+        E: Endpoint + ReturnsJsonResponse,
+        R: DeserializeOwned + std::fmt::Debug,
+    {
+        let method = endpoint.method();
+        let url = endpoint.endpoint();
+        let parameters = endpoint.parameters();
+        let mime_type_and_body = endpoint.body()?;
+        let (status, _, response_body) = self
+            .rest(
+                method,
+                &url,
+                parameters,
+                mime_type_and_body,
+                endpoint.headers(),
+            )
+            .await?;
+        parse_json_response_body(status, &response_body)
+    }
+
     /// use this to get a single page of a paginated JSON response
     /// # Errors
     ///
     /// This can return an error if the endpoint returns an error when creating the
-    /// request body, when the web request fails, when the response can not be parsed
-    /// as a JSON object, when any of the pagination keys or the value key are missing
-    /// in the JSON object or when the values can not be parsed as the result type.
+    /// request body, when the web request fails, when Redmine returns an HTTP error
+    /// status, when the response can not be parsed as a JSON object, when any of the
+    /// pagination keys or the value key are missing in the JSON object or when the
+    /// values can not be parsed as the result type.
     pub async fn json_response_body_page<E, R>(
         &self,
         endpoint: &E,
         offset: u64,
         limit: u64,
     ) -> Result<ResponsePage<R>, crate::Error>
+    where
+        E: Endpoint + ReturnsJsonResponse + Pageable,
+        R: DeserializeOwned + std::fmt::Debug,
+    {
+        self.json_response_body_page_with_parameters(endpoint, endpoint.parameters(), offset, limit)
+            .await
+    }
+
+    /// like [RedmineAsync::json_response_body_page] but with the query parameters
+    /// passed in instead of taken from the endpoint
+    ///
+    /// # Errors
+    ///
+    /// see [RedmineAsync::json_response_body_page]
+    async fn json_response_body_page_with_parameters<E, R>(
+        &self,
+        endpoint: &E,
+        mut parameters: QueryParams<'_>,
+        offset: u64,
+        limit: u64,
+    ) -> Result<ResponsePage<R>, crate::Error>
     where
         E: Endpoint + ReturnsJsonResponse + Pageable,
         R: DeserializeOwned + std::fmt::Debug,
     {
         let method = endpoint.method();
         let url = endpoint.endpoint();
-        let mut parameters = endpoint.parameters();
         parameters.push("offset", offset);
         parameters.push("limit", limit);
         let mime_type_and_body = endpoint.body()?;
-        let (status, response_body) = self
-            .rest(method, &url, parameters, mime_type_and_body)
+        let (status, _, response_body) = self
+            .rest(
+                method,
+                &url,
+                parameters,
+                mime_type_and_body,
+                endpoint.headers(),
+            )
             .await?;
         if response_body.is_empty() {
             Err(crate::Error::EmptyResponseBody(status))
@@ -654,27 +2033,8 @@ impl RedmineAsync {
                 serde_json::from_slice(&response_body)?;
             let json_object_response_body = json_value_response_body.as_object();
             if let Some(json_object_response_body) = json_object_response_body {
-                let total_count = json_object_response_body
-                    .get("total_count")
-                    .ok_or_else(|| crate::Error::PaginationKeyMissing("total_count".to_string()))?
-                    .as_u64()
-                    .ok_or_else(|| {
-                        crate::Error::PaginationKeyHasWrongType("total_count".to_string())
-                    })?;
-                let offset = json_object_response_body
-                    .get("offset")
-                    .ok_or_else(|| crate::Error::PaginationKeyMissing("offset".to_string()))?
-                    .as_u64()
-                    .ok_or_else(|| {
-                        crate::Error::PaginationKeyHasWrongType("total_count".to_string())
-                    })?;
-                let limit = json_object_response_body
-                    .get("limit")
-                    .ok_or_else(|| crate::Error::PaginationKeyMissing("limit".to_string()))?
-                    .as_u64()
-                    .ok_or_else(|| {
-                        crate::Error::PaginationKeyHasWrongType("total_count".to_string())
-                    })?;
+                let (total_count, offset, limit) =
+                    pagination_keys(json_object_response_body, offset, limit)?;
                 let response_wrapper_key = endpoint.response_wrapper_key();
                 let inner_response_body = json_object_response_body
                     .get(&response_wrapper_key)
@@ -695,6 +2055,47 @@ impl RedmineAsync {
         }
     }
 
+    /// fetches the page at the cursor and returns it together with the cursor
+    /// for the next page, which is None after the last page
+    ///
+    /// # Errors
+    ///
+    /// see [RedmineAsync::json_response_body_page]
+    pub async fn json_response_body_page_at<E, R>(
+        &self,
+        endpoint: &E,
+        cursor: PageCursor,
+    ) -> Result<(ResponsePage<R>, Option<PageCursor>), crate::Error>
+    where
+        E: Endpoint + ReturnsJsonResponse + Pageable,
+        R: DeserializeOwned + std::fmt::Debug,
+    {
+        let page = self
+            .json_response_body_page(endpoint, cursor.offset, cursor.limit)
+            .await?;
+        let next_cursor = page.next_cursor();
+        Ok((page, next_cursor))
+    }
+
+    /// returns only the total_count of a paginated endpoint, e.g. the number
+    /// of issues matching a filter
+    ///
+    /// this requests a single row since Redmine treats a limit of 0 as the
+    /// default page size and that row is skipped instead of parsed
+    ///
+    /// # Errors
+    ///
+    /// see [RedmineAsync::json_response_body_page]
+    pub async fn count<E>(&self, endpoint: &E) -> Result<u64, crate::Error>
+    where
+        E: Endpoint + ReturnsJsonResponse + Pageable,
+    {
+        let page = self
+            .json_response_body_page::<E, serde::de::IgnoredAny>(endpoint, 0, COUNT_LIMIT)
+            .await?;
+        Ok(page.total_count)
+    }
+
     /// use this to get the results for all pages of a paginated JSON response
     ///
     /// # Errors
@@ -708,6 +2109,48 @@ impl RedmineAsync {
         &self,
         endpoint: &E,
     ) -> Result<Vec<R>, crate::Error>
+    where
+        E: Endpoint + ReturnsJsonResponse + Pageable,
+        R: DeserializeOwned + std::fmt::Debug,
+    {
+        self.json_response_body_all_pages_with_limit(endpoint, 100)
+            .await
+    }
+
+    /// use this to get the results for all pages of a paginated JSON response
+    /// as a stream of values instead of collecting them all into a Vec first
+    ///
+    /// by default the next page is only requested once the values of the
+    /// previous page have been consumed, use [AllPagesAsync::with_concurrency]
+    /// to prefetch pages
+    pub fn json_response_body_all_pages_stream<'a, E, R>(
+        &'a self,
+        endpoint: &'a E,
+    ) -> AllPagesAsync<'a, E, R>
+    where
+        E: Endpoint + ReturnsJsonResponse + Pageable + Sync,
+        R: DeserializeOwned + std::fmt::Debug + Send + 'a,
+    {
+        AllPagesAsync::new(self, endpoint)
+    }
+
+    /// use this to get the results for all pages of a paginated JSON response
+    /// with the given number of results per request
+    ///
+    /// the limit is clamped to the range 1 to 100 since Redmine ignores larger limits
+    ///
+    /// # Errors
+    ///
+    /// This can return an error if the endpoint returns an error when creating the
+    /// request body, when any of the web requests fails, when the response can not be
+    /// parsed as a JSON object, when any of the pagination keys or the value key are missing
+    /// in the JSON object or when the values can not be parsed as the result type.
+    ///
+    pub async fn json_response_body_all_pages_with_limit<E, R>(
+        &self,
+        endpoint: &E,
+        limit: u64,
+    ) -> Result<Vec<R>, crate::Error>
     where
         E: Endpoint + ReturnsJsonResponse + Pageable,
         R: DeserializeOwned + std::fmt::Debug,
@@ -715,15 +2158,21 @@ impl RedmineAsync {
         let method = endpoint.method();
         let url = endpoint.endpoint();
         let mut offset = 0;
-        let limit = 100;
+        let limit = limit.clamp(1, 100);
         let mut total_results = vec![];
         loop {
-            let mut page_parameters = endpoint.parameters();
+            let mut page_parameters = all_pages_parameters(endpoint);
             page_parameters.push("offset", offset);
             page_parameters.push("limit", limit);
             let mime_type_and_body = endpoint.body()?;
-            let (status, response_body) = self
-                .rest(method.clone(), &url, page_parameters, mime_type_and_body)
+            let (status, _, response_body) = self
+                .rest(
+                    method.clone(),
+                    &url,
+                    page_parameters,
+                    mime_type_and_body,
+                    endpoint.headers(),
+                )
                 .await?;
             if response_body.is_empty() {
                 return Err(crate::Error::EmptyResponseBody(status));
@@ -732,27 +2181,8 @@ impl RedmineAsync {
                 serde_json::from_slice(&response_body)?;
             let json_object_response_body = json_value_response_body.as_object();
             if let Some(json_object_response_body) = json_object_response_body {
-                let total_count: u64 = json_object_response_body
-                    .get("total_count")
-                    .ok_or_else(|| crate::Error::PaginationKeyMissing("total_count".to_string()))?
-                    .as_u64()
-                    .ok_or_else(|| {
-                        crate::Error::PaginationKeyHasWrongType("total_count".to_string())
-                    })?;
-                let response_offset: u64 = json_object_response_body
-                    .get("offset")
-                    .ok_or_else(|| crate::Error::PaginationKeyMissing("offset".to_string()))?
-                    .as_u64()
-                    .ok_or_else(|| {
-                        crate::Error::PaginationKeyHasWrongType("total_count".to_string())
-                    })?;
-                let response_limit: u64 = json_object_response_body
-                    .get("limit")
-                    .ok_or_else(|| crate::Error::PaginationKeyMissing("limit".to_string()))?
-                    .as_u64()
-                    .ok_or_else(|| {
-                        crate::Error::PaginationKeyHasWrongType("total_count".to_string())
-                    })?;
+                let (total_count, response_offset, response_limit) =
+                    pagination_keys(json_object_response_body, offset, limit)?;
                 let response_wrapper_key = endpoint.response_wrapper_key();
                 let inner_response_body = json_object_response_body
                     .get(&response_wrapper_key)
@@ -770,8 +2200,361 @@ impl RedmineAsync {
                 return Err(crate::Error::NonObjectResponseBody(status));
             }
         }
-        Ok(total_results)
+        Ok(total_results)
+    }
+}
+
+/// An iterator over the values of all pages of a paginated JSON response,
+/// created with [Redmine::json_response_body_all_pages_iter]
+#[derive(derive_more::Debug)]
+pub struct AllPages<'a, E, R> {
+    /// the client used for the requests
+    redmine: &'a Redmine,
+    /// the endpoint to request all pages of
+    #[debug(skip)]
+    endpoint: &'a E,
+    /// the number of values requested per page
+    limit: u64,
+    /// the offset of the next page to request
+    next_offset: u64,
+    /// the total number of values, known once the first page was returned
+    total_count: Option<u64>,
+    /// values from the current page not consumed yet
+    #[debug(skip)]
+    buffer: std::collections::VecDeque<R>,
+    /// set once an error was returned, the iterator ends after that
+    failed: bool,
+}
+
+impl<'a, E, R> AllPages<'a, E, R>
+where
+    E: Endpoint + ReturnsJsonResponse + Pageable,
+    R: DeserializeOwned + std::fmt::Debug,
+{
+    /// creates the iterator, no request is made until next is called
+    fn new(redmine: &'a Redmine, endpoint: &'a E) -> Self {
+        Self {
+            redmine,
+            endpoint,
+            limit: 100,
+            next_offset: 0,
+            total_count: None,
+            buffer: std::collections::VecDeque::new(),
+            failed: false,
+        }
+    }
+
+    /// sets the number of values requested per page
+    ///
+    /// the limit is clamped to the range 1 to 100 since Redmine ignores larger limits
+    #[must_use]
+    pub fn with_limit(mut self, limit: u64) -> Self {
+        self.limit = limit.clamp(1, 100);
+        self
+    }
+
+    /// the total number of values as reported by Redmine
+    ///
+    /// this is None until the first page was fetched by the first call to next
+    #[must_use]
+    pub fn total_count(&self) -> Option<u64> {
+        self.total_count
+    }
+}
+
+impl<E, R> Iterator for AllPages<'_, E, R>
+where
+    E: Endpoint + ReturnsJsonResponse + Pageable,
+    R: DeserializeOwned + std::fmt::Debug,
+{
+    type Item = Result<R, crate::Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+        while self.buffer.is_empty() {
+            if let Some(total_count) = self.total_count {
+                if self.next_offset >= total_count {
+                    return None;
+                }
+            }
+            match self.redmine.json_response_body_page_with_parameters(
+                self.endpoint,
+                all_pages_parameters(self.endpoint),
+                self.next_offset,
+                self.limit,
+            ) {
+                Ok(page) => {
+                    self.total_count = Some(page.total_count);
+                    self.buffer.extend(page.values);
+                    self.next_offset += self.limit;
+                }
+                Err(e) => {
+                    self.failed = true;
+                    return Some(Err(e));
+                }
+            }
+        }
+        self.buffer.pop_front().map(Ok)
+    }
+}
+
+/// the future type used for the page requests in [AllPagesAsync]
+type PageFuture<'a, R> = futures::future::BoxFuture<'a, Result<ResponsePage<R>, crate::Error>>;
+
+/// A stream of the values of all pages of a paginated JSON response, created
+/// with [RedmineAsync::json_response_body_all_pages_stream]
+///
+/// values are always returned in offset order, even if several pages are
+/// requested concurrently
+#[derive(derive_more::Debug)]
+pub struct AllPagesAsync<'a, E, R> {
+    /// the client used for the requests
+    redmine: &'a RedmineAsync,
+    /// the endpoint to request all pages of
+    #[debug(skip)]
+    endpoint: &'a E,
+    /// the number of values requested per page
+    limit: u64,
+    /// the maximum number of page requests in flight at the same time
+    concurrency: usize,
+    /// the offset of the next page to request
+    next_offset: u64,
+    /// the total number of values, known once the first page was returned
+    total_count: Option<u64>,
+    /// values from pages already returned but not consumed yet
+    #[debug(skip)]
+    buffer: std::collections::VecDeque<R>,
+    /// the page requests in flight, in offset order
+    #[debug(skip)]
+    in_flight: futures::stream::FuturesOrdered<PageFuture<'a, R>>,
+    /// an error returned by a page request, returned after the buffered values
+    error: Option<crate::Error>,
+    /// set once the error was returned, the stream ends after that
+    failed: bool,
+}
+
+impl<E, R> Unpin for AllPagesAsync<'_, E, R> {}
+
+impl<'a, E, R> AllPagesAsync<'a, E, R>
+where
+    E: Endpoint + ReturnsJsonResponse + Pageable + Sync,
+    R: DeserializeOwned + std::fmt::Debug + Send + 'a,
+{
+    /// creates the stream, no request is made until it is polled
+    fn new(redmine: &'a RedmineAsync, endpoint: &'a E) -> Self {
+        Self {
+            redmine,
+            endpoint,
+            limit: 100,
+            concurrency: 1,
+            next_offset: 0,
+            total_count: None,
+            buffer: std::collections::VecDeque::new(),
+            in_flight: futures::stream::FuturesOrdered::new(),
+            error: None,
+            failed: false,
+        }
+    }
+
+    /// sets the maximum number of page requests in flight at the same time
+    ///
+    /// with the default of 1 the next page is only requested once all values of
+    /// the previous one have been consumed, with higher values up to
+    /// concurrency - 1 pages are prefetched while the values of the current
+    /// one are consumed. Prefetching starts once the first page revealed the
+    /// total number of values.
+    #[must_use]
+    pub fn with_concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// sets the number of values requested per page
+    ///
+    /// the limit is clamped to the range 1 to 100 since Redmine ignores larger limits
+    #[must_use]
+    pub fn with_limit(mut self, limit: u64) -> Self {
+        self.limit = limit.clamp(1, 100);
+        self
+    }
+
+    /// the total number of values as reported by Redmine
+    ///
+    /// this is None until the first page was returned
+    #[must_use]
+    pub fn total_count(&self) -> Option<u64> {
+        self.total_count
+    }
+
+    /// starts the request for the page at next_offset
+    fn request_page(&mut self) {
+        let redmine = self.redmine;
+        let endpoint = self.endpoint;
+        let offset = self.next_offset;
+        let limit = self.limit;
+        self.in_flight.push_back(Box::pin(async move {
+            redmine
+                .json_response_body_page_with_parameters::<E, R>(
+                    endpoint,
+                    all_pages_parameters(endpoint),
+                    offset,
+                    limit,
+                )
+                .await
+        }));
+        self.next_offset += limit;
+    }
+
+    /// starts as many page requests as the concurrency allows
+    fn request_pages(&mut self) {
+        if self.error.is_some() {
+            return;
+        }
+        match self.total_count {
+            None => {
+                if self.next_offset == 0 {
+                    self.request_page();
+                }
+            }
+            Some(total_count) => {
+                let target = if self.buffer.is_empty() {
+                    self.concurrency
+                } else {
+                    self.concurrency - 1
+                };
+                while self.in_flight.len() < target && self.next_offset < total_count {
+                    self.request_page();
+                }
+            }
+        }
+    }
+}
+
+impl<'a, E, R> futures::Stream for AllPagesAsync<'a, E, R>
+where
+    E: Endpoint + ReturnsJsonResponse + Pageable + Sync,
+    R: DeserializeOwned + std::fmt::Debug + Send + 'a,
+{
+    type Item = Result<R, crate::Error>;
+
+    fn poll_next(
+        mut self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Option<Self::Item>> {
+        let this = &mut *self;
+        if this.failed {
+            return std::task::Poll::Ready(None);
+        }
+        loop {
+            this.request_pages();
+            match this.in_flight.poll_next_unpin(cx) {
+                std::task::Poll::Ready(Some(Ok(page))) => {
+                    this.total_count = Some(page.total_count);
+                    this.buffer.extend(page.values);
+                }
+                std::task::Poll::Ready(Some(Err(e))) => {
+                    this.error = Some(e);
+                    this.in_flight = futures::stream::FuturesOrdered::new();
+                    break;
+                }
+                std::task::Poll::Ready(None) | std::task::Poll::Pending => {
+                    break;
+                }
+            }
+        }
+        if let Some(value) = this.buffer.pop_front() {
+            std::task::Poll::Ready(Some(Ok(value)))
+        } else if let Some(e) = this.error.take() {
+            this.failed = true;
+            std::task::Poll::Ready(Some(Err(e)))
+        } else if this.in_flight.is_empty() {
+            std::task::Poll::Ready(None)
+        } else {
+            std::task::Poll::Pending
+        }
+    }
+}
+
+/// used to deserialize the error messages Redmine returns in the body of
+/// some error responses (e.g. validation errors on create/update)
+#[derive(Debug, Clone, serde::Deserialize)]
+struct ErrorMessagesWrapper {
+    /// the error messages
+    errors: Vec<String>,
+}
+
+/// helper to turn an HTTP error status and the response body into an error,
+/// parsing the error messages from the body if possible
+///
+/// 429 Too Many Requests is turned into [crate::Error::RateLimited] and client
+/// errors with a JSON body containing error messages into
+/// [crate::Error::Validation] instead
+fn http_error_response(
+    status: reqwest::StatusCode,
+    retry_after: Option<std::time::Duration>,
+    response_body: &[u8],
+) -> crate::Error {
+    if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+        return crate::Error::RateLimited { retry_after };
+    }
+    let errors = serde_json::from_slice::<ErrorMessagesWrapper>(response_body)
+        .map(|wrapper| wrapper.errors)
+        .ok();
+    if status.is_client_error() {
+        if let Some(errors) = errors {
+            return crate::Error::Validation {
+                status,
+                errors: crate::RedmineErrors(errors),
+            };
+        }
+    }
+    crate::Error::HttpErrorResponse {
+        status,
+        body: String::from_utf8_lossy(response_body).into_owned(),
+        errors: crate::RedmineErrors(errors.unwrap_or_default()),
+    }
+}
+
+/// helper to resolve the content URL of an attachment against the Redmine URL
+///
+/// # Errors
+///
+/// This returns [crate::Error::ForeignAttachmentUrl] if the resolved URL is not
+/// on the same origin as the Redmine URL
+fn attachment_content_url(redmine_url: &Url, content_url: &str) -> Result<Url, crate::Error> {
+    let url = redmine_url.join(content_url)?;
+    if url.origin() != redmine_url.origin() {
+        return Err(crate::Error::ForeignAttachmentUrl(url));
+    }
+    Ok(url)
+}
+
+/// helper to build the URL for the uploads endpoint with the filename parameter
+fn upload_url(redmine_url: &Url, filename: &str) -> Result<Url, crate::Error> {
+    let mut url = redmine_url.join("uploads.json")?;
+    let mut parameters = QueryParams::default();
+    parameters.push("filename", filename);
+    parameters.add_to_url(&mut url);
+    Ok(url)
+}
+
+/// helper to extract the upload token from the response to a streamed upload
+fn upload_token_from_response(
+    status: reqwest::StatusCode,
+    retry_after: Option<std::time::Duration>,
+    response_body: &[u8],
+) -> Result<uploads::FileUploadToken, crate::Error> {
+    if status.is_client_error() || status.is_server_error() {
+        error!("Redmine status error on upload: {:?}", status);
+        return Err(http_error_response(status, retry_after, response_body));
+    }
+    if response_body.is_empty() {
+        return Err(crate::Error::EmptyResponseBody(status));
     }
+    let uploads::UploadWrapper { upload } = serde_json::from_slice(response_body)?;
+    Ok(upload)
 }
 
 /// A trait representing a parameter value.
@@ -873,7 +2656,7 @@ impl ParamValue<'static> for time::OffsetDateTime {
 
 impl ParamValue<'static> for time::Date {
     fn as_value(&self) -> Cow<'static, str> {
-        let format = time::format_description::parse("[year]-[month]-[day]").unwrap();
+        let format = time::format_description::parse_borrowed::<2>("[year]-[month]-[day]").unwrap();
         self.format(&format).unwrap().into()
     }
 }
@@ -923,6 +2706,12 @@ impl<'a> QueryParams<'a> {
         self
     }
 
+    /// returns true if a parameter with this key was pushed
+    #[must_use]
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.params.iter().any(|(k, _)| k == key)
+    }
+
     /// Add the parameters to a URL.
     pub fn add_to_url(&self, url: &mut Url) {
         let mut pairs = url.query_pairs_mut();
@@ -938,7 +2727,7 @@ pub trait Endpoint {
     fn endpoint(&self) -> Cow<'static, str>;
 
     /// Query parameters for the endpoint.
-    fn parameters(&self) -> QueryParams {
+    fn parameters(&self) -> QueryParams<'_> {
         QueryParams::default()
     }
 
@@ -952,6 +2741,34 @@ pub trait Endpoint {
     fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, crate::Error> {
         Ok(None)
     }
+
+    /// Extra HTTP headers for the endpoint, added after the headers
+    /// configured on the client and sent again on retries.
+    fn headers(&self) -> reqwest::header::HeaderMap {
+        reqwest::header::HeaderMap::new()
+    }
+}
+
+/// the name of the header the idempotency_key of the create endpoints is sent in
+const IDEMPOTENCY_KEY_HEADER: &str = "Idempotency-Key";
+
+/// the headers for the idempotency_key of the create endpoints, also useful
+/// for the headers of a [RawEndpoint]
+///
+/// the key is sent as Idempotency-Key header, Redmine itself ignores it but
+/// gateways which honor it can detect and drop duplicates of a request. The
+/// same key is sent on every retry of a request, which is why a [RetryPolicy]
+/// also retries a create request with a key on 502 Bad Gateway and 504 Gateway
+/// Timeout, without a key those are only retried for idempotent methods.
+#[must_use]
+pub fn idempotency_key_header(
+    idempotency_key: Option<&reqwest::header::HeaderValue>,
+) -> reqwest::header::HeaderMap {
+    let mut headers = reqwest::header::HeaderMap::new();
+    if let Some(idempotency_key) = idempotency_key {
+        headers.insert(IDEMPOTENCY_KEY_HEADER, idempotency_key.clone());
+    }
+    headers
 }
 
 /// A trait to indicate that an endpoint is expected to return a JSON result
@@ -961,6 +2778,229 @@ pub trait ReturnsJsonResponse {}
 pub trait Pageable {
     /// returns the name of the key in the response that contains the list of results
     fn response_wrapper_key(&self) -> String;
+
+    /// a sort order which is applied when all pages are requested
+    /// (json_response_body_all_pages and related methods) and the endpoint
+    /// has no explicit sort
+    ///
+    /// Redmine paginates by offset so values created, deleted or reordered
+    /// between the page requests can shift the pages and values are skipped
+    /// or returned twice. Sorting by an ascending id means new values are
+    /// only ever appended at the end. The trade-off is that the results are
+    /// no longer in the default order of Redmine and deleted values can still
+    /// shift later pages.
+    fn default_stable_sort(&self) -> Option<Cow<'static, str>> {
+        None
+    }
+}
+
+/// an endpoint for arbitrary paths, e.g. for REST endpoints provided by
+/// Redmine plugins which are not modelled in this crate
+///
+/// ```
+/// # use redmine_api::api::RawEndpoint;
+/// let endpoint = RawEndpoint::get("custom_plugin/data.json").with_query_param("foo", "bar");
+/// ```
+#[derive(Debug, Clone)]
+pub struct RawEndpoint<'a> {
+    /// the HTTP method to use
+    method: Method,
+    /// the path relative to the Redmine URL
+    path: String,
+    /// the query parameters
+    query: QueryParams<'a>,
+    /// the JSON request body, if any
+    body: Option<serde_json::Value>,
+}
+
+impl<'a> RawEndpoint<'a> {
+    /// creates an endpoint for the given HTTP method and path (relative to
+    /// the Redmine URL) without query parameters or body
+    #[must_use]
+    pub fn new(method: Method, path: impl Into<String>) -> Self {
+        Self {
+            method,
+            path: path.into(),
+            query: QueryParams::default(),
+            body: None,
+        }
+    }
+
+    /// creates an endpoint for a GET request to the given path
+    #[must_use]
+    pub fn get(path: impl Into<String>) -> Self {
+        Self::new(Method::GET, path)
+    }
+
+    /// adds a query parameter
+    #[must_use]
+    pub fn with_query_param(
+        mut self,
+        key: impl Into<Cow<'a, str>>,
+        value: impl Into<Cow<'a, str>>,
+    ) -> Self {
+        self.query.push(key, value.into());
+        self
+    }
+
+    /// sets the JSON request body
+    #[must_use]
+    pub fn with_body(mut self, body: serde_json::Value) -> Self {
+        self.body = Some(body);
+        self
+    }
+}
+
+impl ReturnsJsonResponse for RawEndpoint<'_> {}
+
+impl Endpoint for RawEndpoint<'_> {
+    fn method(&self) -> Method {
+        self.method.clone()
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        self.path.clone().into()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        self.query.clone()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, crate::Error> {
+        if let Some(body) = &self.body {
+            Ok(Some(("application/json", serde_json::to_vec(body)?)))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+/// a typed numeric issue id, serialized transparently as the number
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
+)]
+#[serde(transparent)]
+pub struct IssueId(pub u64);
+
+impl From<u64> for IssueId {
+    fn from(v: u64) -> Self {
+        IssueId(v)
+    }
+}
+
+impl From<IssueId> for u64 {
+    fn from(v: IssueId) -> Self {
+        v.0
+    }
+}
+
+impl std::fmt::Display for IssueId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// a typed numeric project id, serialized transparently as the number
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
+)]
+#[serde(transparent)]
+pub struct ProjectId(pub u64);
+
+impl From<u64> for ProjectId {
+    fn from(v: u64) -> Self {
+        ProjectId(v)
+    }
+}
+
+impl From<ProjectId> for u64 {
+    fn from(v: ProjectId) -> Self {
+        v.0
+    }
+}
+
+impl std::fmt::Display for ProjectId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// a typed numeric user id, serialized transparently as the number
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
+)]
+#[serde(transparent)]
+pub struct UserId(pub u64);
+
+impl From<u64> for UserId {
+    fn from(v: u64) -> Self {
+        UserId(v)
+    }
+}
+
+impl From<UserId> for u64 {
+    fn from(v: UserId) -> Self {
+        v.0
+    }
+}
+
+impl std::fmt::Display for UserId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// a project as it appears in the URL, either the numeric id or the
+/// identifier (the name in the URL, e.g. sandbox)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ProjectIdentifier<'a> {
+    /// the numeric project id
+    Id(u64),
+    /// the identifier of the project as it appears in the URL
+    Name(Cow<'a, str>),
+}
+
+impl From<u64> for ProjectIdentifier<'_> {
+    fn from(v: u64) -> Self {
+        ProjectIdentifier::Id(v)
+    }
+}
+
+impl From<ProjectId> for ProjectIdentifier<'_> {
+    fn from(v: ProjectId) -> Self {
+        ProjectIdentifier::Id(v.0)
+    }
+}
+
+impl<'a> From<&'a str> for ProjectIdentifier<'a> {
+    fn from(v: &'a str) -> Self {
+        ProjectIdentifier::Name(v.into())
+    }
+}
+
+impl From<String> for ProjectIdentifier<'_> {
+    fn from(v: String) -> Self {
+        ProjectIdentifier::Name(v.into())
+    }
+}
+
+impl<'a> From<Cow<'a, str>> for ProjectIdentifier<'a> {
+    fn from(v: Cow<'a, str>) -> Self {
+        ProjectIdentifier::Name(v)
+    }
+}
+
+impl std::fmt::Display for ProjectIdentifier<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ProjectIdentifier::Id(id) => {
+                write!(f, "{}", id)
+            }
+            ProjectIdentifier::Name(name) => {
+                write!(f, "{}", name)
+            }
+        }
+    }
 }
 
 /// helper to parse created_on and updated_on in the correct format
@@ -1048,3 +3088,912 @@ where
         n.serialize(serializer)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_raw_endpoint() -> Result<(), Box<dyn std::error::Error>> {
+        let endpoint = RawEndpoint::new(Method::POST, "custom_plugin/data.json")
+            .with_query_param("foo", "bar")
+            .with_query_param("baz", String::from("a b"))
+            .with_body(serde_json::json!({ "data": { "value": 1 } }));
+        assert_eq!(endpoint.method(), Method::POST);
+        assert_eq!(endpoint.endpoint(), "custom_plugin/data.json");
+        let mut url = Url::parse("https://redmine.example.com/custom_plugin/data.json")?;
+        endpoint.parameters().add_to_url(&mut url);
+        assert_eq!(url.query(), Some("foo=bar&baz=a+b"));
+        assert_eq!(
+            endpoint.body()?,
+            Some(("application/json", br#"{"data":{"value":1}}"#.to_vec()))
+        );
+        assert_eq!(RawEndpoint::get("x.json").body()?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_response_page_paging_helpers() {
+        let page = |offset, limit, total_count| ResponsePage::<()> {
+            values: Vec::new(),
+            total_count,
+            offset,
+            limit,
+        };
+        assert!(page(0, 25, 60).has_next_page());
+        assert_eq!(page(0, 25, 60).next_offset(), Some(25));
+        assert_eq!(page(0, 25, 60).page_count(), 3);
+        assert!(!page(50, 25, 60).has_next_page());
+        assert_eq!(page(50, 25, 60).next_offset(), None);
+        assert!(!page(25, 25, 50).has_next_page());
+        assert_eq!(page(25, 25, 50).next_offset(), None);
+        assert_eq!(page(25, 25, 50).page_count(), 2);
+        assert!(page(24, 25, 50).has_next_page());
+        assert_eq!(page(24, 25, 50).next_offset(), Some(49));
+        assert_eq!(page(0, 25, 0).page_count(), 0);
+        assert!(!page(0, 0, 10).has_next_page());
+        assert_eq!(page(0, 0, 10).page_count(), 0);
+    }
+
+    #[test]
+    fn test_page_cursor_advancement() -> Result<(), Box<dyn std::error::Error>> {
+        let page = |cursor: PageCursor, total_count| ResponsePage::<()> {
+            values: Vec::new(),
+            total_count,
+            offset: cursor.offset,
+            limit: cursor.limit,
+        };
+        let mut cursor = PageCursor::first(25);
+        let mut offsets = Vec::new();
+        loop {
+            offsets.push(cursor.offset);
+            let Some(next) = page(cursor, 60).next_cursor() else {
+                break;
+            };
+            cursor = next;
+        }
+        assert_eq!(offsets, vec![0, 25, 50]);
+        assert_eq!(page(PageCursor::first(25), 0).next_cursor(), None);
+        let persisted = serde_json::to_string(&PageCursor {
+            offset: 25,
+            limit: 25,
+        })?;
+        assert_eq!(persisted, r#"{"offset":25,"limit":25}"#);
+        assert_eq!(
+            serde_json::from_str::<PageCursor>(&persisted)?,
+            PageCursor {
+                offset: 25,
+                limit: 25
+            }
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_all_pages_parameters_stable_sort() -> Result<(), Box<dyn std::error::Error>> {
+        let query = |parameters: QueryParams<'_>| -> Result<Option<String>, url::ParseError> {
+            let mut url = Url::parse("https://redmine.example.com/issues.json")?;
+            parameters.add_to_url(&mut url);
+            Ok(url
+                .query()
+                .filter(|q| !q.is_empty())
+                .map(ToString::to_string))
+        };
+        let endpoint = issues::ListIssues::builder().build()?;
+        assert_eq!(query(endpoint.parameters())?, None);
+        assert_eq!(
+            query(all_pages_parameters(&endpoint))?,
+            Some("sort=id".to_string())
+        );
+        let endpoint = issues::ListIssues::builder()
+            .sort(vec![issues::SortByColumn::Reverse {
+                column_name: issues::IssueSortColumn::UpdatedOn,
+            }])
+            .build()?;
+        assert_eq!(
+            query(all_pages_parameters(&endpoint))?,
+            Some("sort=updated_on%3Adesc".to_string())
+        );
+        let endpoint = users::ListUsers::builder().build()?;
+        assert_eq!(query(all_pages_parameters(&endpoint))?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_create_issue_idempotency_key_header() -> Result<(), Box<dyn std::error::Error>> {
+        let endpoint = issues::CreateIssue::builder()
+            .project_id(1)
+            .idempotency_key(reqwest::header::HeaderValue::from_static("create-42"))
+            .build()?;
+        let headers = endpoint.headers();
+        assert_eq!(
+            headers.get("Idempotency-Key").map(|v| v.as_bytes()),
+            Some(b"create-42".as_slice())
+        );
+        assert_eq!(
+            endpoint.body()?,
+            Some((
+                "application/json",
+                br#"{"issue":{"project_id":1}}"#.to_vec()
+            ))
+        );
+        let endpoint = issues::CreateIssue::builder().project_id(1).build()?;
+        assert!(endpoint.headers().is_empty());
+        Ok(())
+    }
+
+    #[tracing_test::traced_test]
+    #[test]
+    fn test_trace_bodies() {
+        trace_request_body("application/json", br#"{"issue":{}}"#);
+        trace_response_body(b"\xff\xfe");
+        assert!(logs_contain(
+            r#"Request body (Content-Type: application/json):"#
+        ));
+        assert!(logs_contain("could not be parsed as utf8"));
+    }
+
+    #[test]
+    fn test_parse_json_response_body_no_content() -> Result<(), Box<dyn std::error::Error>> {
+        assert_eq!(
+            parse_json_response_body::<serde_json::Value>(reqwest::StatusCode::NO_CONTENT, b"")?,
+            None
+        );
+        assert!(matches!(
+            parse_json_response_body::<serde_json::Value>(reqwest::StatusCode::CREATED, b""),
+            Err(crate::Error::EmptyResponseBody(
+                reqwest::StatusCode::CREATED
+            ))
+        ));
+        assert_eq!(
+            parse_json_response_body::<serde_json::Value>(reqwest::StatusCode::OK, br#"{"a":1}"#)?,
+            Some(serde_json::json!({ "a": 1 }))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_pagination_keys() -> Result<(), Box<dyn std::error::Error>> {
+        let body = serde_json::json!({ "items": [], "total_count": 30, "offset": 25 });
+        let body = body.as_object().ok_or("not an object")?;
+        assert_eq!(pagination_keys(body, 25, 10)?, (30, 25, 10));
+        let body = serde_json::json!({ "items": [], "total_count": 30 });
+        let body = body.as_object().ok_or("not an object")?;
+        assert_eq!(pagination_keys(body, 50, 25)?, (30, 50, 25));
+        let body = serde_json::json!({ "items": [], "offset": 0, "limit": 25 });
+        let body = body.as_object().ok_or("not an object")?;
+        assert!(matches!(
+            pagination_keys(body, 0, 25),
+            Err(crate::Error::PaginationKeyMissing(key)) if key == "total_count"
+        ));
+        let body = serde_json::json!({ "items": [], "total_count": 30, "limit": "25" });
+        let body = body.as_object().ok_or("not an object")?;
+        assert!(matches!(
+            pagination_keys(body, 0, 25),
+            Err(crate::Error::PaginationKeyHasWrongType(key)) if key == "limit"
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_upload_token_from_response() -> Result<(), Box<dyn std::error::Error>> {
+        let body = br#"{"upload":{"token":"7167.ed1ccdb093229ca1bd0b043618d88743"}}"#;
+        let token = upload_token_from_response(reqwest::StatusCode::CREATED, None, body)?;
+        assert_eq!(token.token, "7167.ed1ccdb093229ca1bd0b043618d88743");
+        let url = upload_url(&Url::parse("https://redmine.example.com/")?, "a b.txt")?;
+        assert_eq!(
+            url.as_str(),
+            "https://redmine.example.com/uploads.json?filename=a+b.txt"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_http_error_response_parses_error_messages() {
+        let body = br#"{"errors":["Subject cannot be blank","Tracker cannot be blank"]}"#;
+        let error = http_error_response(reqwest::StatusCode::UNPROCESSABLE_ENTITY, None, body);
+        assert_eq!(
+            error.to_string(),
+            "Redmine rejected the request with status 422 Unprocessable Entity: Subject cannot be blank; Tracker cannot be blank"
+        );
+        match error {
+            crate::Error::Validation { status, errors } => {
+                assert_eq!(status, reqwest::StatusCode::UNPROCESSABLE_ENTITY);
+                assert_eq!(
+                    errors,
+                    crate::RedmineErrors(vec![
+                        "Subject cannot be blank".to_string(),
+                        "Tracker cannot be blank".to_string()
+                    ])
+                );
+            }
+            e => panic!("unexpected error variant {:?}", e),
+        }
+    }
+
+    #[test]
+    fn test_http_error_response_falls_back_for_unexpected_bodies() {
+        let body = br#"{"errors":["Internal error"]}"#;
+        let error = http_error_response(reqwest::StatusCode::INTERNAL_SERVER_ERROR, None, body);
+        assert_eq!(
+            error.to_string(),
+            r#"HTTP error response with status 500 Internal Server Error: {"errors":["Internal error"]}"#
+        );
+        assert!(matches!(
+            error,
+            crate::Error::HttpErrorResponse { status, errors, .. }
+                if status == reqwest::StatusCode::INTERNAL_SERVER_ERROR
+                    && errors == crate::RedmineErrors(vec!["Internal error".to_string()])
+        ));
+        let body = b"<html>Unprocessable</html>";
+        let error = http_error_response(reqwest::StatusCode::UNPROCESSABLE_ENTITY, None, body);
+        assert!(matches!(
+            error,
+            crate::Error::HttpErrorResponse { status, body, errors }
+                if status == reqwest::StatusCode::UNPROCESSABLE_ENTITY
+                    && errors.0.is_empty()
+                    && body == "<html>Unprocessable</html>"
+        ));
+    }
+
+    #[test]
+    fn test_http_error_response_display_truncates_body() {
+        let body = "ä".repeat(150);
+        let error = http_error_response(reqwest::StatusCode::BAD_GATEWAY, None, body.as_bytes());
+        assert_eq!(
+            error.to_string(),
+            format!(
+                "HTTP error response with status 502 Bad Gateway: {}...",
+                "ä".repeat(100)
+            )
+        );
+    }
+
+    #[test]
+    fn test_batch_request_failed_display() {
+        let error = crate::Error::BatchRequestFailed {
+            index: 2,
+            id: Some(5),
+            source: Box::new(http_error_response(
+                reqwest::StatusCode::NOT_FOUND,
+                None,
+                b"",
+            )),
+        };
+        assert_eq!(
+            error.to_string(),
+            "request 2 (id 5) in batch failed: HTTP error response with status 404 Not Found: "
+        );
+    }
+
+    #[test]
+    fn test_http_error_response_without_json_body() {
+        let error = http_error_response(reqwest::StatusCode::NOT_FOUND, None, b"");
+        assert!(error.is_not_found());
+        assert!(matches!(
+            error,
+            crate::Error::HttpErrorResponse { status, .. }
+                if status == reqwest::StatusCode::NOT_FOUND
+        ));
+    }
+
+    #[test]
+    fn test_http_error_response_rate_limited() {
+        let error = http_error_response(
+            reqwest::StatusCode::TOO_MANY_REQUESTS,
+            Some(std::time::Duration::from_secs(5)),
+            b"",
+        );
+        assert!(matches!(
+            error,
+            crate::Error::RateLimited { retry_after: Some(retry_after) }
+                if retry_after == std::time::Duration::from_secs(5)
+        ));
+        assert!(!error.is_not_found());
+    }
+
+    #[test]
+    fn test_retry_policy_exponential_backoff() {
+        let retry_policy = RetryPolicy::default();
+        let headers = reqwest::header::HeaderMap::new();
+        let status = reqwest::StatusCode::SERVICE_UNAVAILABLE;
+        assert_eq!(
+            retry_policy.retry_delay(1, &reqwest::Method::GET, &headers, status, None),
+            Some(std::time::Duration::from_secs(1))
+        );
+        assert_eq!(
+            retry_policy.retry_delay(2, &reqwest::Method::GET, &headers, status, None),
+            Some(std::time::Duration::from_secs(2))
+        );
+        assert_eq!(
+            retry_policy.retry_delay(3, &reqwest::Method::GET, &headers, status, None),
+            None
+        );
+    }
+
+    #[test]
+    fn test_retry_policy_only_retries_transient_errors() {
+        let retry_policy = RetryPolicy::default();
+        let headers = reqwest::header::HeaderMap::new();
+        assert_eq!(
+            retry_policy.retry_delay(
+                1,
+                &reqwest::Method::GET,
+                &headers,
+                reqwest::StatusCode::INTERNAL_SERVER_ERROR,
+                None
+            ),
+            None
+        );
+        assert_eq!(
+            retry_policy.retry_delay(
+                1,
+                &reqwest::Method::GET,
+                &headers,
+                reqwest::StatusCode::UNPROCESSABLE_ENTITY,
+                None
+            ),
+            None
+        );
+        assert_eq!(
+            retry_policy.retry_delay(
+                1,
+                &reqwest::Method::GET,
+                &headers,
+                reqwest::StatusCode::TOO_MANY_REQUESTS,
+                Some(std::time::Duration::from_secs(30))
+            ),
+            Some(std::time::Duration::from_secs(30))
+        );
+    }
+
+    #[test]
+    fn test_retry_policy_max_delay() {
+        let retry_policy = RetryPolicy {
+            max_attempts: 10,
+            max_delay: std::time::Duration::from_secs(5),
+            ..RetryPolicy::default()
+        };
+        let status = reqwest::StatusCode::SERVICE_UNAVAILABLE;
+        let headers = reqwest::header::HeaderMap::new();
+        assert_eq!(
+            retry_policy.retry_delay(3, &reqwest::Method::GET, &headers, status, None),
+            Some(std::time::Duration::from_secs(4))
+        );
+        assert_eq!(
+            retry_policy.retry_delay(4, &reqwest::Method::GET, &headers, status, None),
+            Some(std::time::Duration::from_secs(5))
+        );
+        assert_eq!(
+            retry_policy.retry_delay(
+                1,
+                &reqwest::Method::GET,
+                &headers,
+                reqwest::StatusCode::TOO_MANY_REQUESTS,
+                Some(std::time::Duration::from_secs(3600))
+            ),
+            Some(std::time::Duration::from_secs(5))
+        );
+    }
+
+    #[test]
+    fn test_retry_policy_gateway_errors_only_for_idempotent_requests() {
+        let retry_policy = RetryPolicy::default();
+        let headers = reqwest::header::HeaderMap::new();
+        for status in [
+            reqwest::StatusCode::BAD_GATEWAY,
+            reqwest::StatusCode::GATEWAY_TIMEOUT,
+        ] {
+            assert_eq!(
+                retry_policy.retry_delay(1, &reqwest::Method::POST, &headers, status, None),
+                None
+            );
+            for method in [
+                reqwest::Method::GET,
+                reqwest::Method::PUT,
+                reqwest::Method::DELETE,
+            ] {
+                assert_eq!(
+                    retry_policy.retry_delay(1, &method, &headers, status, None),
+                    Some(std::time::Duration::from_secs(1))
+                );
+            }
+        }
+        assert_eq!(
+            retry_policy.retry_delay(
+                1,
+                &reqwest::Method::POST,
+                &headers,
+                reqwest::StatusCode::SERVICE_UNAVAILABLE,
+                None
+            ),
+            Some(std::time::Duration::from_secs(1))
+        );
+        let headers = idempotency_key_header(Some(&reqwest::header::HeaderValue::from_static(
+            "create-42",
+        )));
+        assert_eq!(
+            retry_policy.retry_delay(
+                1,
+                &reqwest::Method::POST,
+                &headers,
+                reqwest::StatusCode::BAD_GATEWAY,
+                None
+            ),
+            Some(std::time::Duration::from_secs(1))
+        );
+    }
+
+    #[test]
+    fn test_attachment_content_url() -> Result<(), Box<dyn std::error::Error>> {
+        let redmine_url = Url::parse("https://redmine.example.com/")?;
+        assert_eq!(
+            attachment_content_url(&redmine_url, "/attachments/download/5/file.txt")?.as_str(),
+            "https://redmine.example.com/attachments/download/5/file.txt"
+        );
+        assert_eq!(
+            attachment_content_url(
+                &redmine_url,
+                "https://redmine.example.com/attachments/download/5/file.txt"
+            )?
+            .as_str(),
+            "https://redmine.example.com/attachments/download/5/file.txt"
+        );
+        for content_url in [
+            "https://attacker.example.com/attachments/download/5/file.txt",
+            "http://redmine.example.com/attachments/download/5/file.txt",
+            "//attacker.example.com/attachments/download/5/file.txt",
+        ] {
+            assert!(matches!(
+                attachment_content_url(&redmine_url, content_url),
+                Err(crate::Error::ForeignAttachmentUrl(_))
+            ));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_retry_after() {
+        let mut headers = reqwest::header::HeaderMap::new();
+        assert_eq!(parse_retry_after(&headers), None);
+        headers.insert(
+            reqwest::header::RETRY_AFTER,
+            reqwest::header::HeaderValue::from_static("120"),
+        );
+        assert_eq!(
+            parse_retry_after(&headers),
+            Some(std::time::Duration::from_secs(120))
+        );
+        headers.insert(
+            reqwest::header::RETRY_AFTER,
+            reqwest::header::HeaderValue::from_static("Wed, 21 Oct 2015 07:28:00 GMT"),
+        );
+        assert_eq!(parse_retry_after(&headers), Some(std::time::Duration::ZERO));
+    }
+
+    #[test]
+    fn test_stop_impersonating() -> Result<(), Box<dyn std::error::Error>> {
+        let url = Url::parse("https://redmine.example.com/issues.json")?;
+        let mut redmine = Redmine::new(Url::parse("https://redmine.example.com/")?, "0123")?;
+        redmine.impersonate_user(5);
+        let request = redmine.request(Method::GET, url.clone()).build()?;
+        assert_eq!(
+            request.headers().get("X-Redmine-Switch-User"),
+            Some(&reqwest::header::HeaderValue::from_static("5"))
+        );
+        redmine.stop_impersonating();
+        let request = redmine.request(Method::GET, url).build()?;
+        assert_eq!(request.headers().get("X-Redmine-Switch-User"), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_timeout() -> Result<(), Box<dyn std::error::Error>> {
+        let url = Url::parse("https://redmine.example.com/issues.json")?;
+        let mut redmine = Redmine::builder(Url::parse("https://redmine.example.com/")?, "0123")
+            .timeout(std::time::Duration::from_secs(5))
+            .build()?;
+        let request = redmine.request(Method::GET, url.clone()).build()?;
+        assert_eq!(request.timeout(), Some(&std::time::Duration::from_secs(5)));
+        redmine.set_timeout(None);
+        let request = redmine.request(Method::GET, url.clone()).build()?;
+        assert_eq!(request.timeout(), None);
+        let mut redmine = RedmineAsync::new(Url::parse("https://redmine.example.com/")?, "0123")?;
+        redmine.set_timeout(Some(std::time::Duration::from_millis(500)));
+        let request = redmine.request(Method::GET, url).build()?;
+        assert_eq!(
+            request.timeout(),
+            Some(&std::time::Duration::from_millis(500))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_extra_headers() -> Result<(), Box<dyn std::error::Error>> {
+        let url = Url::parse("https://redmine.example.com/issues.json")?;
+        let mut redmine = Redmine::builder(Url::parse("https://redmine.example.com/")?, "0123")
+            .header(
+                reqwest::header::HeaderName::from_static("x-auth-gateway"),
+                reqwest::header::HeaderValue::from_static("secret"),
+            )
+            .build()?;
+        redmine.add_header(
+            reqwest::header::HeaderName::from_static("x-redmine-api-key"),
+            reqwest::header::HeaderValue::from_static("other"),
+        );
+        let request = redmine.request(Method::GET, url.clone()).build()?;
+        assert_eq!(
+            request.headers().get("X-Auth-Gateway"),
+            Some(&reqwest::header::HeaderValue::from_static("secret"))
+        );
+        assert_eq!(
+            request
+                .headers()
+                .get_all("x-redmine-api-key")
+                .iter()
+                .collect::<Vec<_>>(),
+            vec![&reqwest::header::HeaderValue::from_static("0123")]
+        );
+        let mut redmine = RedmineAsync::new(Url::parse("https://redmine.example.com/")?, "0123")?;
+        redmine.add_header(
+            reqwest::header::HeaderName::from_static("x-auth-gateway"),
+            reqwest::header::HeaderValue::from_static("secret"),
+        );
+        let request = redmine.request(Method::GET, url).build()?;
+        assert_eq!(
+            request.headers().get("X-Auth-Gateway"),
+            Some(&reqwest::header::HeaderValue::from_static("secret"))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_stop_impersonating_async() -> Result<(), Box<dyn std::error::Error>> {
+        let url = Url::parse("https://redmine.example.com/issues.json")?;
+        let mut redmine = RedmineAsync::new(Url::parse("https://redmine.example.com/")?, "0123")?;
+        redmine.impersonate_login("jsmith");
+        let request = redmine.request(Method::GET, url.clone()).build()?;
+        assert_eq!(
+            request.headers().get("X-Redmine-Switch-User"),
+            Some(&reqwest::header::HeaderValue::from_static("jsmith"))
+        );
+        redmine.stop_impersonating();
+        let request = redmine.request(Method::GET, url).build()?;
+        assert_eq!(request.headers().get("X-Redmine-Switch-User"), None);
+        Ok(())
+    }
+
+    /// a minimal HTTP/1.1 server answering every request on every connection
+    /// with the response returned by respond for the request head (request
+    /// line and headers), returns the address and the counter of accepted
+    /// connections
+    fn serve_http<F, R>(
+        respond: F,
+    ) -> Result<
+        (
+            std::net::SocketAddr,
+            std::sync::Arc<std::sync::atomic::AtomicUsize>,
+        ),
+        std::io::Error,
+    >
+    where
+        F: Fn(&str) -> R + Send + Sync + 'static,
+        R: AsRef<[u8]>,
+    {
+        use std::io::{BufRead, Write};
+        let listener = std::net::TcpListener::bind("127.0.0.1:0")?;
+        let addr = listener.local_addr()?;
+        let connections = std::sync::Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let accepted = connections.clone();
+        let respond = std::sync::Arc::new(respond);
+        std::thread::spawn(move || {
+            for stream in listener.incoming().map_while(Result::ok) {
+                accepted.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                let respond = respond.clone();
+                std::thread::spawn(move || -> Result<(), std::io::Error> {
+                    let mut reader = std::io::BufReader::new(stream.try_clone()?);
+                    let mut stream = stream;
+                    loop {
+                        let mut head = String::new();
+                        if reader.read_line(&mut head)? == 0 {
+                            return Ok(());
+                        }
+                        let mut header_line = String::new();
+                        while reader.read_line(&mut header_line)? > 2 {
+                            head.push_str(&header_line);
+                            header_line.clear();
+                        }
+                        stream.write_all(respond(&head).as_ref())?;
+                        stream.flush()?;
+                    }
+                });
+            }
+        });
+        Ok((addr, connections))
+    }
+
+    /// formats a HTTP/1.1 response for [serve_http]
+    fn http_response(
+        status_line: &str,
+        headers: &[(&str, &str)],
+        body: impl AsRef<[u8]>,
+    ) -> Vec<u8> {
+        let body = body.as_ref();
+        let mut response = format!("HTTP/1.1 {}\r\n", status_line);
+        for (name, value) in headers {
+            response.push_str(&format!("{}: {}\r\n", name, value));
+        }
+        response.push_str(&format!("Content-Length: {}\r\n\r\n", body.len()));
+        let mut response = response.into_bytes();
+        response.extend_from_slice(body);
+        response
+    }
+
+    /// a server accepting a single streamed (chunked) upload, returns the
+    /// address and a receiver for the uploaded content
+    fn serve_chunked_upload(
+    ) -> Result<(std::net::SocketAddr, std::sync::mpsc::Receiver<Vec<u8>>), std::io::Error> {
+        use std::io::{BufRead, Read, Write};
+        let listener = std::net::TcpListener::bind("127.0.0.1:0")?;
+        let addr = listener.local_addr()?;
+        let (sender, receiver) = std::sync::mpsc::channel();
+        std::thread::spawn(move || -> Result<(), std::io::Error> {
+            let (stream, _) = listener.accept()?;
+            let mut reader = std::io::BufReader::new(stream.try_clone()?);
+            let mut stream = stream;
+            let mut line = String::new();
+            while reader.read_line(&mut line)? > 2 {
+                line.clear();
+            }
+            let mut content = Vec::new();
+            loop {
+                line.clear();
+                reader.read_line(&mut line)?;
+                let size = usize::from_str_radix(line.trim(), 16)
+                    .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+                let mut chunk = vec![0; size + 2];
+                reader.read_exact(&mut chunk)?;
+                if size == 0 {
+                    break;
+                }
+                content.extend_from_slice(&chunk[..size]);
+            }
+            let _ = sender.send(content);
+            stream.write_all(&http_response(
+                "201 Created",
+                &[("Content-Type", "application/json")],
+                r#"{"upload":{"token":"7.ed32257a2ab0f7526c0d72c32994c58b131bb2c0775f7aa84aae01ea8397ea54"}}"#,
+            ))?;
+            stream.flush()
+        });
+        Ok((addr, receiver))
+    }
+
+    #[tokio::test]
+    async fn test_upload_file_from_reader_async() -> Result<(), Box<dyn std::error::Error>> {
+        let (addr, receiver) = serve_chunked_upload()?;
+        let redmine = RedmineAsync::new(Url::parse(&format!("http://{}/", addr))?, "0123")?;
+        let content = b"streamed upload content".repeat(1000);
+        let uploads::FileUploadToken { token } = redmine
+            .upload_file_from_reader(std::io::Cursor::new(content.clone()), "test.txt")
+            .await?;
+        assert_eq!(
+            token,
+            "7.ed32257a2ab0f7526c0d72c32994c58b131bb2c0775f7aa84aae01ea8397ea54"
+        );
+        assert_eq!(receiver.recv()?, content);
+        Ok(())
+    }
+
+    /// a server for [serve_http] answering with pages of a paginated list
+    /// of three project memberships
+    fn serve_memberships_pages() -> Result<
+        (
+            std::net::SocketAddr,
+            std::sync::Arc<std::sync::atomic::AtomicUsize>,
+        ),
+        std::io::Error,
+    > {
+        serve_http(|head| {
+            let offset: u64 = head
+                .split(['?', '&', ' '])
+                .find_map(|p| p.strip_prefix("offset="))
+                .and_then(|o| o.parse().ok())
+                .unwrap_or(0);
+            let items = if offset < 3 {
+                serde_json::json!([{ "id": offset + 1 }])
+            } else {
+                serde_json::json!([])
+            };
+            let body = serde_json::json!({
+                "memberships": items,
+                "total_count": 3,
+                "offset": offset,
+                "limit": 1
+            });
+            http_response(
+                "200 OK",
+                &[("Content-Type", "application/json")],
+                body.to_string(),
+            )
+        })
+    }
+
+    #[test]
+    fn test_blocking_client_reuses_connection_across_pages(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let (addr, connections) = serve_memberships_pages()?;
+        let redmine = Redmine::new(Url::parse(&format!("http://{}/", addr))?, "0123")?;
+        let endpoint = project_memberships::ListProjectMemberships::builder()
+            .project_id_or_name("sandbox")
+            .build()?;
+        let items: Vec<serde_json::Value> =
+            redmine.json_response_body_all_pages_with_limit(&endpoint, 1)?;
+        assert_eq!(items.len(), 3);
+        let total: u64 = redmine.count(&endpoint)?;
+        assert_eq!(total, 3);
+        assert_eq!(connections.load(std::sync::atomic::Ordering::SeqCst), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_json_response_body_gzip() -> Result<(), Box<dyn std::error::Error>> {
+        use std::io::Write;
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(br#"{"issue":{"id":1,"subject":"compressed"}}"#)?;
+        let body = encoder.finish()?;
+        let (addr, _) = serve_http(move |head| {
+            if head.to_lowercase().contains("accept-encoding: gzip") {
+                http_response(
+                    "200 OK",
+                    &[
+                        ("Content-Type", "application/json"),
+                        ("Content-Encoding", "gzip"),
+                    ],
+                    &body,
+                )
+            } else {
+                http_response("406 Not Acceptable", &[], "")
+            }
+        })?;
+        let redmine = Redmine::new(Url::parse(&format!("http://{}/", addr))?, "0123")?;
+        let value = redmine
+            .json_response_body::<_, serde_json::Value>(&RawEndpoint::get("issues/1.json"))?;
+        assert_eq!(
+            value,
+            serde_json::json!({ "issue": { "id": 1, "subject": "compressed" } })
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_json_response_with_meta() -> Result<(), Box<dyn std::error::Error>> {
+        let (addr, _) = serve_http(|_| {
+            http_response(
+                "201 Created",
+                &[
+                    ("Content-Type", "application/json"),
+                    ("ETag", "\"abc\""),
+                    ("Last-Modified", "Wed, 01 May 2024 12:00:00 GMT"),
+                ],
+                r#"{"a":1}"#,
+            )
+        })?;
+        let redmine = Redmine::new(Url::parse(&format!("http://{}/", addr))?, "0123")?;
+        let (value, meta) =
+            redmine.json_response_with_meta::<_, serde_json::Value>(&RawEndpoint::get("a.json"))?;
+        assert_eq!(value, serde_json::json!({ "a": 1 }));
+        assert_eq!(meta.status, reqwest::StatusCode::CREATED);
+        assert_eq!(
+            meta.etag,
+            Some(reqwest::header::HeaderValue::from_static("\"abc\""))
+        );
+        assert_eq!(
+            meta.last_modified,
+            Some(reqwest::header::HeaderValue::from_static(
+                "Wed, 01 May 2024 12:00:00 GMT"
+            ))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_json_response_body_conditional() -> Result<(), Box<dyn std::error::Error>> {
+        let (addr, _) = serve_http(|head| {
+            if head
+                .to_ascii_lowercase()
+                .contains("if-none-match: \"abc\"\r\n")
+            {
+                http_response("304 Not Modified", &[("ETag", "\"abc\"")], "")
+            } else {
+                http_response(
+                    "200 OK",
+                    &[("Content-Type", "application/json"), ("ETag", "\"abc\"")],
+                    r#"{"a":1}"#,
+                )
+            }
+        })?;
+        let redmine = Redmine::new(Url::parse(&format!("http://{}/", addr))?, "0123")?;
+        let endpoint = RawEndpoint::get("a.json");
+        let Some((value, meta)) = redmine.json_response_body_conditional::<_, serde_json::Value>(
+            &endpoint,
+            &ConditionalRequest::default(),
+        )?
+        else {
+            panic!("unconditional request should return the body");
+        };
+        assert_eq!(value, serde_json::json!({ "a": 1 }));
+        let not_modified = redmine.json_response_body_conditional::<_, serde_json::Value>(
+            &endpoint,
+            &ConditionalRequest::from(&meta),
+        )?;
+        assert_eq!(not_modified, None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_web_urls() -> Result<(), Box<dyn std::error::Error>> {
+        let redmine = Redmine::new(Url::parse("https://redmine.example.com/")?, "0123")?;
+        assert_eq!(
+            redmine.project_url("sandbox").as_str(),
+            "https://redmine.example.com/projects/sandbox"
+        );
+        assert_eq!(
+            redmine.version_url(12).as_str(),
+            "https://redmine.example.com/versions/12"
+        );
+        assert_eq!(
+            redmine.user_url(5).as_str(),
+            "https://redmine.example.com/users/5"
+        );
+        assert_eq!(
+            redmine
+                .wiki_page_url("sandbox", "Release Notes/2.0")
+                .as_str(),
+            "https://redmine.example.com/projects/sandbox/wiki/Release%20Notes%2F2.0"
+        );
+        let redmine = RedmineAsync::new(Url::parse("https://redmine.example.com/")?, "0123")?;
+        assert_eq!(
+            redmine.wiki_page_url("sandbox", "Wiki").as_str(),
+            "https://redmine.example.com/projects/sandbox/wiki/Wiki"
+        );
+        assert_eq!(
+            redmine.issue_url(3).as_str(),
+            "https://redmine.example.com/issues/3"
+        );
+        for base in [
+            "https://example.com/redmine/",
+            "https://example.com/redmine",
+        ] {
+            let redmine = Redmine::new(Url::parse(base)?, "0123")?;
+            assert_eq!(
+                redmine.project_url("sandbox").as_str(),
+                "https://example.com/redmine/projects/sandbox"
+            );
+            assert_eq!(
+                redmine.wiki_page_url("sandbox", "Wiki").as_str(),
+                "https://example.com/redmine/projects/sandbox/wiki/Wiki"
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_redmine_builder() -> Result<(), Box<dyn std::error::Error>> {
+        let redmine = Redmine::builder(Url::parse("https://redmine.example.com/")?, "0123")
+            .client(reqwest::blocking::Client::new())
+            .impersonate_user(5)
+            .retry_policy(RetryPolicy::default())
+            .build()?;
+        assert_eq!(redmine.impersonation, Some(Impersonation::UserId(5)));
+        assert!(redmine.retry_policy.is_some());
+        let url = Url::parse("https://redmine.example.com/issues.json")?;
+        let request = redmine.request(Method::GET, url).build()?;
+        assert_eq!(
+            request.headers().get("x-redmine-api-key"),
+            Some(&reqwest::header::HeaderValue::from_static("0123"))
+        );
+        Ok(())
+    }
+}
@@ -5,6 +5,7 @@
 //! - [x] specific attachment endpoint
 //! - [ ] update attachment endpoint (not documented and the link to the issue in the wiki points to an issue about something else)
 //! - [x] delete attachment endpoint
+//! - [x] download attachment content (Redmine::download_attachment)
 
 use derive_builder::Builder;
 use reqwest::Method;
@@ -119,6 +120,19 @@ mod test {
         Ok(())
     }
 
+    #[traced_test]
+    #[test]
+    fn test_download_attachment() -> Result<(), Box<dyn Error>> {
+        dotenvy::dotenv()?;
+        let redmine = crate::api::Redmine::from_env()?;
+        let endpoint = GetAttachment::builder().id(3).build()?;
+        let AttachmentWrapper { attachment } =
+            redmine.json_response_body::<_, AttachmentWrapper<Attachment>>(&endpoint)?;
+        let content = redmine.download_attachment(&attachment)?;
+        assert_eq!(content.len() as u64, attachment.filesize);
+        Ok(())
+    }
+
     /// this tests if any of the results contain a field we are not deserializing
     ///
     /// this will only catch fields we missed if they are part of the response but
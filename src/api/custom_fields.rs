@@ -121,6 +121,16 @@ pub struct CustomField {
     pub projects: Option<Vec<ProjectEssentials>>,
 }
 
+/// a minimal type for Redmine custom fields used in lists of custom fields
+/// included in other Redmine objects (e.g. issue custom fields of a project)
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct CustomFieldEssentials {
+    /// numeric id
+    pub id: u64,
+    /// display name
+    pub name: String,
+}
+
 /// a type for custom field essentials with values used in other Redmine
 /// objects (e.g. issues)
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -292,6 +302,8 @@ impl<'de> serde::Deserialize<'de> for CustomFieldEssentialsWithValue {
 }
 
 /// The endpoint for all custom fields
+///
+/// this requires admin privileges, Redmine does not paginate this endpoint
 #[derive(Debug, Clone, Builder)]
 #[builder(setter(strip_option))]
 pub struct ListCustomFields {}
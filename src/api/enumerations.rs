@@ -51,6 +51,9 @@ pub struct IssuePriority {
     pub name: String,
     /// whether this value is the default value
     pub is_default: bool,
+    /// whether this value is active, only included in recent Redmine versions
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
 }
 
 /// The endpoint for all issue priorities
@@ -124,6 +127,9 @@ pub struct TimeEntryActivity {
     pub name: String,
     /// whether this value is the default value
     pub is_default: bool,
+    /// whether this value is active, only included in recent Redmine versions
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
 }
 
 /// The endpoint for all time entry activities
@@ -197,6 +203,9 @@ pub struct DocumentCategory {
     pub name: String,
     /// whether this value is the default value
     pub is_default: bool,
+    /// whether this value is active, only included in recent Redmine versions
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
 }
 
 /// The endpoint for all document categories
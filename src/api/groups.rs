@@ -141,7 +141,7 @@ impl Endpoint for GetGroup {
         format!("groups/{}.json", &self.id).into()
     }
 
-    fn parameters(&self) -> QueryParams {
+    fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
         params.push_opt("include", self.include.as_ref());
         params
@@ -149,6 +149,7 @@ impl Endpoint for GetGroup {
 }
 
 /// The endpoint to create a Redmine group
+#[serde_with::skip_serializing_none]
 #[derive(Debug, Clone, Builder, Serialize)]
 #[builder(setter(strip_option))]
 pub struct CreateGroup<'a> {
@@ -158,6 +159,10 @@ pub struct CreateGroup<'a> {
     /// user ids of users to put in the group initially
     #[builder(default)]
     user_ids: Option<Vec<u64>>,
+    /// see [idempotency_key_header](crate::api::idempotency_key_header)
+    #[builder(default)]
+    #[serde(skip_serializing)]
+    idempotency_key: Option<reqwest::header::HeaderValue>,
 }
 
 impl ReturnsJsonResponse for CreateGroup<'_> {}
@@ -179,6 +184,10 @@ impl Endpoint for CreateGroup<'_> {
         "groups.json".into()
     }
 
+    fn headers(&self) -> reqwest::header::HeaderMap {
+        crate::api::idempotency_key_header(self.idempotency_key.as_ref())
+    }
+
     fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, crate::Error> {
         Ok(Some((
             "application/json",
@@ -190,6 +199,7 @@ impl Endpoint for CreateGroup<'_> {
 }
 
 /// The endpoint to update an existing Redmine group
+#[serde_with::skip_serializing_none]
 #[derive(Debug, Clone, Builder, Serialize)]
 #[builder(setter(strip_option))]
 pub struct UpdateGroup<'a> {
@@ -337,6 +347,8 @@ pub struct GroupWrapper<T> {
 pub(crate) mod test {
     use super::*;
     use crate::api::test_helpers::with_group;
+    use crate::api::users::test::USER_LOCK;
+    use crate::api::users::{CreateUser, DeleteUser, ListUsers, User, UserWrapper, UsersWrapper};
     use pretty_assertions::assert_eq;
     use std::error::Error;
     use tokio::sync::RwLock;
@@ -368,6 +380,19 @@ pub(crate) mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_get_group_include_parameter() -> Result<(), Box<dyn Error>> {
+        let endpoint = GetGroup::builder()
+            .id(338)
+            .include(vec![GroupInclude::Users, GroupInclude::Memberships])
+            .build()?;
+        assert_eq!(endpoint.endpoint(), "groups/338.json");
+        let mut url = url::Url::parse("https://redmine.example.com/groups/338.json")?;
+        endpoint.parameters().add_to_url(&mut url);
+        assert_eq!(url.query(), Some("include=users%2Cmemberships"));
+        Ok(())
+    }
+
     #[function_name::named]
     #[traced_test]
     #[test]
@@ -380,7 +405,7 @@ pub(crate) mod test {
     #[function_name::named]
     #[traced_test]
     #[test]
-    fn test_update_project() -> Result<(), Box<dyn Error>> {
+    fn test_update_group() -> Result<(), Box<dyn Error>> {
         let name = format!("unittest_{}", function_name!());
         with_group(&name, |redmine, id, _name| {
             let update_endpoint = super::UpdateGroup::builder()
@@ -388,6 +413,96 @@ pub(crate) mod test {
                 .name("unittest_rename_test")
                 .build()?;
             redmine.ignore_response_body::<_>(&update_endpoint)?;
+            let get_endpoint = GetGroup::builder().id(id).build()?;
+            let GroupWrapper { group } =
+                redmine.json_response_body::<_, GroupWrapper<Group>>(&get_endpoint)?;
+            assert_eq!(group.name, "unittest_rename_test");
+            Ok(())
+        })?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_create_group_body() -> Result<(), Box<dyn Error>> {
+        let endpoint = CreateGroup::builder()
+            .name("Developers")
+            .user_ids(vec![5, 7])
+            .build()?;
+        let Some((_, body)) = endpoint.body()? else {
+            panic!("CreateGroup should have a body");
+        };
+        let body: serde_json::Value = serde_json::from_slice(&body)?;
+        assert_eq!(
+            body,
+            serde_json::json!({ "group": { "name": "Developers", "user_ids": [5, 7] } })
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_update_group_body_omits_unset_user_ids() -> Result<(), Box<dyn Error>> {
+        let endpoint = UpdateGroup::builder().id(12).name("Renamed").build()?;
+        assert_eq!(endpoint.endpoint(), "groups/12.json");
+        let Some((_, body)) = endpoint.body()? else {
+            panic!("UpdateGroup should have a body");
+        };
+        let body: serde_json::Value = serde_json::from_slice(&body)?;
+        assert_eq!(body, serde_json::json!({ "group": { "name": "Renamed" } }));
+        Ok(())
+    }
+
+    #[function_name::named]
+    #[traced_test]
+    #[test]
+    fn test_add_and_remove_user_to_group() -> Result<(), Box<dyn Error>> {
+        let _w_user = USER_LOCK.write();
+        let name = format!("unittest_{}", function_name!());
+        with_group(&name, |redmine, id, name| {
+            let list_endpoint = ListUsers::builder().name(name).build()?;
+            let UsersWrapper { users } =
+                redmine.json_response_body::<_, UsersWrapper<User>>(&list_endpoint)?;
+            for user in users {
+                let delete_endpoint = DeleteUser::builder().id(user.id).build()?;
+                redmine.ignore_response_body::<_>(&delete_endpoint)?;
+            }
+            let create_endpoint = CreateUser::builder()
+                .login(name)
+                .firstname("Unit")
+                .lastname("Test")
+                .mail(format!("unit-test_{}@example.org", name))
+                .build()?;
+            let UserWrapper { user } =
+                redmine.json_response_body::<_, UserWrapper<User>>(&create_endpoint)?;
+            let add_endpoint = AddUserToGroup::builder()
+                .group_id(id)
+                .user_id(user.id)
+                .build()?;
+            redmine.ignore_response_body::<_>(&add_endpoint)?;
+            let get_endpoint = GetGroup::builder()
+                .id(id)
+                .include(vec![GroupInclude::Users])
+                .build()?;
+            let GroupWrapper { group } =
+                redmine.json_response_body::<_, GroupWrapper<Group>>(&get_endpoint)?;
+            assert!(group
+                .users
+                .unwrap_or_default()
+                .iter()
+                .any(|u| u.id == user.id));
+            let remove_endpoint = RemoveUserFromGroup::builder()
+                .group_id(id)
+                .user_id(user.id)
+                .build()?;
+            redmine.ignore_response_body::<_>(&remove_endpoint)?;
+            let GroupWrapper { group } =
+                redmine.json_response_body::<_, GroupWrapper<Group>>(&get_endpoint)?;
+            assert!(!group
+                .users
+                .unwrap_or_default()
+                .iter()
+                .any(|u| u.id == user.id));
+            let delete_endpoint = DeleteUser::builder().id(user.id).build()?;
+            redmine.ignore_response_body::<_>(&delete_endpoint)?;
             Ok(())
         })?;
         Ok(())
@@ -7,6 +7,7 @@
 //! - [x] create issue category endpoint
 //! - [x] update issue category endpoint
 //! - [x] delete issue category endpoint
+//!   - [x] reassign_to_id parameter
 
 use derive_builder::Builder;
 use reqwest::Method;
@@ -14,7 +15,7 @@ use std::borrow::Cow;
 
 use crate::api::issues::AssigneeEssentials;
 use crate::api::projects::ProjectEssentials;
-use crate::api::{Endpoint, ReturnsJsonResponse};
+use crate::api::{Endpoint, QueryParams, ReturnsJsonResponse};
 use serde::Serialize;
 
 /// a minimal type for Redmine issue categories used in
@@ -133,6 +134,10 @@ pub struct CreateIssueCategory<'a> {
     /// Issues in this issue category are assigned to this user by default
     #[builder(default)]
     assigned_to_id: Option<u64>,
+    /// see [idempotency_key_header](crate::api::idempotency_key_header)
+    #[builder(default)]
+    #[serde(skip_serializing)]
+    idempotency_key: Option<reqwest::header::HeaderValue>,
 }
 
 impl ReturnsJsonResponse for CreateIssueCategory<'_> {}
@@ -154,6 +159,10 @@ impl Endpoint for CreateIssueCategory<'_> {
         format!("projects/{}/issue_categories.json", self.project_id_or_name).into()
     }
 
+    fn headers(&self) -> reqwest::header::HeaderMap {
+        crate::api::idempotency_key_header(self.idempotency_key.as_ref())
+    }
+
     fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, crate::Error> {
         Ok(Some((
             "application/json",
@@ -213,6 +222,9 @@ impl Endpoint for UpdateIssueCategory<'_> {
 pub struct DeleteIssueCategory {
     /// the id of the issue category to delete
     id: u64,
+    /// the id of another issue category to reassign the issues in this one to
+    #[builder(default)]
+    reassign_to_id: Option<u64>,
 }
 
 impl DeleteIssueCategory {
@@ -231,6 +243,12 @@ impl Endpoint for DeleteIssueCategory {
     fn endpoint(&self) -> Cow<'static, str> {
         format!("issue_categories/{}.json", &self.id).into()
     }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        params.push_opt("reassign_to_id", self.reassign_to_id);
+        params
+    }
 }
 
 /// helper struct for outer layers with a issue_categories field holding the inner data
@@ -251,6 +269,7 @@ pub struct IssueCategoryWrapper<T> {
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::api::issues::{CreateIssue, GetIssue, Issue, IssueWrapper};
     use crate::api::test_helpers::with_project;
     use pretty_assertions::assert_eq;
     use std::error::Error;
@@ -347,6 +366,51 @@ mod test {
         Ok(())
     }
 
+    #[function_name::named]
+    #[traced_test]
+    #[test]
+    fn test_delete_issue_category_reassign() -> Result<(), Box<dyn Error>> {
+        let _w_issue_category = ISSUE_CATEGORY_LOCK.write();
+        let name = format!("unittest_{}", function_name!());
+        with_project(&name, |redmine, project_id, name| {
+            let create_endpoint = super::CreateIssueCategory::builder()
+                .project_id_or_name(name)
+                .name("Unittest Issue Category")
+                .build()?;
+            let IssueCategoryWrapper { issue_category }: IssueCategoryWrapper<IssueCategory> =
+                redmine.json_response_body::<_, _>(&create_endpoint)?;
+            let create_endpoint = super::CreateIssueCategory::builder()
+                .project_id_or_name(name)
+                .name("Unittest Issue Category Reassign Target")
+                .build()?;
+            let IssueCategoryWrapper {
+                issue_category: target_issue_category,
+            }: IssueCategoryWrapper<IssueCategory> =
+                redmine.json_response_body::<_, _>(&create_endpoint)?;
+            let create_issue_endpoint = CreateIssue::builder()
+                .project_id(project_id)
+                .subject("issue category reassign test subject")
+                .category_id(issue_category.id)
+                .build()?;
+            let IssueWrapper { issue }: IssueWrapper<Issue> =
+                redmine.json_response_body::<_, _>(&create_issue_endpoint)?;
+            let delete_endpoint = super::DeleteIssueCategory::builder()
+                .id(issue_category.id)
+                .reassign_to_id(target_issue_category.id)
+                .build()?;
+            redmine.ignore_response_body::<_>(&delete_endpoint)?;
+            let get_issue_endpoint = GetIssue::builder().id(issue.id).build()?;
+            let IssueWrapper { issue }: IssueWrapper<Issue> =
+                redmine.json_response_body::<_, _>(&get_issue_endpoint)?;
+            assert_eq!(
+                issue.category.map(|category| category.id),
+                Some(target_issue_category.id)
+            );
+            Ok(())
+        })?;
+        Ok(())
+    }
+
     /// this tests if any of the results contain a field we are not deserializing
     ///
     /// this will only catch fields we missed if they are part of the response but
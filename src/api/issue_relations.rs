@@ -116,7 +116,7 @@ pub enum IssueRelationType {
 /// The endpoint to create an issue relation
 #[serde_with::skip_serializing_none]
 #[derive(Debug, Clone, Builder, Serialize)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct CreateIssueRelation {
     /// id of the issue where the relation is created
     #[serde(skip_serializing)]
@@ -128,10 +128,33 @@ pub struct CreateIssueRelation {
     /// Delay in days for the precedes and follows relation types
     #[builder(default)]
     delay: Option<u64>,
+    /// see [idempotency_key_header](crate::api::idempotency_key_header)
+    #[builder(default)]
+    #[serde(skip_serializing)]
+    idempotency_key: Option<reqwest::header::HeaderValue>,
 }
 
 impl ReturnsJsonResponse for CreateIssueRelation {}
 
+impl CreateIssueRelationBuilder {
+    /// ensures that delay is only specified for the precedes and follows relation types
+    /// when [Self::build()] is called
+    fn validate(&self) -> Result<(), String> {
+        if let (Some(relation_type), Some(Some(_))) = (&self.relation_type, &self.delay) {
+            if !matches!(
+                relation_type,
+                IssueRelationType::Precedes | IssueRelationType::Follows
+            ) {
+                return Err(format!(
+                    "delay can only be specified for precedes and follows relations, not for {:?}",
+                    relation_type
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
 impl CreateIssueRelation {
     /// Create a builder for the endpoint.
     #[must_use]
@@ -149,6 +172,10 @@ impl Endpoint for CreateIssueRelation {
         format!("issues/{}/relations.json", self.issue_id).into()
     }
 
+    fn headers(&self) -> reqwest::header::HeaderMap {
+        crate::api::idempotency_key_header(self.idempotency_key.as_ref())
+    }
+
     fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, crate::Error> {
         Ok(Some((
             "application/json",
@@ -268,6 +295,24 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_create_issue_relation_delay_validation() {
+        assert!(CreateIssueRelation::builder()
+            .issue_id(1)
+            .issue_to_id(2)
+            .relation_type(IssueRelationType::Precedes)
+            .delay(3)
+            .build()
+            .is_ok());
+        assert!(CreateIssueRelation::builder()
+            .issue_id(1)
+            .issue_to_id(2)
+            .relation_type(IssueRelationType::Relates)
+            .delay(3)
+            .build()
+            .is_err());
+    }
+
     #[function_name::named]
     #[traced_test]
     #[test]
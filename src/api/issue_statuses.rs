@@ -8,7 +8,8 @@ use derive_builder::Builder;
 use reqwest::Method;
 use std::borrow::Cow;
 
-use crate::api::{Endpoint, ReturnsJsonResponse};
+use crate::api::issues::StatusFilter;
+use crate::api::{Endpoint, Redmine, RedmineAsync, ReturnsJsonResponse};
 
 /// a minimal type for Redmine issue status used in
 /// other Redmine objects (e.g. issue)
@@ -54,6 +55,9 @@ pub struct IssueStatus {
     pub name: String,
     /// is this status considered closed
     pub is_closed: bool,
+    /// description (available in Redmine 5.1)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
 }
 
 /// The endpoint for all issue statuses
@@ -96,6 +100,100 @@ pub struct IssueStatusWrapper<T> {
     pub issue_status: T,
 }
 
+/// the ids of the issue statuses with the given names, in the order of the names
+///
+/// # Errors
+///
+/// This returns [crate::Error::UnknownIssueStatus] for the first name which
+/// does not match the name of any of the statuses exactly
+fn status_ids_by_name(
+    statuses: &[IssueStatusEssentials],
+    names: &[&str],
+) -> Result<Vec<u64>, crate::Error> {
+    names
+        .iter()
+        .map(|name| {
+            statuses
+                .iter()
+                .find(|s| s.name == *name)
+                .map(|s| s.id)
+                .ok_or_else(|| crate::Error::UnknownIssueStatus(name.to_string()))
+        })
+        .collect()
+}
+
+impl Redmine {
+    /// the issue statuses of the Redmine instance, fetched on the first call
+    /// and cached for the lifetime of this client
+    ///
+    /// # Errors
+    ///
+    /// This can return an error if the web request fails, when Redmine returns
+    /// an HTTP error status or when the response can not be parsed
+    pub fn issue_statuses(&self) -> Result<&[IssueStatusEssentials], crate::Error> {
+        if let Some(statuses) = self.issue_statuses.get() {
+            return Ok(statuses);
+        }
+        let IssueStatusesWrapper { issue_statuses } = self
+            .json_response_body::<_, IssueStatusesWrapper<IssueStatusEssentials>>(
+                &ListIssueStatuses {},
+            )?;
+        Ok(self.issue_statuses.get_or_init(|| issue_statuses))
+    }
+
+    /// builds a [StatusFilter] matching the issue statuses with the given
+    /// names, the names are resolved via the cached [Redmine::issue_statuses]
+    ///
+    /// # Errors
+    ///
+    /// This returns [crate::Error::UnknownIssueStatus] if one of the names does
+    /// not match any status exactly and can return the errors of
+    /// [Redmine::issue_statuses]
+    pub fn issue_status_filter(&self, names: &[&str]) -> Result<StatusFilter, crate::Error> {
+        Ok(StatusFilter::TheseStatuses(status_ids_by_name(
+            self.issue_statuses()?,
+            names,
+        )?))
+    }
+}
+
+impl RedmineAsync {
+    /// the issue statuses of the Redmine instance, fetched on the first call
+    /// and cached for the lifetime of this client
+    ///
+    /// # Errors
+    ///
+    /// This can return an error if the web request fails, when Redmine returns
+    /// an HTTP error status or when the response can not be parsed
+    pub async fn issue_statuses(&self) -> Result<&[IssueStatusEssentials], crate::Error> {
+        if let Some(statuses) = self.issue_statuses.get() {
+            return Ok(statuses);
+        }
+        let IssueStatusesWrapper { issue_statuses } = self
+            .json_response_body::<_, IssueStatusesWrapper<IssueStatusEssentials>>(
+                &ListIssueStatuses {},
+            )
+            .await?;
+        Ok(self.issue_statuses.get_or_init(|| issue_statuses))
+    }
+
+    /// builds a [StatusFilter] matching the issue statuses with the given
+    /// names, the names are resolved via the cached
+    /// [RedmineAsync::issue_statuses]
+    ///
+    /// # Errors
+    ///
+    /// This returns [crate::Error::UnknownIssueStatus] if one of the names does
+    /// not match any status exactly and can return the errors of
+    /// [RedmineAsync::issue_statuses]
+    pub async fn issue_status_filter(&self, names: &[&str]) -> Result<StatusFilter, crate::Error> {
+        Ok(StatusFilter::TheseStatuses(status_ids_by_name(
+            self.issue_statuses().await?,
+            names,
+        )?))
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -133,4 +231,29 @@ mod test {
         }
         Ok(())
     }
+
+    #[test]
+    fn test_status_ids_by_name() -> Result<(), Box<dyn Error>> {
+        let statuses = vec![
+            IssueStatusEssentials {
+                id: 1,
+                is_closed: Some(false),
+                name: "New".to_string(),
+            },
+            IssueStatusEssentials {
+                id: 5,
+                is_closed: Some(true),
+                name: "Closed".to_string(),
+            },
+        ];
+        assert_eq!(
+            status_ids_by_name(&statuses, &["Closed", "New"])?,
+            vec![5, 1]
+        );
+        assert!(matches!(
+            status_ids_by_name(&statuses, &["New", "closed"]),
+            Err(crate::Error::UnknownIssueStatus(name)) if name == "closed"
+        ));
+        Ok(())
+    }
 }
@@ -7,7 +7,7 @@
 //!
 //! - [ ] all issues endpoint
 //!   - [x] sort
-//!     - [ ] limit sort to the existing columns only instead of a string value
+//!     - [x] limit sort to the existing columns only instead of a string value
 //!   - [x] query_id parameter
 //!   - [x] pagination
 //!   - [x] issue_id filter
@@ -18,6 +18,7 @@
 //!     - [x] !* filter to only get parent project issues
 //!   - [x] tracker_id filter
 //!     - [x] tracker id (multiple are possible, comma separated)
+//!     - [x] negation of list
 //!   - [x] status_id filter
 //!     - [x] open (default)
 //!     - [x] closed
@@ -25,6 +26,9 @@
 //!     - [x] status id (multiple are possible, comma separated)
 //!   - [x] category_id filter
 //!     - [x] category id (multiple are possible, comma separated)
+//!     - [x] negation of list
+//!     - [x] none (!*)
+//!     - [x] any (*)
 //!   - [x] priority_id filter
 //!     - [x] priority id (multiple are possible, comma separated)
 //!   - [x] author_id filter
@@ -42,12 +46,15 @@
 //!     - [x] none (!*)
 //!   - [x] fixed_version_id filter (Target version, API uses old name)
 //!     - [x] version id (multiple are possible, comma separated)
+//!     - [x] negation of list
+//!     - [x] none (!*)
+//!     - [x] any (*)
 //!   - [ ] is_private filter
 //!   - [x] parent_id filter
 //!     - [x] issue id (multiple are possible, comma separated)
-//!   - [ ] custom field filter
-//!     - [ ] exact match
-//!     - [ ] substring match
+//!   - [x] custom field filter
+//!     - [x] exact match
+//!     - [x] substring match
 //!     - [ ] what about multiple value custom fields?
 //!   - [x] subject filter
 //!     - [x] exact match
@@ -55,14 +62,21 @@
 //!   - [x] description filter
 //!     - [x] exact match
 //!     - [x] substring match
-//!   - [ ] done_ratio filter
-//!     - [ ] exact match
-//!     - [ ] less than, greater than ?
-//!     - [ ] range?
-//!   - [ ] estimated_hours filter
-//!     - [ ] exact match
-//!     - [ ] less than, greater than ?
-//!     - [ ] range?
+//!   - [x] done_ratio filter
+//!     - [x] exact match
+//!     - [x] less than or equal, greater than or equal
+//!     - [x] range
+//!     - [x] any/none
+//!   - [x] estimated_hours filter
+//!     - [x] exact match
+//!     - [x] less than or equal, greater than or equal
+//!     - [x] range
+//!     - [x] any/none
+//!   - [x] spent_time filter
+//!     - [x] exact match
+//!     - [x] less than or equal, greater than or equal
+//!     - [x] range
+//!     - [x] any/none
 //!   - [x] created_on filter
 //!     - [x] exact match
 //!     - [x] less than, greater than
@@ -101,9 +115,14 @@ use crate::api::issue_relations::IssueRelation;
 use crate::api::issue_statuses::IssueStatusEssentials;
 use crate::api::projects::ProjectEssentials;
 use crate::api::trackers::TrackerEssentials;
+use crate::api::uploads::{upload_filename, FileUploadToken};
 use crate::api::users::UserEssentials;
 use crate::api::versions::VersionEssentials;
-use crate::api::{Endpoint, Pageable, QueryParams, ReturnsJsonResponse};
+use crate::api::{
+    Endpoint, IssueId, Pageable, ProjectId, QueryParams, Redmine, RedmineAsync,
+    ReturnsJsonResponse, UserId, MAX_CONCURRENT_REQUESTS,
+};
+use futures::{StreamExt, TryStreamExt};
 use serde::Serialize;
 
 /// a minimal type for Redmine users or groups used in lists of assignees included in
@@ -276,6 +295,10 @@ pub struct Issue {
     /// the issue author
     pub author: UserEssentials,
     /// the user or group the issue is assigned to
+    ///
+    /// Redmine only returns the id and name here, use
+    /// [Redmine::resolve_assignees](crate::api::Redmine::resolve_assignees)
+    /// or [GetUser](crate::api::users::GetUser) for the full user
     #[serde(skip_serializing_if = "Option::is_none")]
     pub assigned_to: Option<AssigneeEssentials>,
     /// the issue category
@@ -290,7 +313,7 @@ pub struct Issue {
     /// the issue description
     pub description: Option<String>,
     /// is the issue private (only visible to roles that have the relevant permission enabled)
-    is_private: Option<bool>,
+    pub is_private: Option<bool>,
     /// the start date for the issue
     pub start_date: Option<time::Date>,
     /// the due date for the issue
@@ -336,6 +359,9 @@ pub struct Issue {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub watchers: Option<Vec<UserEssentials>>,
     /// the hours spent
+    ///
+    /// this is returned without any include parameter, both for single issues
+    /// and in lists, but only if the user has permission to view time entries
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub spent_hours: Option<f64>,
     /// the total hours spent on this and sub-tasks
@@ -344,6 +370,9 @@ pub struct Issue {
     /// the total hours estimated on this and sub-tasks
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub total_estimated_hours: Option<f64>,
+    /// the statuses this issue can transition to (only when include parameter is used)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allowed_statuses: Option<Vec<IssueStatusEssentials>>,
 }
 
 /// ways to filter for subproject
@@ -383,6 +412,21 @@ impl std::fmt::Display for SubProjectFilter {
     }
 }
 
+/// writes a comma separated list of ids as used by the list filters,
+/// each id prefixed with ! if the list is negated
+fn write_id_list(f: &mut std::fmt::Formatter<'_>, ids: &[u64], negated: bool) -> std::fmt::Result {
+    for (i, id) in ids.iter().enumerate() {
+        if i > 0 {
+            write!(f, ",")?;
+        }
+        if negated {
+            write!(f, "!")?;
+        }
+        write!(f, "{}", id)?;
+    }
+    Ok(())
+}
+
 /// ways to filter for issue status
 #[derive(Debug, Clone)]
 pub enum StatusFilter {
@@ -410,22 +454,8 @@ impl std::fmt::Display for StatusFilter {
             StatusFilter::All => {
                 write!(f, "*")
             }
-            StatusFilter::TheseStatuses(ids) => {
-                let s: String = ids
-                    .iter()
-                    .map(|e| e.to_string())
-                    .collect::<Vec<_>>()
-                    .join(",");
-                write!(f, "{}", s)
-            }
-            StatusFilter::NotTheseStatuses(ids) => {
-                let s: String = ids
-                    .iter()
-                    .map(|e| format!("!{}", e))
-                    .collect::<Vec<_>>()
-                    .join(",");
-                write!(f, "{}", s)
-            }
+            StatusFilter::TheseStatuses(ids) => write_id_list(f, ids, false),
+            StatusFilter::NotTheseStatuses(ids) => write_id_list(f, ids, true),
         }
     }
 }
@@ -529,7 +559,148 @@ impl std::fmt::Display for AssigneeFilter {
     }
 }
 
-/// Filter options for subject and description
+/// ways to filter for the parent issue
+///
+/// a list of ids converts to [ParentFilter::These]
+#[derive(Debug, Clone)]
+pub enum ParentFilter {
+    /// match issues with one of these parent issues
+    These(Vec<u64>),
+    /// match issues without a parent (top-level issues)
+    None,
+    /// match issues with any parent (subtasks)
+    Any,
+}
+
+impl From<Vec<u64>> for ParentFilter {
+    fn from(ids: Vec<u64>) -> Self {
+        Self::These(ids)
+    }
+}
+
+impl std::fmt::Display for ParentFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::These(ids) => {
+                let s: String = ids
+                    .iter()
+                    .map(|e| e.to_string())
+                    .collect::<Vec<_>>()
+                    .join(",");
+                write!(f, "{}", s)
+            }
+            Self::None => {
+                write!(f, "!*")
+            }
+            Self::Any => {
+                write!(f, "*")
+            }
+        }
+    }
+}
+
+/// ways to filter for trackers
+///
+/// every issue has a tracker so there is no filter for issues without one,
+/// a list of ids converts to [TrackerFilter::TheseTrackers]
+#[derive(Debug, Clone)]
+pub enum TrackerFilter {
+    /// match a specific list of trackers
+    TheseTrackers(Vec<u64>),
+    /// match any tracker but a specific list of trackers
+    NotTheseTrackers(Vec<u64>),
+}
+
+impl From<Vec<u64>> for TrackerFilter {
+    fn from(ids: Vec<u64>) -> Self {
+        Self::TheseTrackers(ids)
+    }
+}
+
+impl std::fmt::Display for TrackerFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::TheseTrackers(ids) => write_id_list(f, ids, false),
+            Self::NotTheseTrackers(ids) => write_id_list(f, ids, true),
+        }
+    }
+}
+
+/// ways to filter for the target version
+///
+/// a list of ids converts to [VersionFilter::TheseVersions]
+#[derive(Debug, Clone)]
+pub enum VersionFilter {
+    /// match a specific list of versions
+    TheseVersions(Vec<u64>),
+    /// match any version but a specific list of versions
+    NotTheseVersions(Vec<u64>),
+    /// match issues without a target version
+    NoVersion,
+    /// match issues with any target version
+    AnyVersion,
+}
+
+impl From<Vec<u64>> for VersionFilter {
+    fn from(ids: Vec<u64>) -> Self {
+        Self::TheseVersions(ids)
+    }
+}
+
+impl std::fmt::Display for VersionFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::TheseVersions(ids) => write_id_list(f, ids, false),
+            Self::NotTheseVersions(ids) => write_id_list(f, ids, true),
+            Self::NoVersion => {
+                write!(f, "!*")
+            }
+            Self::AnyVersion => {
+                write!(f, "*")
+            }
+        }
+    }
+}
+
+/// ways to filter for issue categories
+///
+/// a list of ids converts to [CategoryFilter::TheseCategories]
+#[derive(Debug, Clone)]
+pub enum CategoryFilter {
+    /// match a specific list of categories
+    TheseCategories(Vec<u64>),
+    /// match any category but a specific list of categories
+    NotTheseCategories(Vec<u64>),
+    /// match uncategorized issues
+    NoCategory,
+    /// match issues with any category
+    AnyCategory,
+}
+
+impl From<Vec<u64>> for CategoryFilter {
+    fn from(ids: Vec<u64>) -> Self {
+        Self::TheseCategories(ids)
+    }
+}
+
+impl std::fmt::Display for CategoryFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::TheseCategories(ids) => write_id_list(f, ids, false),
+            Self::NotTheseCategories(ids) => write_id_list(f, ids, true),
+            Self::NoCategory => {
+                write!(f, "!*")
+            }
+            Self::AnyCategory => {
+                write!(f, "*")
+            }
+        }
+    }
+}
+
+/// Filter options for string fields (e.g. subject and description)
+///
+/// plain strings convert to [StringFieldFilter::SubStringMatch]
 #[derive(Debug, Clone)]
 pub enum StringFieldFilter {
     /// match exactly this value
@@ -538,6 +709,18 @@ pub enum StringFieldFilter {
     SubStringMatch(String),
 }
 
+impl From<&str> for StringFieldFilter {
+    fn from(s: &str) -> Self {
+        Self::SubStringMatch(s.to_string())
+    }
+}
+
+impl From<String> for StringFieldFilter {
+    fn from(s: String) -> Self {
+        Self::SubStringMatch(s)
+    }
+}
+
 impl std::fmt::Display for StringFieldFilter {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -551,6 +734,82 @@ impl std::fmt::Display for StringFieldFilter {
     }
 }
 
+/// Filter for a custom field, sent as the cf_{id} query parameter
+#[derive(Debug, Clone)]
+pub struct CustomFieldFilter {
+    /// the id of the custom field
+    pub id: u64,
+    /// the value to filter for
+    pub value: CustomFieldFilterValue,
+}
+
+/// The value of a [CustomFieldFilter], depending on the format of the custom field
+#[derive(Debug, Clone)]
+pub enum CustomFieldFilterValue {
+    /// filter for a string, list or text custom field
+    String(StringFieldFilter),
+    /// filter for an integer custom field
+    Integer(IntegerFilter),
+    /// filter for a float custom field
+    Float(FloatFilter),
+    /// filter for a date custom field
+    Date(DateFilter),
+    /// filter for a date-time custom field, including future dates
+    DateTime(DateTimeFilter),
+}
+
+impl From<StringFieldFilter> for CustomFieldFilterValue {
+    fn from(filter: StringFieldFilter) -> Self {
+        Self::String(filter)
+    }
+}
+
+impl From<IntegerFilter> for CustomFieldFilterValue {
+    fn from(filter: IntegerFilter) -> Self {
+        Self::Integer(filter)
+    }
+}
+
+impl From<FloatFilter> for CustomFieldFilterValue {
+    fn from(filter: FloatFilter) -> Self {
+        Self::Float(filter)
+    }
+}
+
+impl From<DateFilter> for CustomFieldFilterValue {
+    fn from(filter: DateFilter) -> Self {
+        Self::Date(filter)
+    }
+}
+
+impl From<DateTimeFilter> for CustomFieldFilterValue {
+    fn from(filter: DateTimeFilter) -> Self {
+        Self::DateTime(filter)
+    }
+}
+
+impl std::fmt::Display for CustomFieldFilterValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::String(filter) => {
+                write!(f, "{}", filter)
+            }
+            Self::Integer(filter) => {
+                write!(f, "{}", filter)
+            }
+            Self::Float(filter) => {
+                write!(f, "{}", filter)
+            }
+            Self::Date(filter) => {
+                write!(f, "{}", filter)
+            }
+            Self::DateTime(filter) => {
+                write!(f, "{}", filter)
+            }
+        }
+    }
+}
+
 /// a trait for comparable filter values, we do not just use Display because
 /// one of our main application is dates and we need a specific format
 pub trait ComparableFilterValue {
@@ -562,7 +821,7 @@ pub trait ComparableFilterValue {
 
 impl ComparableFilterValue for time::Date {
     fn value_string(&self) -> Cow<'static, str> {
-        let format = time::format_description::parse("[year]-[month]-[day]").unwrap();
+        let format = time::format_description::parse_borrowed::<2>("[year]-[month]-[day]").unwrap();
         self.format(&format).unwrap().into()
     }
 }
@@ -620,108 +879,579 @@ where
     }
 }
 
-/// Sort by this column
+/// Filter for date fields (e.g. start_date or spent_on), in addition to
+/// comparisons with specific dates this supports the relative operators Redmine
+/// offers for dates in the past
 #[derive(Debug, Clone)]
-pub enum SortByColumn {
-    /// Sort in an ascending direction
-    Forward {
-        /// the column to sort by
-        column_name: String,
-    },
-    /// Sort in a descending direction
-    Reverse {
-        /// the column to sort by
-        column_name: String,
-    },
+pub enum DateFilter {
+    /// an exact match
+    ExactMatch(time::Date),
+    /// a range match (inclusive)
+    Range(time::Date, time::Date),
+    /// we only want values less than or equal to the parameter
+    LessThanOrEqual(time::Date),
+    /// we only want values greater than or equal to the parameter
+    GreaterThanOrEqual(time::Date),
+    /// less than this many days ago
+    LessThanDaysAgo(u64),
+    /// more than this many days ago
+    MoreThanDaysAgo(u64),
+    /// within the past this many days
+    WithinPastDays(u64),
+    /// exactly this many days ago
+    DaysAgo(u64),
+    /// today
+    Today,
+    /// yesterday
+    Yesterday,
+    /// the current week
+    ThisWeek,
+    /// the previous week
+    LastWeek,
+    /// the current and the previous week
+    LastTwoWeeks,
+    /// the current month
+    ThisMonth,
+    /// the previous month
+    LastMonth,
+    /// the current year
+    ThisYear,
+    /// any value is set
+    Any,
+    /// no value is set
+    None,
 }
 
-impl std::fmt::Display for SortByColumn {
+impl std::fmt::Display for DateFilter {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            SortByColumn::Forward { column_name } => {
-                write!(f, "{}", column_name)
+            DateFilter::ExactMatch(v) => {
+                write!(f, "{}", v.value_string())
             }
-            SortByColumn::Reverse { column_name } => {
-                write!(f, "{}:desc", column_name)
+            DateFilter::Range(v_start, v_end) => {
+                write!(f, "><{}|{}", v_start.value_string(), v_end.value_string())
+            }
+            DateFilter::LessThanOrEqual(v) => {
+                write!(f, "<={}", v.value_string())
+            }
+            DateFilter::GreaterThanOrEqual(v) => {
+                write!(f, ">={}", v.value_string())
+            }
+            DateFilter::LessThanDaysAgo(d) => {
+                write!(f, ">t-{}", d)
+            }
+            DateFilter::MoreThanDaysAgo(d) => {
+                write!(f, "<t-{}", d)
+            }
+            DateFilter::WithinPastDays(d) => {
+                write!(f, "><t-{}", d)
+            }
+            DateFilter::DaysAgo(d) => {
+                write!(f, "t-{}", d)
+            }
+            DateFilter::Today => {
+                write!(f, "t")
+            }
+            DateFilter::Yesterday => {
+                write!(f, "ld")
+            }
+            DateFilter::ThisWeek => {
+                write!(f, "w")
+            }
+            DateFilter::LastWeek => {
+                write!(f, "lw")
+            }
+            DateFilter::LastTwoWeeks => {
+                write!(f, "l2w")
+            }
+            DateFilter::ThisMonth => {
+                write!(f, "m")
+            }
+            DateFilter::LastMonth => {
+                write!(f, "lm")
+            }
+            DateFilter::ThisYear => {
+                write!(f, "y")
+            }
+            DateFilter::Any => {
+                write!(f, "*")
+            }
+            DateFilter::None => {
+                write!(f, "!*")
             }
         }
     }
 }
 
-/// The types of associated data which can be fetched along with a issue
+/// Filter for date and time fields which can only be in the past (e.g.
+/// created_on or updated_on), in addition to comparisons with specific times
+/// this supports the relative operators Redmine offers for dates in the past
 #[derive(Debug, Clone)]
-pub enum IssueListInclude {
-    /// Issue Attachments
-    Attachments,
-    /// Issue relations
-    Relations,
+pub enum DateTimeFilterPast {
+    /// a range match (inclusive)
+    Range(time::OffsetDateTime, time::OffsetDateTime),
+    /// we only want values less than or equal to the parameter
+    LessThanOrEqual(time::OffsetDateTime),
+    /// we only want values greater than or equal to the parameter
+    GreaterThanOrEqual(time::OffsetDateTime),
+    /// less than this many days ago
+    LessThanDaysAgo(u64),
+    /// more than this many days ago
+    MoreThanDaysAgo(u64),
+    /// within the past this many days
+    WithinPastDays(u64),
+    /// exactly this many days ago
+    DaysAgo(u64),
+    /// today
+    Today,
+    /// yesterday
+    Yesterday,
+    /// the current week
+    ThisWeek,
+    /// the previous week
+    LastWeek,
+    /// the current and the previous week
+    LastTwoWeeks,
+    /// the current month
+    ThisMonth,
+    /// the previous month
+    LastMonth,
+    /// the current year
+    ThisYear,
+    /// any value is set
+    Any,
+    /// no value is set
+    None,
 }
 
-impl std::fmt::Display for IssueListInclude {
+impl std::fmt::Display for DateTimeFilterPast {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            Self::Attachments => {
-                write!(f, "attachments")
+            DateTimeFilterPast::Range(v_start, v_end) => {
+                write!(f, "><{}|{}", v_start.value_string(), v_end.value_string())
             }
-            Self::Relations => {
-                write!(f, "relations")
+            DateTimeFilterPast::LessThanOrEqual(v) => {
+                write!(f, "<={}", v.value_string())
+            }
+            DateTimeFilterPast::GreaterThanOrEqual(v) => {
+                write!(f, ">={}", v.value_string())
+            }
+            DateTimeFilterPast::LessThanDaysAgo(d) => {
+                write!(f, ">t-{}", d)
+            }
+            DateTimeFilterPast::MoreThanDaysAgo(d) => {
+                write!(f, "<t-{}", d)
+            }
+            DateTimeFilterPast::WithinPastDays(d) => {
+                write!(f, "><t-{}", d)
+            }
+            DateTimeFilterPast::DaysAgo(d) => {
+                write!(f, "t-{}", d)
+            }
+            DateTimeFilterPast::Today => {
+                write!(f, "t")
+            }
+            DateTimeFilterPast::Yesterday => {
+                write!(f, "ld")
+            }
+            DateTimeFilterPast::ThisWeek => {
+                write!(f, "w")
+            }
+            DateTimeFilterPast::LastWeek => {
+                write!(f, "lw")
+            }
+            DateTimeFilterPast::LastTwoWeeks => {
+                write!(f, "l2w")
+            }
+            DateTimeFilterPast::ThisMonth => {
+                write!(f, "m")
+            }
+            DateTimeFilterPast::LastMonth => {
+                write!(f, "lm")
+            }
+            DateTimeFilterPast::ThisYear => {
+                write!(f, "y")
+            }
+            DateTimeFilterPast::Any => {
+                write!(f, "*")
+            }
+            DateTimeFilterPast::None => {
+                write!(f, "!*")
             }
         }
     }
 }
 
-/// The endpoint for all Redmine issues
-#[derive(Debug, Clone, Builder)]
-#[builder(setter(strip_option))]
-pub struct ListIssues {
-    /// Include associated data
-    #[builder(default)]
-    include: Option<Vec<IssueListInclude>>,
-    /// Sort by column
-    #[builder(default)]
-    sort: Option<Vec<SortByColumn>>,
-    /// Filter by issue id(s)
-    #[builder(default)]
-    issue_id: Option<Vec<u64>>,
-    /// Filter by project id
-    #[builder(default)]
-    project_id: Option<Vec<u64>>,
-    /// Filter by subproject
-    #[builder(default)]
-    subproject_id: Option<SubProjectFilter>,
-    /// Filter by tracker id
-    #[builder(default)]
-    tracker_id: Option<Vec<u64>>,
-    /// Filter by priority id
-    #[builder(default)]
-    priority_id: Option<Vec<u64>>,
-    /// Filter by parent issue id
-    #[builder(default)]
-    parent_id: Option<Vec<u64>>,
-    /// Filter by issue category id
-    #[builder(default)]
-    category_id: Option<Vec<u64>>,
-    /// Filter by issue status
-    #[builder(default)]
-    status_id: Option<StatusFilter>,
-    /// Filter by subject
-    #[builder(default)]
-    subject: Option<StringFieldFilter>,
-    /// Filter by description
-    #[builder(default)]
-    description: Option<StringFieldFilter>,
-    /// Filter by author
-    #[builder(default)]
-    author: Option<AuthorFilter>,
-    /// Filter by assignee
+/// Filter for date and time fields which can also be in the future, in
+/// addition to the operators of [DateTimeFilterPast] this supports the
+/// relative operators Redmine offers for dates in the future
+///
+/// for custom fields pass the filter as the value of a [CustomFieldFilter],
+/// e.g. `CustomFieldFilter { id, value: filter.into() }`
+#[derive(Debug, Clone)]
+pub enum DateTimeFilter {
+    /// any of the filters for the past
+    Past(DateTimeFilterPast),
+    /// less than this many days in the future
+    InLessThanDays(u64),
+    /// more than this many days in the future
+    InMoreThanDays(u64),
+    /// within the next this many days
+    WithinNextDays(u64),
+    /// exactly this many days in the future
+    InDays(u64),
+    /// tomorrow
+    Tomorrow,
+    /// the next week
+    NextWeek,
+    /// the next month
+    NextMonth,
+}
+
+impl From<DateTimeFilterPast> for DateTimeFilter {
+    fn from(filter: DateTimeFilterPast) -> Self {
+        Self::Past(filter)
+    }
+}
+
+impl std::fmt::Display for DateTimeFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DateTimeFilter::Past(filter) => {
+                write!(f, "{}", filter)
+            }
+            DateTimeFilter::InLessThanDays(d) => {
+                write!(f, "<t+{}", d)
+            }
+            DateTimeFilter::InMoreThanDays(d) => {
+                write!(f, ">t+{}", d)
+            }
+            DateTimeFilter::WithinNextDays(d) => {
+                write!(f, "><t+{}", d)
+            }
+            DateTimeFilter::InDays(d) => {
+                write!(f, "t+{}", d)
+            }
+            DateTimeFilter::Tomorrow => {
+                write!(f, "nd")
+            }
+            DateTimeFilter::NextWeek => {
+                write!(f, "nw")
+            }
+            DateTimeFilter::NextMonth => {
+                write!(f, "nm")
+            }
+        }
+    }
+}
+
+/// Filter for integer fields (e.g. done_ratio)
+#[derive(Debug, Clone)]
+pub enum IntegerFilter {
+    /// an exact match
+    ExactMatch(u64),
+    /// a range match (inclusive)
+    Range(u64, u64),
+    /// we only want values less than or equal to the parameter
+    LessThanOrEqual(u64),
+    /// we only want values greater than or equal to the parameter
+    GreaterThanOrEqual(u64),
+    /// any value is set
+    Any,
+    /// no value is set
+    None,
+}
+
+impl std::fmt::Display for IntegerFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IntegerFilter::ExactMatch(v) => {
+                write!(f, "{}", v)
+            }
+            IntegerFilter::Range(v_start, v_end) => {
+                write!(f, "><{}|{}", v_start, v_end)
+            }
+            IntegerFilter::LessThanOrEqual(v) => {
+                write!(f, "<={}", v)
+            }
+            IntegerFilter::GreaterThanOrEqual(v) => {
+                write!(f, ">={}", v)
+            }
+            IntegerFilter::Any => {
+                write!(f, "*")
+            }
+            IntegerFilter::None => {
+                write!(f, "!*")
+            }
+        }
+    }
+}
+
+/// Filter for float fields (e.g. estimated_hours)
+#[derive(Debug, Clone)]
+pub enum FloatFilter {
+    /// an exact match
+    ExactMatch(f64),
+    /// a range match (inclusive)
+    Range(f64, f64),
+    /// we only want values less than or equal to the parameter
+    LessThanOrEqual(f64),
+    /// we only want values greater than or equal to the parameter
+    GreaterThanOrEqual(f64),
+    /// any value is set
+    Any,
+    /// no value is set
+    None,
+}
+
+impl std::fmt::Display for FloatFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FloatFilter::ExactMatch(v) => {
+                write!(f, "{}", v)
+            }
+            FloatFilter::Range(v_start, v_end) => {
+                write!(f, "><{}|{}", v_start, v_end)
+            }
+            FloatFilter::LessThanOrEqual(v) => {
+                write!(f, "<={}", v)
+            }
+            FloatFilter::GreaterThanOrEqual(v) => {
+                write!(f, ">={}", v)
+            }
+            FloatFilter::Any => {
+                write!(f, "*")
+            }
+            FloatFilter::None => {
+                write!(f, "!*")
+            }
+        }
+    }
+}
+
+/// The columns issues can be sorted by
+#[derive(Debug, Clone)]
+pub enum IssueSortColumn {
+    /// issue id
+    Id,
+    /// project
+    Project,
+    /// tracker
+    Tracker,
+    /// parent issue
+    Parent,
+    /// issue status
+    Status,
+    /// issue priority
+    Priority,
+    /// issue subject
+    Subject,
+    /// issue author
+    Author,
+    /// user or group the issue is assigned to
+    AssignedTo,
+    /// issue category
+    Category,
+    /// target version
+    Version,
+    /// start date
+    StartDate,
+    /// due date
+    DueDate,
+    /// estimated hours
+    EstimatedHours,
+    /// percentage done
+    DoneRatio,
+    /// creation time
+    CreatedOn,
+    /// last update time
+    UpdatedOn,
+    /// time the issue was closed
+    ClosedOn,
+    /// a custom field by id
+    CustomField(u64),
+    /// any other column name Redmine understands
+    Custom(String),
+}
+
+impl std::fmt::Display for IssueSortColumn {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Id => {
+                write!(f, "id")
+            }
+            Self::Project => {
+                write!(f, "project")
+            }
+            Self::Tracker => {
+                write!(f, "tracker")
+            }
+            Self::Parent => {
+                write!(f, "parent")
+            }
+            Self::Status => {
+                write!(f, "status")
+            }
+            Self::Priority => {
+                write!(f, "priority")
+            }
+            Self::Subject => {
+                write!(f, "subject")
+            }
+            Self::Author => {
+                write!(f, "author")
+            }
+            Self::AssignedTo => {
+                write!(f, "assigned_to")
+            }
+            Self::Category => {
+                write!(f, "category")
+            }
+            Self::Version => {
+                write!(f, "fixed_version")
+            }
+            Self::StartDate => {
+                write!(f, "start_date")
+            }
+            Self::DueDate => {
+                write!(f, "due_date")
+            }
+            Self::EstimatedHours => {
+                write!(f, "estimated_hours")
+            }
+            Self::DoneRatio => {
+                write!(f, "done_ratio")
+            }
+            Self::CreatedOn => {
+                write!(f, "created_on")
+            }
+            Self::UpdatedOn => {
+                write!(f, "updated_on")
+            }
+            Self::ClosedOn => {
+                write!(f, "closed_on")
+            }
+            Self::CustomField(id) => {
+                write!(f, "cf_{}", id)
+            }
+            Self::Custom(s) => {
+                write!(f, "{}", s)
+            }
+        }
+    }
+}
+
+/// Sort by this column
+///
+/// the column type defaults to [IssueSortColumn], other list endpoints use
+/// their own column types (e.g. [UserSortColumn](crate::api::users::UserSortColumn))
+#[derive(Debug, Clone)]
+pub enum SortByColumn<C = IssueSortColumn> {
+    /// Sort in an ascending direction
+    Forward {
+        /// the column to sort by
+        column_name: C,
+    },
+    /// Sort in a descending direction
+    Reverse {
+        /// the column to sort by
+        column_name: C,
+    },
+}
+
+impl<C: std::fmt::Display> std::fmt::Display for SortByColumn<C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SortByColumn::Forward { column_name } => {
+                write!(f, "{}", column_name)
+            }
+            SortByColumn::Reverse { column_name } => {
+                write!(f, "{}:desc", column_name)
+            }
+        }
+    }
+}
+
+/// The types of associated data which can be fetched along with a issue
+/// in the issue list
+///
+/// Redmine's issues.json only honors attachments and relations, the other
+/// values of [IssueInclude] (journals, watchers, children, changesets,
+/// allowed_statuses) are silently ignored on the list endpoint so they are
+/// not offered here, use [GetIssue] for those
+#[derive(Debug, Clone)]
+pub enum IssueListInclude {
+    /// Issue Attachments
+    Attachments,
+    /// Issue relations
+    Relations,
+}
+
+impl std::fmt::Display for IssueListInclude {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Attachments => {
+                write!(f, "attachments")
+            }
+            Self::Relations => {
+                write!(f, "relations")
+            }
+        }
+    }
+}
+
+/// The endpoint for all Redmine issues
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(strip_option))]
+pub struct ListIssues {
+    /// Include associated data
+    #[builder(default)]
+    include: Option<Vec<IssueListInclude>>,
+    /// Sort by column
+    #[builder(default)]
+    sort: Option<Vec<SortByColumn>>,
+    /// Filter by issue id(s)
+    #[builder(default)]
+    issue_id: Option<Vec<u64>>,
+    /// Filter by project id
+    #[builder(default)]
+    project_id: Option<Vec<u64>>,
+    /// Filter by subproject
+    #[builder(default)]
+    subproject_id: Option<SubProjectFilter>,
+    /// Filter by tracker id
+    #[builder(default, setter(into))]
+    tracker_id: Option<TrackerFilter>,
+    /// Filter by priority id
+    #[builder(default)]
+    priority_id: Option<Vec<u64>>,
+    /// Filter by parent issue id
+    #[builder(default, setter(into))]
+    parent_id: Option<ParentFilter>,
+    /// Filter by issue category id
+    #[builder(default, setter(into))]
+    category_id: Option<CategoryFilter>,
+    /// Filter by issue status
+    #[builder(default)]
+    status_id: Option<StatusFilter>,
+    /// Filter by subject
+    #[builder(default)]
+    subject: Option<StringFieldFilter>,
+    /// Filter by description
+    #[builder(default)]
+    description: Option<StringFieldFilter>,
+    /// Filter by author
+    #[builder(default)]
+    author: Option<AuthorFilter>,
+    /// Filter by assignee
     #[builder(default)]
     assignee: Option<AssigneeFilter>,
     /// Filter by a saved query
     #[builder(default)]
     query_id: Option<u64>,
     /// Filter by target version
-    #[builder(default)]
-    version_id: Option<Vec<u64>>,
+    #[builder(default, setter(into))]
+    version_id: Option<VersionFilter>,
     /// Filter by creation time
     #[builder(default)]
     created_on: Option<ComparableFilter<time::OffsetDateTime>>,
@@ -734,6 +1464,18 @@ pub struct ListIssues {
     /// Filter by due date
     #[builder(default)]
     due_date: Option<ComparableFilter<time::Date>>,
+    /// Filter by done ratio
+    #[builder(default)]
+    done_ratio: Option<IntegerFilter>,
+    /// Filter by estimated hours
+    #[builder(default)]
+    estimated_hours: Option<FloatFilter>,
+    /// Filter by spent time (in hours)
+    #[builder(default)]
+    spent_time: Option<FloatFilter>,
+    /// Filter by custom field values
+    #[builder(default)]
+    custom_field_filters: Option<Vec<CustomFieldFilter>>,
 }
 
 impl ReturnsJsonResponse for ListIssues {}
@@ -742,6 +1484,10 @@ impl Pageable for ListIssues {
     fn response_wrapper_key(&self) -> String {
         "issues".to_string()
     }
+
+    fn default_stable_sort(&self) -> Option<Cow<'static, str>> {
+        Some("id".into())
+    }
 }
 
 impl ListIssues {
@@ -761,7 +1507,7 @@ impl Endpoint for ListIssues {
         "issues.json".into()
     }
 
-    fn parameters(&self) -> QueryParams {
+    fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
         params.push_opt("include", self.include.as_ref());
         params.push_opt("sort", self.sort.as_ref());
@@ -771,10 +1517,16 @@ impl Endpoint for ListIssues {
             "subproject_id",
             self.subproject_id.as_ref().map(|s| s.to_string()),
         );
-        params.push_opt("tracker_id", self.tracker_id.as_ref());
+        params.push_opt(
+            "tracker_id",
+            self.tracker_id.as_ref().map(|s| s.to_string()),
+        );
         params.push_opt("priority_id", self.priority_id.as_ref());
-        params.push_opt("parent_id", self.parent_id.as_ref());
-        params.push_opt("category_id", self.category_id.as_ref());
+        params.push_opt("parent_id", self.parent_id.as_ref().map(|s| s.to_string()));
+        params.push_opt(
+            "category_id",
+            self.category_id.as_ref().map(|s| s.to_string()),
+        );
         params.push_opt("status_id", self.status_id.as_ref().map(|s| s.to_string()));
         params.push_opt("subject", self.subject.as_ref().map(|s| s.to_string()));
         params.push_opt(
@@ -787,7 +1539,10 @@ impl Endpoint for ListIssues {
             self.assignee.as_ref().map(|s| s.to_string()),
         );
         params.push_opt("query_id", self.query_id);
-        params.push_opt("fixed_version_id", self.version_id.as_ref());
+        params.push_opt(
+            "fixed_version_id",
+            self.version_id.as_ref().map(|s| s.to_string()),
+        );
         params.push_opt(
             "created_on",
             self.created_on.as_ref().map(|s| s.to_string()),
@@ -801,6 +1556,26 @@ impl Endpoint for ListIssues {
             self.start_date.as_ref().map(|s| s.to_string()),
         );
         params.push_opt("due_date", self.due_date.as_ref().map(|s| s.to_string()));
+        params.push_opt(
+            "done_ratio",
+            self.done_ratio.as_ref().map(|s| s.to_string()),
+        );
+        params.push_opt(
+            "estimated_hours",
+            self.estimated_hours.as_ref().map(|s| s.to_string()),
+        );
+        params.push_opt(
+            "spent_time",
+            self.spent_time.as_ref().map(|s| s.to_string()),
+        );
+        if let Some(custom_field_filters) = &self.custom_field_filters {
+            for custom_field_filter in custom_field_filters {
+                params.push(
+                    format!("cf_{}", custom_field_filter.id),
+                    custom_field_filter.value.to_string(),
+                );
+            }
+        }
         params
     }
 }
@@ -848,7 +1623,7 @@ impl std::fmt::Display for IssueInclude {
                 write!(f, "relations")
             }
             Self::Changesets => {
-                write!(f, "relations")
+                write!(f, "changesets")
             }
             Self::Journals => {
                 write!(f, "journals")
@@ -868,7 +1643,8 @@ impl std::fmt::Display for IssueInclude {
 #[builder(setter(strip_option))]
 pub struct GetIssue {
     /// id of the issue to retrieve
-    id: u64,
+    #[builder(setter(into))]
+    id: IssueId,
     /// associated data to include
     #[builder(default)]
     include: Option<Vec<IssueInclude>>,
@@ -893,7 +1669,7 @@ impl Endpoint for GetIssue {
         format!("issues/{}.json", &self.id).into()
     }
 
-    fn parameters(&self) -> QueryParams {
+    fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
         params.push_opt("include", self.include.as_ref());
         params
@@ -904,11 +1680,53 @@ impl Endpoint for GetIssue {
 #[derive(Debug, Clone, Serialize, serde::Deserialize)]
 pub struct CustomField<'a> {
     /// the custom field's id
-    id: u64,
+    pub id: u64,
     /// is usually present in contexts where it is returned by Redmine but can be omitted when it is sent by the client
-    name: Option<Cow<'a, str>>,
+    pub name: Option<Cow<'a, str>>,
     /// the custom field's value
-    value: Cow<'a, str>,
+    pub value: CustomFieldValue<'a>,
+}
+
+/// the value of a [CustomField], multi-value custom fields use a list
+///
+/// this can be created from strings and vectors of strings via `into()`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, serde::Deserialize)]
+#[serde(untagged)]
+pub enum CustomFieldValue<'a> {
+    /// the value of a single value custom field
+    Single(Cow<'a, str>),
+    /// the values of a multi-value custom field
+    Multiple(Vec<Cow<'a, str>>),
+}
+
+impl<'a> From<&'a str> for CustomFieldValue<'a> {
+    fn from(s: &'a str) -> Self {
+        Self::Single(s.into())
+    }
+}
+
+impl From<String> for CustomFieldValue<'_> {
+    fn from(s: String) -> Self {
+        Self::Single(s.into())
+    }
+}
+
+impl<'a> From<Cow<'a, str>> for CustomFieldValue<'a> {
+    fn from(s: Cow<'a, str>) -> Self {
+        Self::Single(s)
+    }
+}
+
+impl<'a> From<Vec<&'a str>> for CustomFieldValue<'a> {
+    fn from(v: Vec<&'a str>) -> Self {
+        Self::Multiple(v.into_iter().map(Into::into).collect())
+    }
+}
+
+impl From<Vec<String>> for CustomFieldValue<'_> {
+    fn from(v: Vec<String>) -> Self {
+        Self::Multiple(v.into_iter().map(Into::into).collect())
+    }
 }
 
 /// the information the uploader needs to supply for an attachment
@@ -926,13 +1744,44 @@ pub struct UploadedAttachment<'a> {
     pub content_type: Cow<'a, str>,
 }
 
+impl<'a> UploadedAttachment<'a> {
+    /// creates the attachment for an uploaded file, the filename is the last
+    /// component of the path and the content type is guessed from the
+    /// extension with [content_type_for_path|crate::api::uploads::content_type_for_path]
+    ///
+    /// # Errors
+    ///
+    /// This returns [crate::Error::UploadFileError] if the path has no
+    /// filename or it is not valid UTF-8
+    pub fn from_path(
+        token: impl Into<Cow<'a, str>>,
+        path: impl AsRef<std::path::Path>,
+    ) -> Result<Self, crate::Error> {
+        let path = path.as_ref();
+        Ok(Self {
+            token: token.into(),
+            filename: crate::api::uploads::upload_filename(path)?.into(),
+            description: None,
+            content_type: crate::api::uploads::content_type_for_path(path).into(),
+        })
+    }
+
+    /// sets the description of the attachment
+    #[must_use]
+    pub fn with_description(mut self, description: impl Into<Cow<'a, str>>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+}
+
 /// The endpoint to create a Redmine issue
 #[serde_with::skip_serializing_none]
 #[derive(Debug, Clone, Builder, Serialize)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct CreateIssue<'a> {
     /// project for the issue
-    project_id: u64,
+    #[builder(setter(into))]
+    project_id: ProjectId,
     /// tracker for the issue
     #[builder(default)]
     tracker_id: Option<u64>,
@@ -972,9 +1821,47 @@ pub struct CreateIssue<'a> {
     /// estimated hours it will take to implement this issue
     #[builder(default)]
     estimated_hours: Option<f64>,
+    /// percentage done (0-100), Redmine rejects other values so this is
+    /// checked when the endpoint is built
+    #[builder(default)]
+    done_ratio: Option<u64>,
+    /// the start date for the issue (serialized as YYYY-MM-DD)
+    #[builder(default)]
+    start_date: Option<time::Date>,
+    /// the due date for the issue (serialized as YYYY-MM-DD)
+    #[builder(default)]
+    due_date: Option<time::Date>,
     /// attachments (files)
     #[builder(default)]
     uploads: Option<Vec<UploadedAttachment<'a>>>,
+    /// associated data to include in the returned issue (e.g. watchers),
+    /// sent as a query parameter
+    #[builder(default)]
+    #[serde(skip_serializing)]
+    include: Option<Vec<IssueInclude>>,
+    /// see [idempotency_key_header](crate::api::idempotency_key_header)
+    #[builder(default)]
+    #[serde(skip_serializing)]
+    idempotency_key: Option<reqwest::header::HeaderValue>,
+}
+
+/// ensures that done_ratio is a percentage when [CreateIssueBuilder::build()]
+/// or [UpdateIssueBuilder::build()] is called
+fn validate_done_ratio(done_ratio: Option<Option<u64>>) -> Result<(), String> {
+    match done_ratio {
+        Some(Some(done_ratio)) if done_ratio > 100 => Err(format!(
+            "done_ratio needs to be between 0 and 100, not {}",
+            done_ratio
+        )),
+        _ => Ok(()),
+    }
+}
+
+impl CreateIssueBuilder<'_> {
+    /// ensures that done_ratio is a percentage when [Self::build()] is called
+    fn validate(&self) -> Result<(), String> {
+        validate_done_ratio(self.done_ratio)
+    }
 }
 
 impl<'a> CreateIssue<'a> {
@@ -996,6 +1883,16 @@ impl Endpoint for CreateIssue<'_> {
         "issues.json".into()
     }
 
+    fn headers(&self) -> reqwest::header::HeaderMap {
+        crate::api::idempotency_key_header(self.idempotency_key.as_ref())
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        params.push_opt("include", self.include.as_ref());
+        params
+    }
+
     fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, crate::Error> {
         Ok(Some((
             "application/json",
@@ -1006,14 +1903,44 @@ impl Endpoint for CreateIssue<'_> {
     }
 }
 
-/// The endpoint to update an existing Redmine issue
-#[serde_with::skip_serializing_none]
+/// the new assignee of an issue when updating it
+///
+/// a plain user/group id converts to [AssignTo::User]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AssignTo {
+    /// assign the issue to this user/group id
+    User(u64),
+    /// remove the current assignee, sent as an empty value
+    Unassign,
+}
+
+impl From<u64> for AssignTo {
+    fn from(id: u64) -> Self {
+        Self::User(id)
+    }
+}
+
+impl Serialize for AssignTo {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Self::User(id) => serializer.serialize_u64(*id),
+            Self::Unassign => serializer.serialize_str(""),
+        }
+    }
+}
+
+/// The endpoint to update an existing Redmine issue
+#[serde_with::skip_serializing_none]
 #[derive(Debug, Clone, Builder, Serialize)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct UpdateIssue<'a> {
     /// id of the issue to update
     #[serde(skip_serializing)]
-    id: u64,
+    #[builder(setter(into))]
+    id: IssueId,
     /// project for the issue
     #[builder(default)]
     project_id: Option<u64>,
@@ -1038,9 +1965,10 @@ pub struct UpdateIssue<'a> {
     /// ID of the Target Versions (previously called 'Fixed Version' and still referred to as such in the API)
     #[builder(default, setter(name = "version"))]
     fixed_version_id: Option<u64>,
-    /// user/group id the issue will be assigned to
-    #[builder(default)]
-    assigned_to_id: Option<u64>,
+    /// user/group id the issue will be assigned to or [AssignTo::Unassign]
+    /// to remove the current assignee, omitting it leaves the assignee unchanged
+    #[builder(default, setter(into))]
+    assigned_to_id: Option<AssignTo>,
     /// Id of the parent issue
     #[builder(default)]
     parent_issue_id: Option<u64>,
@@ -1056,6 +1984,16 @@ pub struct UpdateIssue<'a> {
     /// estimated hours it will take to implement this issue
     #[builder(default)]
     estimated_hours: Option<f64>,
+    /// percentage done (0-100), Redmine rejects other values so this is
+    /// checked when the endpoint is built
+    #[builder(default)]
+    done_ratio: Option<u64>,
+    /// the start date for the issue (serialized as YYYY-MM-DD)
+    #[builder(default)]
+    start_date: Option<time::Date>,
+    /// the due date for the issue (serialized as YYYY-MM-DD)
+    #[builder(default)]
+    due_date: Option<time::Date>,
     /// add a comment (note)
     #[builder(default)]
     notes: Option<Cow<'a, str>>,
@@ -1067,12 +2005,47 @@ pub struct UpdateIssue<'a> {
     uploads: Option<Vec<UploadedAttachment<'a>>>,
 }
 
+impl UpdateIssueBuilder<'_> {
+    /// ensures that done_ratio is a percentage when [Self::build()] is called
+    fn validate(&self) -> Result<(), String> {
+        validate_done_ratio(self.done_ratio)
+    }
+}
+
 impl<'a> UpdateIssue<'a> {
     /// Create a builder for the endpoint.
     #[must_use]
     pub fn builder() -> UpdateIssueBuilder<'a> {
         UpdateIssueBuilder::default()
     }
+
+    /// an update which changes nothing, the client helpers set the few
+    /// fields they need on top of this
+    fn unchanged(id: IssueId) -> Self {
+        Self {
+            id,
+            project_id: None,
+            tracker_id: None,
+            status_id: None,
+            priority_id: None,
+            subject: None,
+            description: None,
+            category_id: None,
+            fixed_version_id: None,
+            assigned_to_id: None,
+            parent_issue_id: None,
+            custom_fields: None,
+            watcher_user_ids: None,
+            is_private: None,
+            estimated_hours: None,
+            done_ratio: None,
+            start_date: None,
+            due_date: None,
+            notes: None,
+            private_notes: None,
+            uploads: None,
+        }
+    }
 }
 
 impl Endpoint for UpdateIssue<'_> {
@@ -1099,7 +2072,8 @@ impl Endpoint for UpdateIssue<'_> {
 #[builder(setter(strip_option))]
 pub struct DeleteIssue {
     /// id of the issue to delete
-    id: u64,
+    #[builder(setter(into))]
+    id: IssueId,
 }
 
 impl DeleteIssue {
@@ -1126,9 +2100,11 @@ impl Endpoint for DeleteIssue {
 pub struct AddWatcher {
     /// id of the issue to add the watcher to
     #[serde(skip_serializing)]
-    issue_id: u64,
+    #[builder(setter(into))]
+    issue_id: IssueId,
     /// id of the user to add as a watcher
-    user_id: u64,
+    #[builder(setter(into))]
+    user_id: UserId,
 }
 
 impl AddWatcher {
@@ -1158,9 +2134,11 @@ impl Endpoint for AddWatcher {
 #[builder(setter(strip_option))]
 pub struct RemoveWatcher {
     /// id of the issue to remove the watcher from
-    issue_id: u64,
+    #[builder(setter(into))]
+    issue_id: IssueId,
     /// id of the user to remove as a watcher
-    user_id: u64,
+    #[builder(setter(into))]
+    user_id: UserId,
 }
 
 impl RemoveWatcher {
@@ -1196,6 +2174,599 @@ pub struct IssueWrapper<T> {
     pub issue: T,
 }
 
+/// checks that the status is among the allowed statuses and, if expect_closed
+/// is set and Redmine reports it, that it is closed or open as expected
+fn check_status_transition(
+    issue_id: IssueId,
+    status_id: u64,
+    allowed_statuses: &[IssueStatusEssentials],
+    expect_closed: Option<bool>,
+) -> Result<(), crate::Error> {
+    let allowed = allowed_statuses.iter().any(|s| {
+        s.id == status_id
+            && match (expect_closed, s.is_closed) {
+                (Some(expected), Some(is_closed)) => expected == is_closed,
+                _ => true,
+            }
+    });
+    if allowed {
+        Ok(())
+    } else {
+        Err(crate::Error::IssueStatusTransitionNotAllowed {
+            issue_id: issue_id.0,
+            status_id,
+        })
+    }
+}
+
+impl Redmine {
+    /// creates several issues one after the other and returns the created
+    /// issues in the same order
+    ///
+    /// # Errors
+    ///
+    /// This stops at the first request that fails and returns
+    /// [crate::Error::BatchRequestFailed] with its index, the issues before it
+    /// have already been created at that point
+    pub fn create_issues(&self, endpoints: &[CreateIssue<'_>]) -> Result<Vec<Issue>, crate::Error> {
+        endpoints
+            .iter()
+            .enumerate()
+            .map(|(index, endpoint)| {
+                self.json_response_body::<_, IssueWrapper<Issue>>(endpoint)
+                    .map(|IssueWrapper { issue }| issue)
+                    .map_err(|e| crate::Error::BatchRequestFailed {
+                        index,
+                        id: None,
+                        source: Box::new(e),
+                    })
+            })
+            .collect()
+    }
+
+    /// returns the users watching an issue
+    ///
+    /// Redmine has no standalone endpoint to list the watchers of an issue
+    /// so this uses [GetIssue] with only the watchers include
+    ///
+    /// # Errors
+    ///
+    /// This can return an error if the web request fails, when Redmine returns
+    /// an HTTP error status (e.g. 404 if the issue does not exist) or when the
+    /// response can not be parsed
+    pub fn list_issue_watchers(
+        &self,
+        issue_id: impl Into<IssueId>,
+    ) -> Result<Vec<UserEssentials>, crate::Error> {
+        let endpoint = GetIssue {
+            id: issue_id.into(),
+            include: Some(vec![IssueInclude::Watchers]),
+        };
+        let IssueWrapper { issue } =
+            self.json_response_body::<_, IssueWrapper<Issue>>(&endpoint)?;
+        Ok(issue.watchers.unwrap_or_default())
+    }
+
+    /// returns the journals (comments and changes) of an issue
+    ///
+    /// Redmine has no standalone endpoint to list the journals of an issue
+    /// so this uses [GetIssue] with only the journals include
+    ///
+    /// # Errors
+    ///
+    /// This can return an error if the web request fails, when Redmine returns
+    /// an HTTP error status (e.g. 404 if the issue does not exist) or when the
+    /// response can not be parsed
+    pub fn list_issue_journals(
+        &self,
+        issue_id: impl Into<IssueId>,
+    ) -> Result<Vec<Journal>, crate::Error> {
+        let endpoint = GetIssue {
+            id: issue_id.into(),
+            include: Some(vec![IssueInclude::Journals]),
+        };
+        let IssueWrapper { issue } =
+            self.json_response_body::<_, IssueWrapper<Issue>>(&endpoint)?;
+        Ok(issue.journals.unwrap_or_default())
+    }
+
+    /// adds several users as watchers to an issue, one request per user
+    /// (the same request as [AddWatcher])
+    ///
+    /// # Errors
+    ///
+    /// This stops at the first request that fails and returns
+    /// [crate::Error::BatchRequestFailed] with the index and id of the user, the
+    /// users before it have already been added at that point
+    pub fn add_issue_watchers(
+        &self,
+        issue_id: impl Into<IssueId>,
+        user_ids: &[u64],
+    ) -> Result<(), crate::Error> {
+        let issue_id = issue_id.into();
+        for (index, user_id) in user_ids.iter().enumerate() {
+            let endpoint = AddWatcher {
+                issue_id,
+                user_id: UserId(*user_id),
+            };
+            self.ignore_response_body(&endpoint)
+                .map_err(|e| crate::Error::BatchRequestFailed {
+                    index,
+                    id: Some(*user_id),
+                    source: Box::new(e),
+                })?;
+        }
+        Ok(())
+    }
+
+    /// removes several users as watchers from an issue, one request per user
+    /// (the same request as [RemoveWatcher])
+    ///
+    /// # Errors
+    ///
+    /// This stops at the first request that fails and returns
+    /// [crate::Error::BatchRequestFailed] with the index and id of the user, the
+    /// users before it have already been removed at that point
+    pub fn remove_issue_watchers(
+        &self,
+        issue_id: impl Into<IssueId>,
+        user_ids: &[u64],
+    ) -> Result<(), crate::Error> {
+        let issue_id = issue_id.into();
+        for (index, user_id) in user_ids.iter().enumerate() {
+            let endpoint = RemoveWatcher {
+                issue_id,
+                user_id: UserId(*user_id),
+            };
+            self.ignore_response_body(&endpoint)
+                .map_err(|e| crate::Error::BatchRequestFailed {
+                    index,
+                    id: Some(*user_id),
+                    source: Box::new(e),
+                })?;
+        }
+        Ok(())
+    }
+
+    /// adds a note (comment) to an issue without changing anything else,
+    /// this uses [UpdateIssue] with only notes and private_notes set
+    ///
+    /// private notes are only visible to users with the permission to view
+    /// private notes
+    ///
+    /// # Errors
+    ///
+    /// This can return an error if the web request fails or when Redmine
+    /// returns an HTTP error status
+    pub fn add_issue_note(
+        &self,
+        issue_id: impl Into<IssueId>,
+        notes: &str,
+        private: bool,
+    ) -> Result<(), crate::Error> {
+        self.ignore_response_body(&UpdateIssue {
+            notes: Some(notes.into()),
+            private_notes: Some(private),
+            ..UpdateIssue::unchanged(issue_id.into())
+        })
+    }
+
+    /// changes the status of an issue (the same request as [UpdateIssue]
+    /// with status_id and notes) after checking that the transition is allowed
+    /// using the allowed_statuses include of [GetIssue]
+    ///
+    /// Redmine before 5.0 does not return the allowed statuses, the check is
+    /// skipped in that case and Redmine decides on its own
+    ///
+    /// # Errors
+    ///
+    /// This returns [crate::Error::IssueStatusTransitionNotAllowed] if the
+    /// status is not among the allowed statuses of the issue, otherwise it can
+    /// return an error if the web request fails, when Redmine returns an HTTP
+    /// error status or when the response can not be parsed
+    pub fn update_issue_status(
+        &self,
+        issue_id: impl Into<IssueId>,
+        status_id: u64,
+        notes: Option<&str>,
+    ) -> Result<(), crate::Error> {
+        self.change_issue_status(issue_id.into(), status_id, notes, None)
+    }
+
+    /// closes an issue, like [Redmine::update_issue_status] but the status
+    /// also needs to be a closed status
+    ///
+    /// # Errors
+    ///
+    /// This returns [crate::Error::IssueStatusTransitionNotAllowed] if the
+    /// status is not among the allowed statuses of the issue or if Redmine
+    /// reports it as not closed, otherwise it can return an error if the web
+    /// request fails, when Redmine returns an HTTP error status or when the
+    /// response can not be parsed
+    pub fn close_issue(
+        &self,
+        issue_id: impl Into<IssueId>,
+        status_id: u64,
+        notes: Option<&str>,
+    ) -> Result<(), crate::Error> {
+        self.change_issue_status(issue_id.into(), status_id, notes, Some(true))
+    }
+
+    /// reopens an issue, like [Redmine::update_issue_status] but the status
+    /// also needs to be an open status
+    ///
+    /// # Errors
+    ///
+    /// This returns [crate::Error::IssueStatusTransitionNotAllowed] if the
+    /// status is not among the allowed statuses of the issue or if Redmine
+    /// reports it as closed, otherwise it can return an error if the web
+    /// request fails, when Redmine returns an HTTP error status or when the
+    /// response can not be parsed
+    pub fn reopen_issue(
+        &self,
+        issue_id: impl Into<IssueId>,
+        status_id: u64,
+        notes: Option<&str>,
+    ) -> Result<(), crate::Error> {
+        self.change_issue_status(issue_id.into(), status_id, notes, Some(false))
+    }
+
+    /// shared implementation of the issue status helpers
+    ///
+    /// # Errors
+    ///
+    /// see [Redmine::update_issue_status]
+    fn change_issue_status(
+        &self,
+        issue_id: IssueId,
+        status_id: u64,
+        notes: Option<&str>,
+        expect_closed: Option<bool>,
+    ) -> Result<(), crate::Error> {
+        let endpoint = GetIssue {
+            id: issue_id,
+            include: Some(vec![IssueInclude::AllowedStatuses]),
+        };
+        let IssueWrapper { issue } =
+            self.json_response_body::<_, IssueWrapper<Issue>>(&endpoint)?;
+        if let Some(allowed_statuses) = issue.allowed_statuses {
+            check_status_transition(issue_id, status_id, &allowed_statuses, expect_closed)?;
+        }
+        self.ignore_response_body(&UpdateIssue {
+            status_id: Some(status_id),
+            notes: notes.map(Cow::Borrowed),
+            ..UpdateIssue::unchanged(issue_id)
+        })
+    }
+
+    /// uploads a local file and attaches it to an issue, this is the same as
+    /// uploading it with [UploadFile](crate::api::uploads::UploadFile) and
+    /// passing the token in an [UploadedAttachment] to [UpdateIssue]
+    ///
+    /// the filename and content type are set by [UploadedAttachment::from_path]
+    ///
+    /// # Errors
+    ///
+    /// This can return an error if the file can not be read, when one of the
+    /// web requests fails, when Redmine returns an HTTP error status or when
+    /// the upload response can not be parsed
+    pub fn attach_file_to_issue(
+        &self,
+        issue_id: impl Into<IssueId>,
+        path: impl AsRef<std::path::Path>,
+        description: Option<&str>,
+    ) -> Result<(), crate::Error> {
+        let path = path.as_ref();
+        let filename = upload_filename(path)?;
+        let file = std::fs::File::open(path)
+            .map_err(|e| crate::Error::UploadFileError(path.to_path_buf(), e))?;
+        let FileUploadToken { token } = self.upload_file_from_reader(file, &filename)?;
+        let mut attachment = UploadedAttachment::from_path(token, path)?;
+        if let Some(description) = description {
+            attachment = attachment.with_description(description);
+        }
+        self.ignore_response_body(&UpdateIssue {
+            uploads: Some(vec![attachment]),
+            ..UpdateIssue::unchanged(issue_id.into())
+        })
+    }
+}
+
+impl RedmineAsync {
+    /// creates several issues concurrently (at most [MAX_CONCURRENT_REQUESTS]
+    /// requests at a time) and returns the created issues in the same order as
+    /// the endpoints
+    ///
+    /// # Errors
+    ///
+    /// This stops at the first request that fails and returns
+    /// [crate::Error::BatchRequestFailed] with its index, the issues before it
+    /// and possibly some of those after it which were already in flight have
+    /// been created at that point
+    pub async fn create_issues(
+        &self,
+        endpoints: &[CreateIssue<'_>],
+    ) -> Result<Vec<Issue>, crate::Error> {
+        futures::stream::iter(endpoints.iter().enumerate())
+            .map(|(index, endpoint)| async move {
+                self.json_response_body::<_, IssueWrapper<Issue>>(endpoint)
+                    .await
+                    .map(|IssueWrapper { issue }| issue)
+                    .map_err(|e| crate::Error::BatchRequestFailed {
+                        index,
+                        id: None,
+                        source: Box::new(e),
+                    })
+            })
+            .buffered(MAX_CONCURRENT_REQUESTS)
+            .try_collect()
+            .await
+    }
+
+    /// returns the users watching an issue
+    ///
+    /// Redmine has no standalone endpoint to list the watchers of an issue
+    /// so this uses [GetIssue] with only the watchers include
+    ///
+    /// # Errors
+    ///
+    /// This can return an error if the web request fails, when Redmine returns
+    /// an HTTP error status (e.g. 404 if the issue does not exist) or when the
+    /// response can not be parsed
+    pub async fn list_issue_watchers(
+        &self,
+        issue_id: impl Into<IssueId>,
+    ) -> Result<Vec<UserEssentials>, crate::Error> {
+        let endpoint = GetIssue {
+            id: issue_id.into(),
+            include: Some(vec![IssueInclude::Watchers]),
+        };
+        let IssueWrapper { issue } = self
+            .json_response_body::<_, IssueWrapper<Issue>>(&endpoint)
+            .await?;
+        Ok(issue.watchers.unwrap_or_default())
+    }
+
+    /// returns the journals (comments and changes) of an issue
+    ///
+    /// Redmine has no standalone endpoint to list the journals of an issue
+    /// so this uses [GetIssue] with only the journals include
+    ///
+    /// # Errors
+    ///
+    /// This can return an error if the web request fails, when Redmine returns
+    /// an HTTP error status (e.g. 404 if the issue does not exist) or when the
+    /// response can not be parsed
+    pub async fn list_issue_journals(
+        &self,
+        issue_id: impl Into<IssueId>,
+    ) -> Result<Vec<Journal>, crate::Error> {
+        let endpoint = GetIssue {
+            id: issue_id.into(),
+            include: Some(vec![IssueInclude::Journals]),
+        };
+        let IssueWrapper { issue } = self
+            .json_response_body::<_, IssueWrapper<Issue>>(&endpoint)
+            .await?;
+        Ok(issue.journals.unwrap_or_default())
+    }
+
+    /// adds several users as watchers to an issue, one request per user
+    /// (the same request as [AddWatcher]), up to [MAX_CONCURRENT_REQUESTS]
+    /// requests are sent concurrently
+    ///
+    /// # Errors
+    ///
+    /// This stops at the first request that fails and returns
+    /// [crate::Error::BatchRequestFailed] with the index and id of the user, other
+    /// users may or may not have been added at that point
+    pub async fn add_issue_watchers(
+        &self,
+        issue_id: impl Into<IssueId>,
+        user_ids: &[u64],
+    ) -> Result<(), crate::Error> {
+        let issue_id = issue_id.into();
+        futures::stream::iter(user_ids.iter().enumerate().map(Ok))
+            .try_for_each_concurrent(MAX_CONCURRENT_REQUESTS, |(index, user_id)| async move {
+                let endpoint = AddWatcher {
+                    issue_id,
+                    user_id: UserId(*user_id),
+                };
+                self.ignore_response_body(&endpoint).await.map_err(|e| {
+                    crate::Error::BatchRequestFailed {
+                        index,
+                        id: Some(*user_id),
+                        source: Box::new(e),
+                    }
+                })
+            })
+            .await
+    }
+
+    /// removes several users as watchers from an issue, one request per user
+    /// (the same request as [RemoveWatcher]), up to [MAX_CONCURRENT_REQUESTS]
+    /// requests are sent concurrently
+    ///
+    /// # Errors
+    ///
+    /// This stops at the first request that fails and returns
+    /// [crate::Error::BatchRequestFailed] with the index and id of the user, other
+    /// users may or may not have been removed at that point
+    pub async fn remove_issue_watchers(
+        &self,
+        issue_id: impl Into<IssueId>,
+        user_ids: &[u64],
+    ) -> Result<(), crate::Error> {
+        let issue_id = issue_id.into();
+        futures::stream::iter(user_ids.iter().enumerate().map(Ok))
+            .try_for_each_concurrent(MAX_CONCURRENT_REQUESTS, |(index, user_id)| async move {
+                let endpoint = RemoveWatcher {
+                    issue_id,
+                    user_id: UserId(*user_id),
+                };
+                self.ignore_response_body(&endpoint).await.map_err(|e| {
+                    crate::Error::BatchRequestFailed {
+                        index,
+                        id: Some(*user_id),
+                        source: Box::new(e),
+                    }
+                })
+            })
+            .await
+    }
+
+    /// adds a note (comment) to an issue without changing anything else,
+    /// this uses [UpdateIssue] with only notes and private_notes set
+    ///
+    /// private notes are only visible to users with the permission to view
+    /// private notes
+    ///
+    /// # Errors
+    ///
+    /// This can return an error if the web request fails or when Redmine
+    /// returns an HTTP error status
+    pub async fn add_issue_note(
+        &self,
+        issue_id: impl Into<IssueId>,
+        notes: &str,
+        private: bool,
+    ) -> Result<(), crate::Error> {
+        self.ignore_response_body(&UpdateIssue {
+            notes: Some(notes.into()),
+            private_notes: Some(private),
+            ..UpdateIssue::unchanged(issue_id.into())
+        })
+        .await
+    }
+
+    /// changes the status of an issue (the same request as [UpdateIssue]
+    /// with status_id and notes) after checking that the transition is allowed
+    /// using the allowed_statuses include of [GetIssue]
+    ///
+    /// Redmine before 5.0 does not return the allowed statuses, the check is
+    /// skipped in that case and Redmine decides on its own
+    ///
+    /// # Errors
+    ///
+    /// This returns [crate::Error::IssueStatusTransitionNotAllowed] if the
+    /// status is not among the allowed statuses of the issue, otherwise it can
+    /// return an error if the web request fails, when Redmine returns an HTTP
+    /// error status or when the response can not be parsed
+    pub async fn update_issue_status(
+        &self,
+        issue_id: impl Into<IssueId>,
+        status_id: u64,
+        notes: Option<&str>,
+    ) -> Result<(), crate::Error> {
+        self.change_issue_status(issue_id.into(), status_id, notes, None)
+            .await
+    }
+
+    /// closes an issue, like [RedmineAsync::update_issue_status] but the status
+    /// also needs to be a closed status
+    ///
+    /// # Errors
+    ///
+    /// This returns [crate::Error::IssueStatusTransitionNotAllowed] if the
+    /// status is not among the allowed statuses of the issue or if Redmine
+    /// reports it as not closed, otherwise it can return an error if the web
+    /// request fails, when Redmine returns an HTTP error status or when the
+    /// response can not be parsed
+    pub async fn close_issue(
+        &self,
+        issue_id: impl Into<IssueId>,
+        status_id: u64,
+        notes: Option<&str>,
+    ) -> Result<(), crate::Error> {
+        self.change_issue_status(issue_id.into(), status_id, notes, Some(true))
+            .await
+    }
+
+    /// reopens an issue, like [RedmineAsync::update_issue_status] but the status
+    /// also needs to be an open status
+    ///
+    /// # Errors
+    ///
+    /// This returns [crate::Error::IssueStatusTransitionNotAllowed] if the
+    /// status is not among the allowed statuses of the issue or if Redmine
+    /// reports it as closed, otherwise it can return an error if the web
+    /// request fails, when Redmine returns an HTTP error status or when the
+    /// response can not be parsed
+    pub async fn reopen_issue(
+        &self,
+        issue_id: impl Into<IssueId>,
+        status_id: u64,
+        notes: Option<&str>,
+    ) -> Result<(), crate::Error> {
+        self.change_issue_status(issue_id.into(), status_id, notes, Some(false))
+            .await
+    }
+
+    /// shared implementation of the issue status helpers
+    ///
+    /// # Errors
+    ///
+    /// see [RedmineAsync::update_issue_status]
+    async fn change_issue_status(
+        &self,
+        issue_id: IssueId,
+        status_id: u64,
+        notes: Option<&str>,
+        expect_closed: Option<bool>,
+    ) -> Result<(), crate::Error> {
+        let endpoint = GetIssue {
+            id: issue_id,
+            include: Some(vec![IssueInclude::AllowedStatuses]),
+        };
+        let IssueWrapper { issue } = self
+            .json_response_body::<_, IssueWrapper<Issue>>(&endpoint)
+            .await?;
+        if let Some(allowed_statuses) = issue.allowed_statuses {
+            check_status_transition(issue_id, status_id, &allowed_statuses, expect_closed)?;
+        }
+        self.ignore_response_body(&UpdateIssue {
+            status_id: Some(status_id),
+            notes: notes.map(Cow::Borrowed),
+            ..UpdateIssue::unchanged(issue_id)
+        })
+        .await
+    }
+
+    /// uploads a local file and attaches it to an issue, this is the same as
+    /// uploading it with [UploadFile](crate::api::uploads::UploadFile) and
+    /// passing the token in an [UploadedAttachment] to [UpdateIssue]
+    ///
+    /// the filename and content type are set by [UploadedAttachment::from_path]
+    ///
+    /// # Errors
+    ///
+    /// This can return an error if the file can not be read, when one of the
+    /// web requests fails, when Redmine returns an HTTP error status or when
+    /// the upload response can not be parsed
+    pub async fn attach_file_to_issue(
+        &self,
+        issue_id: impl Into<IssueId>,
+        path: impl AsRef<std::path::Path>,
+        description: Option<&str>,
+    ) -> Result<(), crate::Error> {
+        let path = path.as_ref();
+        let filename = upload_filename(path)?;
+        let file = tokio::fs::File::open(path)
+            .await
+            .map_err(|e| crate::Error::UploadFileError(path.to_path_buf(), e))?;
+        let FileUploadToken { token } = self.upload_file_from_reader(file, &filename).await?;
+        let mut attachment = UploadedAttachment::from_path(token, path)?;
+        if let Some(description) = description {
+            attachment = attachment.with_description(description);
+        }
+        self.ignore_response_body(&UpdateIssue {
+            uploads: Some(vec![attachment]),
+            ..UpdateIssue::unchanged(issue_id.into())
+        })
+        .await
+    }
+}
+
 #[cfg(test)]
 pub(crate) mod test {
     use super::*;
@@ -1209,6 +2780,29 @@ pub(crate) mod test {
     /// creating/deleting or creating/updating/deleting
     pub static ISSUES_LOCK: RwLock<()> = RwLock::const_new(());
 
+    /// the JSON of a minimal unassigned public issue as Redmine returns it,
+    /// for tests which need an [Issue] without a Redmine instance
+    pub(crate) fn issue_json(id: u64) -> serde_json::Value {
+        serde_json::json!({
+            "id": id,
+            "project": { "id": 1, "name": "Sandbox" },
+            "tracker": { "id": 1, "name": "Bug" },
+            "status": { "id": 1, "name": "New", "is_closed": false },
+            "priority": { "id": 2, "name": "Normal" },
+            "author": { "id": 1, "name": "Redmine Admin" },
+            "subject": "Test Issue",
+            "description": "",
+            "start_date": null,
+            "due_date": null,
+            "done_ratio": 0,
+            "is_private": false,
+            "estimated_hours": null,
+            "created_on": "2024-03-01T12:00:00Z",
+            "updated_on": "2024-03-01T12:00:00Z",
+            "closed_on": null
+        })
+    }
+
     #[traced_test]
     #[test]
     fn test_list_issues_no_pagination() -> Result<(), Box<dyn Error>> {
@@ -1231,161 +2825,1130 @@ pub(crate) mod test {
         Ok(())
     }
 
-    /// this version of the test will load all pages of issues which means it
-    /// can take a while (a minute or more) so you need to use --include-ignored
-    /// or --ignored to run it
     #[traced_test]
     #[test]
-    #[ignore]
-    fn test_list_issues_all_pages() -> Result<(), Box<dyn Error>> {
+    fn test_count_issues() -> Result<(), Box<dyn Error>> {
         let _r_issues = ISSUES_LOCK.read();
         dotenvy::dotenv()?;
         let redmine = crate::api::Redmine::from_env()?;
         let endpoint = ListIssues::builder().build()?;
-        redmine.json_response_body_all_pages::<_, Issue>(&endpoint)?;
+        let count = redmine.count(&endpoint)?;
+        let page = redmine.json_response_body_page::<_, Issue>(&endpoint, 0, 25)?;
+        assert_eq!(count, page.total_count);
         Ok(())
     }
 
     #[traced_test]
     #[test]
-    fn test_get_issue() -> Result<(), Box<dyn Error>> {
+    fn test_list_issues_status_filter_by_name() -> Result<(), Box<dyn Error>> {
         let _r_issues = ISSUES_LOCK.read();
         dotenvy::dotenv()?;
         let redmine = crate::api::Redmine::from_env()?;
-        let endpoint = GetIssue::builder().id(40000).build()?;
-        redmine.json_response_body::<_, IssueWrapper<Issue>>(&endpoint)?;
+        let status_filter = match redmine.issue_status_filter(&["Closed"]) {
+            Ok(status_filter) => status_filter,
+            Err(crate::Error::UnknownIssueStatus(_)) => return Ok(()),
+            Err(e) => return Err(e.into()),
+        };
+        let closed_ids = redmine
+            .issue_statuses()?
+            .iter()
+            .filter(|s| s.name == "Closed")
+            .map(|s| s.id)
+            .collect::<Vec<_>>();
+        let endpoint = ListIssues::builder().status_id(status_filter).build()?;
+        let page = redmine.json_response_body_page::<_, Issue>(&endpoint, 0, 25)?;
+        for issue in page.values {
+            assert!(closed_ids.contains(&issue.status.id));
+        }
         Ok(())
     }
 
-    #[function_name::named]
-    #[traced_test]
     #[test]
-    fn test_create_issue() -> Result<(), Box<dyn Error>> {
-        let _w_issues = ISSUES_LOCK.write();
-        let name = format!("unittest_{}", function_name!());
-        with_project(&name, |redmine, project_id, _| {
-            let create_endpoint = super::CreateIssue::builder()
-                .project_id(project_id)
-                .subject("old test subject")
-                .build()?;
-            redmine.json_response_body::<_, IssueWrapper<Issue>>(&create_endpoint)?;
-            Ok(())
-        })?;
+    fn test_list_issues_parent_id_parameter() -> Result<(), Box<dyn Error>> {
+        for (filter, query) in [
+            (ParentFilter::from(vec![3, 4]), "parent_id=3%2C4"),
+            (ParentFilter::None, "parent_id=%21*"),
+            (ParentFilter::Any, "parent_id=*"),
+        ] {
+            let endpoint = ListIssues::builder().parent_id(filter).build()?;
+            let mut url = url::Url::parse("https://redmine.example.com/issues.json")?;
+            endpoint.parameters().add_to_url(&mut url);
+            assert_eq!(url.query(), Some(query));
+        }
+        let endpoint = ListIssues::builder().parent_id(vec![3]).build()?;
+        let mut url = url::Url::parse("https://redmine.example.com/issues.json")?;
+        endpoint.parameters().add_to_url(&mut url);
+        assert_eq!(url.query(), Some("parent_id=3"));
         Ok(())
     }
 
-    #[function_name::named]
-    #[traced_test]
     #[test]
-    fn test_update_issue() -> Result<(), Box<dyn Error>> {
-        let _w_issues = ISSUES_LOCK.write();
-        let name = format!("unittest_{}", function_name!());
-        with_project(&name, |redmine, project_id, _name| {
-            let create_endpoint = super::CreateIssue::builder()
-                .project_id(project_id)
-                .subject("old test subject")
-                .build()?;
-            let IssueWrapper { issue }: IssueWrapper<Issue> =
-                redmine.json_response_body::<_, _>(&create_endpoint)?;
-            let update_endpoint = super::UpdateIssue::builder()
-                .id(issue.id)
-                .subject("New test subject")
-                .build()?;
-            redmine.ignore_response_body::<_>(&update_endpoint)?;
-            Ok(())
-        })?;
-        Ok(())
+    fn test_date_time_filter_display() {
+        assert_eq!(DateTimeFilter::InLessThanDays(3).to_string(), "<t+3");
+        assert_eq!(DateTimeFilter::InMoreThanDays(3).to_string(), ">t+3");
+        assert_eq!(DateTimeFilter::WithinNextDays(7).to_string(), "><t+7");
+        assert_eq!(DateTimeFilter::InDays(2).to_string(), "t+2");
+        assert_eq!(DateTimeFilter::Tomorrow.to_string(), "nd");
+        assert_eq!(DateTimeFilter::NextWeek.to_string(), "nw");
+        assert_eq!(DateTimeFilter::NextMonth.to_string(), "nm");
+        assert_eq!(
+            DateTimeFilter::from(DateTimeFilterPast::LessThanDaysAgo(5)).to_string(),
+            ">t-5"
+        );
     }
 
-    #[function_name::named]
-    #[traced_test]
     #[test]
-    fn test_delete_issue() -> Result<(), Box<dyn Error>> {
-        let _w_issues = ISSUES_LOCK.write();
-        let name = format!("unittest_{}", function_name!());
-        with_project(&name, |redmine, project_id, _name| {
-            let create_endpoint = super::CreateIssue::builder()
-                .project_id(project_id)
-                .subject("test subject")
-                .build()?;
-            let IssueWrapper { issue }: IssueWrapper<Issue> =
-                redmine.json_response_body::<_, _>(&create_endpoint)?;
-            let delete_endpoint = super::DeleteIssue::builder().id(issue.id).build()?;
-            redmine.ignore_response_body::<_>(&delete_endpoint)?;
-            Ok(())
-        })?;
+    fn test_list_issues_tracker_id_parameter() -> Result<(), Box<dyn Error>> {
+        for (filter, query) in [
+            (TrackerFilter::from(vec![1, 2]), "tracker_id=1%2C2"),
+            (TrackerFilter::NotTheseTrackers(vec![1]), "tracker_id=%211"),
+        ] {
+            let endpoint = ListIssues::builder().tracker_id(filter).build()?;
+            let mut url = url::Url::parse("https://redmine.example.com/issues.json")?;
+            endpoint.parameters().add_to_url(&mut url);
+            assert_eq!(url.query(), Some(query));
+        }
         Ok(())
     }
 
-    /// this tests if any of the results contain a field we are not deserializing
-    ///
-    /// this will only catch fields we missed if they are part of the response but
-    /// it is better than nothing
     #[traced_test]
     #[test]
-    fn test_completeness_issue_type() -> Result<(), Box<dyn Error>> {
+    fn test_list_issues_not_these_trackers() -> Result<(), Box<dyn Error>> {
         let _r_issues = ISSUES_LOCK.read();
         dotenvy::dotenv()?;
         let redmine = crate::api::Redmine::from_env()?;
         let endpoint = ListIssues::builder()
-            .include(vec![
-                IssueListInclude::Attachments,
-                IssueListInclude::Relations,
-            ])
+            .tracker_id(TrackerFilter::NotTheseTrackers(vec![1]))
             .build()?;
-        let IssuesWrapper { issues: values } =
-            redmine.json_response_body::<_, IssuesWrapper<serde_json::Value>>(&endpoint)?;
-        for value in values {
-            let o: Issue = serde_json::from_value(value.clone())?;
-            let reserialized = serde_json::to_value(o)?;
-            assert_eq!(value, reserialized);
+        let issues = redmine.json_response_body_page::<_, Issue>(&endpoint, 0, 25)?;
+        assert!(issues.values.iter().all(|i| i.tracker.id != 1));
+        Ok(())
+    }
+
+    #[test]
+    fn test_list_issues_version_id_parameter() -> Result<(), Box<dyn Error>> {
+        for (filter, query) in [
+            (VersionFilter::from(vec![3, 4]), "fixed_version_id=3%2C4"),
+            (
+                VersionFilter::NotTheseVersions(vec![3]),
+                "fixed_version_id=%213",
+            ),
+            (VersionFilter::NoVersion, "fixed_version_id=%21*"),
+            (VersionFilter::AnyVersion, "fixed_version_id=*"),
+        ] {
+            let endpoint = ListIssues::builder().version_id(filter).build()?;
+            let mut url = url::Url::parse("https://redmine.example.com/issues.json")?;
+            endpoint.parameters().add_to_url(&mut url);
+            assert_eq!(url.query(), Some(query));
         }
         Ok(())
     }
 
-    /// this tests if any of the results contain a field we are not deserializing
-    ///
-    /// this will only catch fields we missed if they are part of the response but
-    /// it is better than nothing
-    ///
-    /// this version of the test will load all pages of issues which means it
-    /// can take a while (a minute or more) so you need to use --include-ignored
-    /// or --ignored to run it
     #[traced_test]
     #[test]
-    #[ignore]
-    fn test_completeness_issue_type_all_pages() -> Result<(), Box<dyn Error>> {
+    fn test_list_issues_without_version() -> Result<(), Box<dyn Error>> {
         let _r_issues = ISSUES_LOCK.read();
         dotenvy::dotenv()?;
         let redmine = crate::api::Redmine::from_env()?;
         let endpoint = ListIssues::builder()
-            .include(vec![
-                IssueListInclude::Attachments,
-                IssueListInclude::Relations,
-            ])
+            .version_id(VersionFilter::NoVersion)
             .build()?;
-        let values = redmine.json_response_body_all_pages::<_, serde_json::Value>(&endpoint)?;
-        for value in values {
-            let o: Issue = serde_json::from_value(value.clone())?;
-            let reserialized = serde_json::to_value(o)?;
-            assert_eq!(value, reserialized);
+        let issues = redmine.json_response_body_page::<_, Issue>(&endpoint, 0, 25)?;
+        assert!(issues.values.iter().all(|i| i.version.is_none()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_list_issues_category_id_parameter() -> Result<(), Box<dyn Error>> {
+        for (filter, query) in [
+            (CategoryFilter::from(vec![3, 4]), "category_id=3%2C4"),
+            (
+                CategoryFilter::NotTheseCategories(vec![3]),
+                "category_id=%213",
+            ),
+            (CategoryFilter::NoCategory, "category_id=%21*"),
+            (CategoryFilter::AnyCategory, "category_id=*"),
+        ] {
+            let endpoint = ListIssues::builder().category_id(filter).build()?;
+            let mut url = url::Url::parse("https://redmine.example.com/issues.json")?;
+            endpoint.parameters().add_to_url(&mut url);
+            assert_eq!(url.query(), Some(query));
         }
         Ok(())
     }
 
-    /// this tests if any of the results contain a field we are not deserializing
-    ///
-    /// this will only catch fields we missed if they are part of the response but
-    /// it is better than nothing
-    ///
-    /// this version of the test will load all pages of issues and the individual
-    /// issues for each via GetIssue which means it
-    /// can take a while (about 400 seconds) so you need to use --include-ignored
-    /// or --ignored to run it
     #[traced_test]
     #[test]
-    #[ignore]
-    fn test_completeness_issue_type_all_pages_all_issue_details() -> Result<(), Box<dyn Error>> {
+    fn test_list_issues_uncategorized() -> Result<(), Box<dyn Error>> {
+        let _r_issues = ISSUES_LOCK.read();
+        dotenvy::dotenv()?;
+        let redmine = crate::api::Redmine::from_env()?;
+        let endpoint = ListIssues::builder()
+            .category_id(CategoryFilter::NoCategory)
+            .build()?;
+        let issues = redmine.json_response_body_page::<_, Issue>(&endpoint, 0, 25)?;
+        assert!(issues.values.iter().all(|i| i.category.is_none()));
+        Ok(())
+    }
+
+    #[traced_test]
+    #[test]
+    fn test_list_issues_root_issues() -> Result<(), Box<dyn Error>> {
+        let _r_issues = ISSUES_LOCK.read();
+        dotenvy::dotenv()?;
+        let redmine = crate::api::Redmine::from_env()?;
+        let endpoint = ListIssues::builder()
+            .parent_id(ParentFilter::None)
+            .build()?;
+        let issues = redmine.json_response_body_page::<_, Issue>(&endpoint, 0, 25)?;
+        assert!(issues.values.iter().all(|i| i.parent.is_none()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_issue_include_display() {
+        assert_eq!(IssueListInclude::Attachments.to_string(), "attachments");
+        assert_eq!(IssueListInclude::Relations.to_string(), "relations");
+        assert_eq!(IssueInclude::Changesets.to_string(), "changesets");
+    }
+
+    #[traced_test]
+    #[test]
+    fn test_list_issues_include_attachments() -> Result<(), Box<dyn Error>> {
+        let _r_issues = ISSUES_LOCK.read();
+        dotenvy::dotenv()?;
+        let redmine = crate::api::Redmine::from_env()?;
+        let endpoint = ListIssues::builder()
+            .include(vec![IssueListInclude::Attachments])
+            .build()?;
+        let issues = redmine.json_response_body_page::<_, Issue>(&endpoint, 0, 25)?;
+        assert!(issues.values.iter().all(|i| i.attachments.is_some()));
+        Ok(())
+    }
+
+    #[traced_test]
+    #[test]
+    fn test_list_issues_include_relations() -> Result<(), Box<dyn Error>> {
+        let _r_issues = ISSUES_LOCK.read();
+        dotenvy::dotenv()?;
+        let redmine = crate::api::Redmine::from_env()?;
+        let endpoint = ListIssues::builder()
+            .include(vec![IssueListInclude::Relations])
+            .build()?;
+        let issues = redmine.json_response_body_page::<_, Issue>(&endpoint, 0, 25)?;
+        assert!(issues.values.iter().all(|i| i.relations.is_some()));
+        Ok(())
+    }
+
+    #[traced_test]
+    #[test]
+    fn test_list_issues_done_ratio_filter() -> Result<(), Box<dyn Error>> {
+        let _r_issues = ISSUES_LOCK.read();
+        dotenvy::dotenv()?;
+        let redmine = crate::api::Redmine::from_env()?;
+        let endpoint = ListIssues::builder()
+            .done_ratio(IntegerFilter::Range(25, 75))
+            .build()?;
+        let issues = redmine.json_response_body_page::<_, Issue>(&endpoint, 0, 25)?;
+        for issue in issues.values {
+            assert!((25..=75).contains(&issue.done_ratio));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_list_issues_done_ratio_parameter() -> Result<(), Box<dyn Error>> {
+        for (filter, query) in [
+            (IntegerFilter::ExactMatch(50), "done_ratio=50"),
+            (IntegerFilter::Range(25, 75), "done_ratio=%3E%3C25%7C75"),
+            (IntegerFilter::LessThanOrEqual(30), "done_ratio=%3C%3D30"),
+            (IntegerFilter::GreaterThanOrEqual(70), "done_ratio=%3E%3D70"),
+            (IntegerFilter::Any, "done_ratio=*"),
+            (IntegerFilter::None, "done_ratio=%21*"),
+        ] {
+            let endpoint = ListIssues::builder().done_ratio(filter).build()?;
+            let mut url = url::Url::parse("https://redmine.example.com/issues.json")?;
+            endpoint.parameters().add_to_url(&mut url);
+            assert_eq!(url.query(), Some(query));
+        }
+        Ok(())
+    }
+
+    #[traced_test]
+    #[test]
+    fn test_list_issues_estimated_hours_filter() -> Result<(), Box<dyn Error>> {
+        let _r_issues = ISSUES_LOCK.read();
+        dotenvy::dotenv()?;
+        let redmine = crate::api::Redmine::from_env()?;
+        let endpoint = ListIssues::builder()
+            .estimated_hours(FloatFilter::GreaterThanOrEqual(8.0))
+            .build()?;
+        let issues = redmine.json_response_body_page::<_, Issue>(&endpoint, 0, 25)?;
+        for issue in issues.values {
+            assert!(issue.estimated_hours.is_some_and(|h| h >= 8.0));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_list_issues_estimated_hours_parameter() -> Result<(), Box<dyn Error>> {
+        for (filter, query) in [
+            (FloatFilter::ExactMatch(1.5), "estimated_hours=1.5"),
+            (
+                FloatFilter::Range(2.0, 4.5),
+                "estimated_hours=%3E%3C2%7C4.5",
+            ),
+            (
+                FloatFilter::GreaterThanOrEqual(8.0),
+                "estimated_hours=%3E%3D8",
+            ),
+            (FloatFilter::None, "estimated_hours=%21*"),
+        ] {
+            let endpoint = ListIssues::builder().estimated_hours(filter).build()?;
+            let mut url = url::Url::parse("https://redmine.example.com/issues.json")?;
+            endpoint.parameters().add_to_url(&mut url);
+            assert_eq!(url.query(), Some(query));
+        }
+        Ok(())
+    }
+
+    #[traced_test]
+    #[test]
+    fn test_list_issues_spent_time_filter() -> Result<(), Box<dyn Error>> {
+        let _r_issues = ISSUES_LOCK.read();
+        dotenvy::dotenv()?;
+        let redmine = crate::api::Redmine::from_env()?;
+        let endpoint = ListIssues::builder()
+            .spent_time(FloatFilter::GreaterThanOrEqual(1.0))
+            .build()?;
+        let issues = redmine.json_response_body_page::<_, Issue>(&endpoint, 0, 25)?;
+        for issue in issues.values {
+            // spent_hours is only returned with the permission to view time entries
+            if let Some(spent_hours) = issue.spent_hours {
+                assert!(spent_hours >= 1.0);
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_list_issues_spent_time_parameter() -> Result<(), Box<dyn Error>> {
+        for (filter, query) in [
+            (FloatFilter::GreaterThanOrEqual(1.0), "spent_time=%3E%3D1"),
+            (FloatFilter::LessThanOrEqual(0.5), "spent_time=%3C%3D0.5"),
+            (FloatFilter::Any, "spent_time=*"),
+        ] {
+            let endpoint = ListIssues::builder().spent_time(filter).build()?;
+            let mut url = url::Url::parse("https://redmine.example.com/issues.json")?;
+            endpoint.parameters().add_to_url(&mut url);
+            assert_eq!(url.query(), Some(query));
+        }
+        Ok(())
+    }
+
+    #[traced_test]
+    #[test]
+    fn test_list_issues_custom_field_filter() -> Result<(), Box<dyn Error>> {
+        let _r_issues = ISSUES_LOCK.read();
+        dotenvy::dotenv()?;
+        let redmine = crate::api::Redmine::from_env()?;
+        let endpoint = ListIssues::builder()
+            .custom_field_filters(vec![CustomFieldFilter {
+                id: 1,
+                value: StringFieldFilter::SubStringMatch("a".to_string()).into(),
+            }])
+            .build()?;
+        let issues = redmine.json_response_body_page::<_, Issue>(&endpoint, 0, 25)?;
+        for issue in issues.values {
+            let values = issue
+                .custom_fields
+                .unwrap_or_default()
+                .into_iter()
+                .find(|cf| cf.id == 1)
+                .and_then(|cf| cf.value)
+                .unwrap_or_default();
+            assert!(values.iter().any(|v| v.to_lowercase().contains('a')));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_list_issues_custom_field_parameter() -> Result<(), Box<dyn Error>> {
+        let endpoint = ListIssues::builder()
+            .custom_field_filters(vec![
+                CustomFieldFilter {
+                    id: 1,
+                    value: StringFieldFilter::SubStringMatch("a".to_string()).into(),
+                },
+                CustomFieldFilter {
+                    id: 2,
+                    value: StringFieldFilter::ExactMatch("b".to_string()).into(),
+                },
+                CustomFieldFilter {
+                    id: 3,
+                    value: IntegerFilter::GreaterThanOrEqual(5).into(),
+                },
+                CustomFieldFilter {
+                    id: 4,
+                    value: DateTimeFilter::WithinNextDays(7).into(),
+                },
+            ])
+            .build()?;
+        let mut url = url::Url::parse("https://redmine.example.com/issues.json")?;
+        endpoint.parameters().add_to_url(&mut url);
+        assert_eq!(
+            url.query(),
+            Some("cf_1=%7Ea&cf_2=b&cf_3=%3E%3D5&cf_4=%3E%3Ct%2B7")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_list_issues_sort_parameter() -> Result<(), Box<dyn Error>> {
+        let endpoint = ListIssues::builder()
+            .sort(vec![
+                SortByColumn::Reverse {
+                    column_name: IssueSortColumn::Priority,
+                },
+                SortByColumn::Forward {
+                    column_name: IssueSortColumn::UpdatedOn,
+                },
+                SortByColumn::Forward {
+                    column_name: IssueSortColumn::CustomField(3),
+                },
+            ])
+            .build()?;
+        let mut url = url::Url::parse("https://redmine.example.com/issues.json")?;
+        endpoint.parameters().add_to_url(&mut url);
+        assert_eq!(
+            url.query(),
+            Some("sort=priority%3Adesc%2Cupdated_on%2Ccf_3")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_watcher_typed_ids_body() -> Result<(), Box<dyn Error>> {
+        let endpoint = AddWatcher::builder()
+            .issue_id(IssueId(1))
+            .user_id(UserId(5))
+            .build()?;
+        assert_eq!(endpoint.endpoint(), "issues/1/watchers.json");
+        assert_eq!(
+            endpoint.body()?,
+            Some(("application/json", br#"{"user_id":5}"#.to_vec()))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_update_issue_multi_value_custom_field_body() -> Result<(), Box<dyn Error>> {
+        let endpoint = UpdateIssue::builder()
+            .id(1)
+            .custom_fields(vec![
+                CustomField {
+                    id: 1,
+                    name: None,
+                    value: "single".into(),
+                },
+                CustomField {
+                    id: 2,
+                    name: None,
+                    value: vec!["first", "second"].into(),
+                },
+            ])
+            .build()?;
+        assert_eq!(
+            endpoint.body()?,
+            Some((
+                "application/json",
+                br#"{"issue":{"custom_fields":[{"id":1,"name":null,"value":"single"},{"id":2,"name":null,"value":["first","second"]}]}}"#.to_vec()
+            ))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_update_issue_assign_to_body() -> Result<(), Box<dyn Error>> {
+        let endpoint = UpdateIssue::builder().id(1).assigned_to_id(5).build()?;
+        assert_eq!(
+            endpoint.body()?,
+            Some((
+                "application/json",
+                br#"{"issue":{"assigned_to_id":5}}"#.to_vec()
+            ))
+        );
+        let endpoint = UpdateIssue::builder()
+            .id(1)
+            .assigned_to_id(AssignTo::Unassign)
+            .build()?;
+        assert_eq!(
+            endpoint.body()?,
+            Some((
+                "application/json",
+                br#"{"issue":{"assigned_to_id":""}}"#.to_vec()
+            ))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_issue_done_ratio_validation() -> Result<(), Box<dyn Error>> {
+        let endpoint = UpdateIssue::builder().id(1).done_ratio(50).build()?;
+        assert_eq!(
+            endpoint.body()?,
+            Some((
+                "application/json",
+                br#"{"issue":{"done_ratio":50}}"#.to_vec()
+            ))
+        );
+        assert!(UpdateIssue::builder().id(1).done_ratio(100).build().is_ok());
+        assert!(UpdateIssue::builder()
+            .id(1)
+            .done_ratio(101)
+            .build()
+            .is_err());
+        assert!(CreateIssue::builder()
+            .project_id(1)
+            .done_ratio(150)
+            .build()
+            .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_create_issue_include_parameter() -> Result<(), Box<dyn Error>> {
+        let endpoint = CreateIssue::builder()
+            .project_id(1)
+            .watcher_user_ids(vec![5])
+            .include(vec![IssueInclude::Watchers])
+            .build()?;
+        let mut url = url::Url::parse("https://redmine.example.com/issues.json")?;
+        endpoint.parameters().add_to_url(&mut url);
+        assert_eq!(url.query(), Some("include=watchers"));
+        assert_eq!(
+            endpoint.body()?,
+            Some((
+                "application/json",
+                br#"{"issue":{"project_id":1,"watcher_user_ids":[5]}}"#.to_vec()
+            ))
+        );
+        Ok(())
+    }
+
+    #[function_name::named]
+    #[traced_test]
+    #[test]
+    fn test_create_issue_include_watchers() -> Result<(), Box<dyn Error>> {
+        let _w_issues = ISSUES_LOCK.write();
+        let name = format!("unittest_{}", function_name!());
+        with_project(&name, |redmine, project_id, _name| {
+            let current_user_endpoint = crate::api::users::GetUser::builder().build()?;
+            let crate::api::users::UserWrapper { user } = redmine
+                .json_response_body::<_, crate::api::users::UserWrapper<crate::api::users::User>>(
+                    &current_user_endpoint,
+                )?;
+            let create_endpoint = super::CreateIssue::builder()
+                .project_id(project_id)
+                .subject("include watchers test subject")
+                .watcher_user_ids(vec![user.id])
+                .include(vec![IssueInclude::Watchers])
+                .build()?;
+            let IssueWrapper { issue }: IssueWrapper<Issue> =
+                redmine.json_response_body::<_, _>(&create_endpoint)?;
+            assert_eq!(
+                issue
+                    .watchers
+                    .map(|w| w.iter().map(|w| w.id).collect::<Vec<_>>()),
+                Some(vec![user.id])
+            );
+            Ok(())
+        })?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_create_issue_dates_body() -> Result<(), Box<dyn Error>> {
+        let endpoint = CreateIssue::builder()
+            .project_id(1)
+            .start_date(time::Date::from_calendar_date(2024, time::Month::March, 1)?)
+            .due_date(time::Date::from_calendar_date(
+                2024,
+                time::Month::March,
+                31,
+            )?)
+            .build()?;
+        assert_eq!(
+            endpoint.body()?,
+            Some((
+                "application/json",
+                br#"{"issue":{"project_id":1,"start_date":"2024-03-01","due_date":"2024-03-31"}}"#
+                    .to_vec()
+            ))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_uploaded_attachment_from_path() -> Result<(), Box<dyn Error>> {
+        for (path, filename, content_type) in [
+            ("/tmp/notes.txt", "notes.txt", "text/plain"),
+            ("docs/README.md", "README.md", "text/markdown"),
+            ("report.pdf", "report.pdf", "application/pdf"),
+            ("screenshots/Screen.PNG", "Screen.PNG", "image/png"),
+            ("build/Makefile", "Makefile", "application/octet-stream"),
+        ] {
+            let attachment = UploadedAttachment::from_path("7167.ed1c", path)?;
+            assert_eq!(attachment.filename, filename);
+            assert_eq!(attachment.content_type, content_type);
+            assert_eq!(attachment.description, None);
+        }
+        let attachment =
+            UploadedAttachment::from_path("7167.ed1c", "notes.txt")?.with_description("notes");
+        assert_eq!(attachment.description.as_deref(), Some("notes"));
+        assert!(UploadedAttachment::from_path("7167.ed1c", "/").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_issue_is_private_deserialize() -> Result<(), Box<dyn Error>> {
+        let mut value = issue_json(1);
+        value["is_private"] = true.into();
+        let issue: Issue = serde_json::from_value(value)?;
+        assert_eq!(issue.is_private, Some(true));
+        Ok(())
+    }
+
+    #[test]
+    fn test_custom_field_value_deserialize() -> Result<(), Box<dyn Error>> {
+        let single: CustomField = serde_json::from_str(r#"{"id":1,"name":"Single","value":"a"}"#)?;
+        assert_eq!(single.value, CustomFieldValue::Single("a".into()));
+        let multiple: CustomField =
+            serde_json::from_str(r#"{"id":2,"name":"Multiple","value":["a","b"]}"#)?;
+        assert_eq!(multiple.value, vec!["a", "b"].into());
+        Ok(())
+    }
+
+    #[test]
+    fn test_date_filter_display() -> Result<(), Box<dyn Error>> {
+        assert_eq!(
+            DateFilter::Range(
+                time::Date::from_calendar_date(2024, time::Month::January, 1)?,
+                time::Date::from_calendar_date(2024, time::Month::January, 31)?
+            )
+            .to_string(),
+            "><2024-01-01|2024-01-31"
+        );
+        assert_eq!(DateFilter::WithinPastDays(7).to_string(), "><t-7");
+        assert_eq!(DateFilter::ThisMonth.to_string(), "m");
+        Ok(())
+    }
+
+    #[test]
+    fn test_float_filter_display() {
+        assert_eq!(FloatFilter::GreaterThanOrEqual(8.0).to_string(), ">=8");
+        assert_eq!(FloatFilter::Range(0.5, 12.25).to_string(), "><0.5|12.25");
+    }
+
+    /// this version of the test will load all pages of issues which means it
+    /// can take a while (a minute or more) so you need to use --include-ignored
+    /// or --ignored to run it
+    #[traced_test]
+    #[test]
+    #[ignore]
+    fn test_list_issues_all_pages() -> Result<(), Box<dyn Error>> {
+        let _r_issues = ISSUES_LOCK.read();
+        dotenvy::dotenv()?;
+        let redmine = crate::api::Redmine::from_env()?;
+        let endpoint = ListIssues::builder().build()?;
+        redmine.json_response_body_all_pages::<_, Issue>(&endpoint)?;
+        Ok(())
+    }
+
+    #[traced_test]
+    #[test]
+    fn test_get_issue() -> Result<(), Box<dyn Error>> {
+        let _r_issues = ISSUES_LOCK.read();
+        dotenvy::dotenv()?;
+        let redmine = crate::api::Redmine::from_env()?;
+        let endpoint = GetIssue::builder().id(40000).build()?;
+        redmine.json_response_body::<_, IssueWrapper<Issue>>(&endpoint)?;
+        Ok(())
+    }
+
+    #[function_name::named]
+    #[traced_test]
+    #[test]
+    fn test_create_issues() -> Result<(), Box<dyn Error>> {
+        let _w_issues = ISSUES_LOCK.write();
+        let name = format!("unittest_{}", function_name!());
+        with_project(&name, |redmine, project_id, _name| {
+            let endpoints = (1..=3)
+                .map(|i| {
+                    CreateIssue::builder()
+                        .project_id(project_id)
+                        .subject(format!("Bulk Test Issue {}", i))
+                        .build()
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            let issues = redmine.create_issues(&endpoints)?;
+            assert_eq!(
+                issues.iter().map(|i| i.subject.clone()).collect::<Vec<_>>(),
+                vec![
+                    Some("Bulk Test Issue 1".to_string()),
+                    Some("Bulk Test Issue 2".to_string()),
+                    Some("Bulk Test Issue 3".to_string()),
+                ]
+            );
+            Ok(())
+        })?;
+        Ok(())
+    }
+
+    #[function_name::named]
+    #[traced_test]
+    #[test]
+    fn test_create_issue() -> Result<(), Box<dyn Error>> {
+        let _w_issues = ISSUES_LOCK.write();
+        let name = format!("unittest_{}", function_name!());
+        with_project(&name, |redmine, project_id, _| {
+            let create_endpoint = super::CreateIssue::builder()
+                .project_id(project_id)
+                .subject("old test subject")
+                .build()?;
+            redmine.json_response_body::<_, IssueWrapper<Issue>>(&create_endpoint)?;
+            Ok(())
+        })?;
+        Ok(())
+    }
+
+    #[function_name::named]
+    #[traced_test]
+    #[test]
+    fn test_list_issue_watchers() -> Result<(), Box<dyn Error>> {
+        let _w_issues = ISSUES_LOCK.write();
+        let name = format!("unittest_{}", function_name!());
+        with_project(&name, |redmine, project_id, _name| {
+            let create_endpoint = super::CreateIssue::builder()
+                .project_id(project_id)
+                .subject("watcher test subject")
+                .build()?;
+            let IssueWrapper { issue }: IssueWrapper<Issue> =
+                redmine.json_response_body::<_, _>(&create_endpoint)?;
+            assert!(redmine.list_issue_watchers(issue.id)?.is_empty());
+            let current_user_endpoint = crate::api::users::GetUser::builder().build()?;
+            let crate::api::users::UserWrapper { user } = redmine
+                .json_response_body::<_, crate::api::users::UserWrapper<crate::api::users::User>>(
+                    &current_user_endpoint,
+                )?;
+            let add_endpoint = AddWatcher::builder()
+                .issue_id(issue.id)
+                .user_id(user.id)
+                .build()?;
+            redmine.ignore_response_body::<_>(&add_endpoint)?;
+            let watchers = redmine.list_issue_watchers(issue.id)?;
+            assert_eq!(
+                watchers.iter().map(|w| w.id).collect::<Vec<_>>(),
+                vec![user.id]
+            );
+            Ok(())
+        })?;
+        Ok(())
+    }
+
+    #[function_name::named]
+    #[traced_test]
+    #[test]
+    fn test_add_and_remove_issue_watchers() -> Result<(), Box<dyn Error>> {
+        let _w_issues = ISSUES_LOCK.write();
+        let name = format!("unittest_{}", function_name!());
+        with_project(&name, |redmine, project_id, _name| {
+            let create_endpoint = super::CreateIssue::builder()
+                .project_id(project_id)
+                .subject("batch watcher test subject")
+                .build()?;
+            let IssueWrapper { issue }: IssueWrapper<Issue> =
+                redmine.json_response_body::<_, _>(&create_endpoint)?;
+            let users_endpoint = crate::api::users::ListUsers::builder()
+                .status(crate::api::users::UserStatus::Active)
+                .build()?;
+            let users = redmine.json_response_body_page::<_, crate::api::users::User>(
+                &users_endpoint,
+                0,
+                3,
+            )?;
+            let mut user_ids = users.values.iter().map(|u| u.id).collect::<Vec<_>>();
+            user_ids.sort_unstable();
+            redmine.add_issue_watchers(issue.id, &user_ids)?;
+            let get_endpoint = GetIssue::builder()
+                .id(issue.id)
+                .include(vec![IssueInclude::Watchers])
+                .build()?;
+            let IssueWrapper { issue: fetched }: IssueWrapper<Issue> =
+                redmine.json_response_body::<_, _>(&get_endpoint)?;
+            let mut watcher_ids = fetched
+                .watchers
+                .unwrap_or_default()
+                .iter()
+                .map(|w| w.id)
+                .collect::<Vec<_>>();
+            watcher_ids.sort_unstable();
+            assert_eq!(watcher_ids, user_ids);
+            redmine.remove_issue_watchers(issue.id, &user_ids)?;
+            assert!(redmine.list_issue_watchers(issue.id)?.is_empty());
+            Ok(())
+        })?;
+        Ok(())
+    }
+
+    #[function_name::named]
+    #[traced_test]
+    #[test]
+    fn test_close_issue() -> Result<(), Box<dyn Error>> {
+        let _w_issues = ISSUES_LOCK.write();
+        let name = format!("unittest_{}", function_name!());
+        with_project(&name, |redmine, project_id, _name| {
+            let create_endpoint = super::CreateIssue::builder()
+                .project_id(project_id)
+                .subject("close test subject")
+                .build()?;
+            let IssueWrapper { issue }: IssueWrapper<Issue> =
+                redmine.json_response_body::<_, _>(&create_endpoint)?;
+            let get_endpoint = GetIssue::builder()
+                .id(issue.id)
+                .include(vec![IssueInclude::AllowedStatuses])
+                .build()?;
+            let IssueWrapper { issue }: IssueWrapper<Issue> =
+                redmine.json_response_body::<_, _>(&get_endpoint)?;
+            let allowed_statuses = issue.allowed_statuses.unwrap_or_default();
+            let closed_status = allowed_statuses
+                .iter()
+                .find(|s| s.is_closed == Some(true))
+                .ok_or("no closed status allowed for a new issue")?;
+            let open_status = allowed_statuses
+                .iter()
+                .find(|s| s.is_closed == Some(false))
+                .ok_or("no open status allowed for a new issue")?;
+            assert!(matches!(
+                redmine.close_issue(issue.id, open_status.id, None),
+                Err(crate::Error::IssueStatusTransitionNotAllowed { .. })
+            ));
+            redmine.close_issue(issue.id, closed_status.id, Some("closed by test"))?;
+            let IssueWrapper { issue: closed }: IssueWrapper<Issue> =
+                redmine.json_response_body::<_, _>(&get_endpoint)?;
+            assert_eq!(closed.status.id, closed_status.id);
+            Ok(())
+        })?;
+        Ok(())
+    }
+
+    #[function_name::named]
+    #[traced_test]
+    #[test]
+    fn test_assign_and_unassign_issue() -> Result<(), Box<dyn Error>> {
+        let _w_issues = ISSUES_LOCK.write();
+        let name = format!("unittest_{}", function_name!());
+        with_project(&name, |redmine, project_id, _name| {
+            let membership_endpoint =
+                crate::api::project_memberships::CreateProjectMembership::builder()
+                    .project_id_or_name(project_id.to_string())
+                    .user_id(1)
+                    .role_ids(vec![8])
+                    .build()?;
+            redmine.ignore_response_body::<_>(&membership_endpoint)?;
+            let create_endpoint = super::CreateIssue::builder()
+                .project_id(project_id)
+                .subject("assign test subject")
+                .build()?;
+            let IssueWrapper { issue }: IssueWrapper<Issue> =
+                redmine.json_response_body::<_, _>(&create_endpoint)?;
+            let get_endpoint = GetIssue::builder().id(issue.id).build()?;
+            let assign_endpoint = super::UpdateIssue::builder()
+                .id(issue.id)
+                .assigned_to_id(1)
+                .build()?;
+            redmine.ignore_response_body::<_>(&assign_endpoint)?;
+            let IssueWrapper { issue: assigned }: IssueWrapper<Issue> =
+                redmine.json_response_body::<_, _>(&get_endpoint)?;
+            assert_eq!(assigned.assigned_to.map(|a| a.id), Some(1));
+            let unassign_endpoint = super::UpdateIssue::builder()
+                .id(issue.id)
+                .assigned_to_id(AssignTo::Unassign)
+                .build()?;
+            redmine.ignore_response_body::<_>(&unassign_endpoint)?;
+            let IssueWrapper { issue: unassigned }: IssueWrapper<Issue> =
+                redmine.json_response_body::<_, _>(&get_endpoint)?;
+            assert!(unassigned.assigned_to.is_none());
+            Ok(())
+        })?;
+        Ok(())
+    }
+
+    #[function_name::named]
+    #[traced_test]
+    #[test]
+    fn test_resolve_assignees() -> Result<(), Box<dyn Error>> {
+        let _w_issues = ISSUES_LOCK.write();
+        let name = format!("unittest_{}", function_name!());
+        with_project(&name, |redmine, project_id, _name| {
+            let membership_endpoint =
+                crate::api::project_memberships::CreateProjectMembership::builder()
+                    .project_id_or_name(project_id.to_string())
+                    .user_id(1)
+                    .role_ids(vec![8])
+                    .build()?;
+            redmine.ignore_response_body::<_>(&membership_endpoint)?;
+            let mut issues = Vec::new();
+            for assigned_to in [Some(1), None, Some(1)] {
+                let mut create_endpoint = super::CreateIssue::builder();
+                create_endpoint
+                    .project_id(project_id)
+                    .subject("resolve assignees test subject");
+                if let Some(assigned_to) = assigned_to {
+                    create_endpoint.assigned_to_id(assigned_to);
+                }
+                let IssueWrapper { issue }: IssueWrapper<Issue> =
+                    redmine.json_response_body::<_, _>(&create_endpoint.build()?)?;
+                issues.push(issue);
+            }
+            let users = redmine.resolve_assignees(&issues)?;
+            assert_eq!(users.len(), 1);
+            assert_eq!(users.get(&1).map(|u| u.id), Some(1));
+            Ok(())
+        })?;
+        Ok(())
+    }
+
+    #[function_name::named]
+    #[traced_test]
+    #[test]
+    fn test_list_issue_journals() -> Result<(), Box<dyn Error>> {
+        let _w_issues = ISSUES_LOCK.write();
+        let name = format!("unittest_{}", function_name!());
+        with_project(&name, |redmine, project_id, _name| {
+            let create_endpoint = super::CreateIssue::builder()
+                .project_id(project_id)
+                .subject("journals test subject")
+                .build()?;
+            let IssueWrapper { issue }: IssueWrapper<Issue> =
+                redmine.json_response_body::<_, _>(&create_endpoint)?;
+            assert!(redmine.list_issue_journals(issue.id)?.is_empty());
+            let update_endpoint = super::UpdateIssue::builder()
+                .id(issue.id)
+                .notes("journals test note".into())
+                .build()?;
+            redmine.ignore_response_body::<_>(&update_endpoint)?;
+            let journals = redmine.list_issue_journals(issue.id)?;
+            assert_eq!(
+                journals
+                    .iter()
+                    .map(|j| j.notes.as_deref())
+                    .collect::<Vec<_>>(),
+                vec![Some("journals test note")]
+            );
+            Ok(())
+        })?;
+        Ok(())
+    }
+
+    #[function_name::named]
+    #[traced_test]
+    #[test]
+    fn test_add_issue_note() -> Result<(), Box<dyn Error>> {
+        let _w_issues = ISSUES_LOCK.write();
+        let name = format!("unittest_{}", function_name!());
+        with_project(&name, |redmine, project_id, _name| {
+            let create_endpoint = super::CreateIssue::builder()
+                .project_id(project_id)
+                .subject("note test subject")
+                .build()?;
+            let IssueWrapper { issue }: IssueWrapper<Issue> =
+                redmine.json_response_body::<_, _>(&create_endpoint)?;
+            redmine.add_issue_note(issue.id, "public test note", false)?;
+            redmine.add_issue_note(issue.id, "private test note", true)?;
+            let journals = redmine.list_issue_journals(issue.id)?;
+            assert_eq!(
+                journals
+                    .iter()
+                    .map(|j| (j.notes.as_deref(), j.private_notes))
+                    .collect::<Vec<_>>(),
+                vec![
+                    (Some("public test note"), false),
+                    (Some("private test note"), true)
+                ]
+            );
+            assert!(journals.iter().all(|j| j.details.is_empty()));
+            Ok(())
+        })?;
+        Ok(())
+    }
+
+    #[function_name::named]
+    #[traced_test]
+    #[test]
+    fn test_update_issue_done_ratio() -> Result<(), Box<dyn Error>> {
+        let _w_issues = ISSUES_LOCK.write();
+        let name = format!("unittest_{}", function_name!());
+        with_project(&name, |redmine, project_id, _name| {
+            let create_endpoint = super::CreateIssue::builder()
+                .project_id(project_id)
+                .subject("done ratio test subject")
+                .build()?;
+            let IssueWrapper { issue }: IssueWrapper<Issue> =
+                redmine.json_response_body::<_, _>(&create_endpoint)?;
+            let update_endpoint = super::UpdateIssue::builder()
+                .id(issue.id)
+                .done_ratio(50)
+                .build()?;
+            redmine.ignore_response_body::<_>(&update_endpoint)?;
+            let get_endpoint = GetIssue::builder().id(issue.id).build()?;
+            let IssueWrapper { issue: updated }: IssueWrapper<Issue> =
+                redmine.json_response_body::<_, _>(&get_endpoint)?;
+            assert_eq!(updated.done_ratio, 50);
+            Ok(())
+        })?;
+        Ok(())
+    }
+
+    #[function_name::named]
+    #[traced_test]
+    #[test]
+    fn test_create_issue_with_dates() -> Result<(), Box<dyn Error>> {
+        let _w_issues = ISSUES_LOCK.write();
+        let name = format!("unittest_{}", function_name!());
+        with_project(&name, |redmine, project_id, _name| {
+            let start_date = time::Date::from_calendar_date(2024, time::Month::March, 1)?;
+            let due_date = time::Date::from_calendar_date(2024, time::Month::March, 31)?;
+            let create_endpoint = super::CreateIssue::builder()
+                .project_id(project_id)
+                .subject("dates test subject")
+                .start_date(start_date)
+                .due_date(due_date)
+                .build()?;
+            let IssueWrapper { issue }: IssueWrapper<Issue> =
+                redmine.json_response_body::<_, _>(&create_endpoint)?;
+            let get_endpoint = GetIssue::builder().id(issue.id).build()?;
+            let IssueWrapper { issue: fetched }: IssueWrapper<Issue> =
+                redmine.json_response_body::<_, _>(&get_endpoint)?;
+            assert_eq!(fetched.start_date, Some(start_date));
+            assert_eq!(fetched.due_date, Some(due_date));
+            Ok(())
+        })?;
+        Ok(())
+    }
+
+    #[function_name::named]
+    #[traced_test]
+    #[test]
+    fn test_update_issue() -> Result<(), Box<dyn Error>> {
+        let _w_issues = ISSUES_LOCK.write();
+        let name = format!("unittest_{}", function_name!());
+        with_project(&name, |redmine, project_id, _name| {
+            let create_endpoint = super::CreateIssue::builder()
+                .project_id(project_id)
+                .subject("old test subject")
+                .build()?;
+            let IssueWrapper { issue }: IssueWrapper<Issue> =
+                redmine.json_response_body::<_, _>(&create_endpoint)?;
+            let update_endpoint = super::UpdateIssue::builder()
+                .id(issue.id)
+                .subject("New test subject")
+                .build()?;
+            redmine.ignore_response_body::<_>(&update_endpoint)?;
+            Ok(())
+        })?;
+        Ok(())
+    }
+
+    #[function_name::named]
+    #[traced_test]
+    #[test]
+    fn test_delete_issue() -> Result<(), Box<dyn Error>> {
+        let _w_issues = ISSUES_LOCK.write();
+        let name = format!("unittest_{}", function_name!());
+        with_project(&name, |redmine, project_id, _name| {
+            let create_endpoint = super::CreateIssue::builder()
+                .project_id(project_id)
+                .subject("test subject")
+                .build()?;
+            let IssueWrapper { issue }: IssueWrapper<Issue> =
+                redmine.json_response_body::<_, _>(&create_endpoint)?;
+            let delete_endpoint = super::DeleteIssue::builder().id(issue.id).build()?;
+            redmine.ignore_response_body::<_>(&delete_endpoint)?;
+            Ok(())
+        })?;
+        Ok(())
+    }
+
+    /// this tests if any of the results contain a field we are not deserializing
+    ///
+    /// this will only catch fields we missed if they are part of the response but
+    /// it is better than nothing
+    #[traced_test]
+    #[test]
+    fn test_completeness_issue_type() -> Result<(), Box<dyn Error>> {
+        let _r_issues = ISSUES_LOCK.read();
+        dotenvy::dotenv()?;
+        let redmine = crate::api::Redmine::from_env()?;
+        let endpoint = ListIssues::builder()
+            .include(vec![
+                IssueListInclude::Attachments,
+                IssueListInclude::Relations,
+            ])
+            .build()?;
+        let IssuesWrapper { issues: values } =
+            redmine.json_response_body::<_, IssuesWrapper<serde_json::Value>>(&endpoint)?;
+        for value in values {
+            let o: Issue = serde_json::from_value(value.clone())?;
+            let reserialized = serde_json::to_value(o)?;
+            assert_eq!(value, reserialized);
+        }
+        Ok(())
+    }
+
+    /// this tests if any of the results contain a field we are not deserializing
+    ///
+    /// this will only catch fields we missed if they are part of the response but
+    /// it is better than nothing
+    ///
+    /// this version of the test will load all pages of issues which means it
+    /// can take a while (a minute or more) so you need to use --include-ignored
+    /// or --ignored to run it
+    #[traced_test]
+    #[test]
+    #[ignore]
+    fn test_completeness_issue_type_all_pages() -> Result<(), Box<dyn Error>> {
+        let _r_issues = ISSUES_LOCK.read();
+        dotenvy::dotenv()?;
+        let redmine = crate::api::Redmine::from_env()?;
+        let endpoint = ListIssues::builder()
+            .include(vec![
+                IssueListInclude::Attachments,
+                IssueListInclude::Relations,
+            ])
+            .build()?;
+        let values = redmine.json_response_body_all_pages::<_, serde_json::Value>(&endpoint)?;
+        for value in values {
+            let o: Issue = serde_json::from_value(value.clone())?;
+            let reserialized = serde_json::to_value(o)?;
+            assert_eq!(value, reserialized);
+        }
+        Ok(())
+    }
+
+    /// this tests if any of the results contain a field we are not deserializing
+    ///
+    /// this will only catch fields we missed if they are part of the response but
+    /// it is better than nothing
+    ///
+    /// this version of the test will load all pages of issues and the individual
+    /// issues for each via GetIssue which means it
+    /// can take a while (about 400 seconds) so you need to use --include-ignored
+    /// or --ignored to run it
+    #[traced_test]
+    #[test]
+    #[ignore]
+    fn test_completeness_issue_type_all_pages_all_issue_details() -> Result<(), Box<dyn Error>> {
         let _r_issues = ISSUES_LOCK.read();
         dotenvy::dotenv()?;
         let redmine = crate::api::Redmine::from_env()?;
@@ -1406,6 +3969,7 @@ pub(crate) mod test {
                     IssueInclude::Relations,
                     IssueInclude::Journals,
                     IssueInclude::Watchers,
+                    IssueInclude::AllowedStatuses,
                 ])
                 .build()?;
             let IssueWrapper { issue: mut value } =
@@ -1425,4 +3989,101 @@ pub(crate) mod test {
         }
         Ok(())
     }
+
+    #[test]
+    fn test_check_status_transition() {
+        let allowed = vec![
+            IssueStatusEssentials {
+                id: 1,
+                is_closed: Some(false),
+                name: "New".to_string(),
+            },
+            IssueStatusEssentials {
+                id: 5,
+                is_closed: Some(true),
+                name: "Closed".to_string(),
+            },
+            IssueStatusEssentials {
+                id: 6,
+                is_closed: None,
+                name: "Rejected".to_string(),
+            },
+        ];
+        assert!(check_status_transition(IssueId(3), 5, &allowed, None).is_ok());
+        assert!(check_status_transition(IssueId(3), 5, &allowed, Some(true)).is_ok());
+        assert!(check_status_transition(IssueId(3), 6, &allowed, Some(true)).is_ok());
+        assert!(check_status_transition(IssueId(3), 1, &allowed, Some(false)).is_ok());
+        assert!(matches!(
+            check_status_transition(IssueId(3), 1, &allowed, Some(true)),
+            Err(crate::Error::IssueStatusTransitionNotAllowed {
+                issue_id: 3,
+                status_id: 1
+            })
+        ));
+        assert!(check_status_transition(IssueId(3), 2, &allowed, None).is_err());
+    }
+
+    #[test]
+    fn test_client_helper_update_bodies() -> Result<(), Box<dyn Error>> {
+        let endpoint = UpdateIssue {
+            notes: Some("looked into it".into()),
+            private_notes: Some(true),
+            ..UpdateIssue::unchanged(IssueId(3))
+        };
+        assert_eq!(endpoint.method(), Method::PUT);
+        assert_eq!(endpoint.endpoint(), "issues/3.json");
+        assert_eq!(
+            endpoint.body()?,
+            Some((
+                "application/json",
+                br#"{"issue":{"notes":"looked into it","private_notes":true}}"#.to_vec()
+            ))
+        );
+        let endpoint = UpdateIssue {
+            status_id: Some(5),
+            notes: Some("done".into()),
+            ..UpdateIssue::unchanged(IssueId(3))
+        };
+        assert_eq!(
+            endpoint.body()?,
+            Some((
+                "application/json",
+                br#"{"issue":{"status_id":5,"notes":"done"}}"#.to_vec()
+            ))
+        );
+        let endpoint = UpdateIssue {
+            uploads: Some(vec![UploadedAttachment {
+                token: "7167.ed1c".into(),
+                filename: "notes.txt".into(),
+                description: None,
+                content_type: "text/plain".into(),
+            }]),
+            ..UpdateIssue::unchanged(IssueId(3))
+        };
+        assert_eq!(
+            endpoint.body()?,
+            Some((
+                "application/json",
+                br#"{"issue":{"uploads":[{"token":"7167.ed1c","filename":"notes.txt","content_type":"text/plain"}]}}"#
+                    .to_vec()
+            ))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_watcher_endpoints() -> Result<(), Box<dyn Error>> {
+        let add = AddWatcher::builder().issue_id(3).user_id(5).build()?;
+        assert_eq!(add.method(), Method::POST);
+        assert_eq!(add.endpoint(), "issues/3/watchers.json");
+        assert_eq!(
+            add.body()?,
+            Some(("application/json", br#"{"user_id":5}"#.to_vec()))
+        );
+        let remove = RemoveWatcher::builder().issue_id(3).user_id(5).build()?;
+        assert_eq!(remove.method(), Method::DELETE);
+        assert_eq!(remove.endpoint(), "issues/3/watchers/5.json");
+        assert_eq!(remove.body()?, None);
+        Ok(())
+    }
 }
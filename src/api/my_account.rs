@@ -3,12 +3,16 @@
 //! [Redmine Documentation](https://www.redmine.org/projects/redmine/wiki/Rest_MyAccount)
 //!
 //! - [x] my account endpoint
+//! - [x] update my account endpoint
 
 use derive_builder::Builder;
 use reqwest::Method;
+use serde::Serialize;
 use std::borrow::Cow;
 
 use crate::api::custom_fields::CustomFieldEssentialsWithValue;
+use crate::api::issues::CustomField;
+use crate::api::users::{MailNotificationOptions, UserWrapper};
 use crate::api::{Endpoint, ReturnsJsonResponse};
 
 /// a type for my account to use as an API return type
@@ -74,10 +78,61 @@ impl Endpoint for GetMyAccount {
     }
 }
 
+/// The endpoint to update the current user's my account settings/data
+///
+/// unlike [UpdateUser](crate::api::users::UpdateUser) this does not require
+/// admin permissions
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Clone, Builder, Serialize)]
+#[builder(setter(strip_option))]
+pub struct UpdateMyAccount<'a> {
+    /// The user's firstname
+    #[builder(default, setter(into))]
+    firstname: Option<Cow<'a, str>>,
+    /// The user's lastname
+    #[builder(default, setter(into))]
+    lastname: Option<Cow<'a, str>>,
+    /// The users primary email address
+    #[builder(default, setter(into))]
+    mail: Option<Cow<'a, str>>,
+    /// what kind of mail notifications should be sent to the user
+    #[builder(default)]
+    mail_notification: Option<MailNotificationOptions>,
+    /// custom field values
+    #[builder(default)]
+    custom_fields: Option<Vec<CustomField<'a>>>,
+}
+
+impl<'a> UpdateMyAccount<'a> {
+    /// Create a builder for the endpoint.
+    #[must_use]
+    pub fn builder() -> UpdateMyAccountBuilder<'a> {
+        UpdateMyAccountBuilder::default()
+    }
+}
+
+impl Endpoint for UpdateMyAccount<'_> {
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "my/account.json".into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, crate::Error> {
+        Ok(Some((
+            "application/json",
+            serde_json::to_vec(&UserWrapper::<UpdateMyAccount> {
+                user: (*self).to_owned(),
+            })?,
+        )))
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::api::users::UserWrapper;
     use pretty_assertions::assert_eq;
     use std::error::Error;
     use tracing_test::traced_test;
@@ -92,6 +147,37 @@ mod test {
         Ok(())
     }
 
+    #[traced_test]
+    #[test]
+    fn test_update_my_account() -> Result<(), Box<dyn Error>> {
+        dotenvy::dotenv()?;
+        let redmine = crate::api::Redmine::from_env()?;
+        let get_endpoint = GetMyAccount::builder().build()?;
+        let UserWrapper { user: account } =
+            redmine.json_response_body::<_, UserWrapper<MyAccount>>(&get_endpoint)?;
+        let _fb = finally_block::finally(|| {
+            let restore_endpoint = UpdateMyAccount::builder()
+                .firstname(account.firstname.as_str())
+                .build()
+                .unwrap_or_else(|_| panic!("Building restore endpoint for my account failed"));
+            redmine
+                .ignore_response_body::<_>(&restore_endpoint)
+                .unwrap_or_else(|_| panic!("Restoring my account failed"));
+        });
+        let firstname = if account.firstname == "Unittest" {
+            "Unittest Changed"
+        } else {
+            "Unittest"
+        };
+        let update_endpoint = UpdateMyAccount::builder().firstname(firstname).build()?;
+        redmine.ignore_response_body::<_>(&update_endpoint)?;
+        let UserWrapper { user: updated } =
+            redmine.json_response_body::<_, UserWrapper<MyAccount>>(&get_endpoint)?;
+        assert_eq!(firstname, updated.firstname);
+        assert_eq!(account.lastname, updated.lastname);
+        Ok(())
+    }
+
     /// this tests if any of the results contain a field we are not deserializing
     ///
     /// this will only catch fields we missed if they are part of the response but
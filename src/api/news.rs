@@ -4,9 +4,12 @@
 //!
 //! - [x] all news endpoint
 //! - [x] project news endpoint
+//! - [x] create project news endpoint
+//! - [x] delete news endpoint
 //!
 use derive_builder::Builder;
 use reqwest::Method;
+use serde::Serialize;
 use std::borrow::Cow;
 
 use crate::api::projects::ProjectEssentials;
@@ -101,6 +104,87 @@ impl Endpoint for ListProjectNews<'_> {
     }
 }
 
+/// The endpoint to create news in a Redmine project
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Clone, Builder, Serialize)]
+#[builder(setter(strip_option))]
+pub struct CreateNews<'a> {
+    /// The project Id or the project name as it appears in the URL to add the news to
+    #[builder(setter(into))]
+    #[serde(skip_serializing)]
+    project_id_or_name: Cow<'a, str>,
+    /// the title of the news
+    #[builder(setter(into))]
+    title: Cow<'a, str>,
+    /// the summary of the news
+    #[builder(default, setter(into))]
+    summary: Option<Cow<'a, str>>,
+    /// the description of the news (body)
+    #[builder(setter(into))]
+    description: Cow<'a, str>,
+    /// see [idempotency_key_header](crate::api::idempotency_key_header)
+    #[builder(default)]
+    #[serde(skip_serializing)]
+    idempotency_key: Option<reqwest::header::HeaderValue>,
+}
+
+impl<'a> CreateNews<'a> {
+    /// Create a builder for the endpoint.
+    #[must_use]
+    pub fn builder() -> CreateNewsBuilder<'a> {
+        CreateNewsBuilder::default()
+    }
+}
+
+impl Endpoint for CreateNews<'_> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/news.json", self.project_id_or_name).into()
+    }
+
+    fn headers(&self) -> reqwest::header::HeaderMap {
+        crate::api::idempotency_key_header(self.idempotency_key.as_ref())
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, crate::Error> {
+        Ok(Some((
+            "application/json",
+            serde_json::to_vec(&SingleNewsWrapper::<CreateNews> {
+                news: (*self).to_owned(),
+            })?,
+        )))
+    }
+}
+
+/// The endpoint to delete a Redmine news item
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(strip_option))]
+pub struct DeleteNews {
+    /// The id of the news to delete
+    id: u64,
+}
+
+impl DeleteNews {
+    /// Create a builder for the endpoint.
+    #[must_use]
+    pub fn builder() -> DeleteNewsBuilder {
+        DeleteNewsBuilder::default()
+    }
+}
+
+impl Endpoint for DeleteNews {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("news/{}.json", &self.id).into()
+    }
+}
+
 /// helper struct for outer layers with a news field holding the inner data
 #[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub struct NewsWrapper<T> {
@@ -108,9 +192,18 @@ pub struct NewsWrapper<T> {
     pub news: Vec<T>,
 }
 
+/// A lot of APIs in Redmine wrap their data in an extra layer, this is a
+/// helper struct for outer layers with a news field holding a single news item
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct SingleNewsWrapper<T> {
+    /// to parse JSON with news key
+    pub news: T,
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::api::test_helpers::with_project;
     use pretty_assertions::assert_eq;
     use std::error::Error;
     use tracing_test::traced_test;
@@ -145,6 +238,37 @@ mod test {
         Ok(())
     }
 
+    #[function_name::named]
+    #[traced_test]
+    #[test]
+    fn test_create_and_delete_news() -> Result<(), Box<dyn Error>> {
+        let name = format!("unittest_{}", function_name!());
+        with_project(&name, |redmine, _, name| {
+            let create_endpoint = CreateNews::builder()
+                .project_id_or_name(name)
+                .title("Test News")
+                .summary("Test Summary")
+                .description("Created as part of unit test for redmine-api")
+                .build()?;
+            redmine.ignore_response_body::<_>(&create_endpoint)?;
+            let list_endpoint = ListProjectNews::builder()
+                .project_id_or_name(name)
+                .build()?;
+            let NewsWrapper { news } =
+                redmine.json_response_body::<_, NewsWrapper<News>>(&list_endpoint)?;
+            assert_eq!(news.len(), 1);
+            assert_eq!(news[0].title, "Test News");
+            assert_eq!(news[0].summary, "Test Summary");
+            let delete_endpoint = DeleteNews::builder().id(news[0].id).build()?;
+            redmine.ignore_response_body::<_>(&delete_endpoint)?;
+            let NewsWrapper { news } =
+                redmine.json_response_body::<_, NewsWrapper<News>>(&list_endpoint)?;
+            assert!(news.is_empty());
+            Ok(())
+        })?;
+        Ok(())
+    }
+
     /// this tests if any of the results contain a field we are not deserializing
     ///
     /// this will only catch fields we missed if they are part of the response but
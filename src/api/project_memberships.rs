@@ -16,7 +16,7 @@ use crate::api::groups::GroupEssentials;
 use crate::api::projects::ProjectEssentials;
 use crate::api::roles::RoleEssentials;
 use crate::api::users::UserEssentials;
-use crate::api::{Endpoint, Pageable, ReturnsJsonResponse};
+use crate::api::{Endpoint, Pageable, ProjectIdentifier, ReturnsJsonResponse};
 use serde::Serialize;
 
 /// a minimal type for project memberships to be used in lists of memberships
@@ -52,16 +52,29 @@ pub struct ProjectMembership {
     pub id: u64,
     /// the project
     pub project: ProjectEssentials,
-    /// the user (project member), optional because alternatively we could have a group
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub user: Option<UserEssentials>,
-    /// the group (project member), optional because alternatively we could have a user
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub group: Option<GroupEssentials>,
+    /// the user or group which is a member of the project
+    #[serde(flatten)]
+    pub principal: MembershipPrincipal,
     /// the roles the user or group has in the project
+    ///
+    /// roles with inherited set to true come from a membership in a parent
+    /// project (or from a group the user is in) and can not be removed from
+    /// this membership directly, Redmine does not tell us which membership
+    /// they originate from
     pub roles: Vec<RoleEssentials>,
 }
 
+/// the principal of a project membership, Redmine memberships are either
+/// for a single user or for a group, never both
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MembershipPrincipal {
+    /// the user (project member)
+    User(UserEssentials),
+    /// the group (project member)
+    Group(GroupEssentials),
+}
+
 /// The endpoint for all memberships in a Redmine project
 #[derive(Debug, Clone, Builder)]
 #[builder(setter(strip_option))]
@@ -84,6 +97,14 @@ impl<'a> ListProjectMemberships<'a> {
     pub fn builder() -> ListProjectMembershipsBuilder<'a> {
         ListProjectMembershipsBuilder::default()
     }
+
+    /// the endpoint for the memberships of the project, for the client
+    /// helpers which already have a [ProjectIdentifier]
+    pub(crate) fn for_project(project_id_or_name: &ProjectIdentifier<'_>) -> Self {
+        Self {
+            project_id_or_name: project_id_or_name.to_string().into(),
+        }
+    }
 }
 
 impl Endpoint for ListProjectMemberships<'_> {
@@ -137,6 +158,10 @@ pub struct CreateProjectMembership<'a> {
     user_id: u64,
     /// roles for the user to add to the project
     role_ids: Vec<u64>,
+    /// see [idempotency_key_header](crate::api::idempotency_key_header)
+    #[builder(default)]
+    #[serde(skip_serializing)]
+    idempotency_key: Option<reqwest::header::HeaderValue>,
 }
 
 impl ReturnsJsonResponse for CreateProjectMembership<'_> {}
@@ -158,6 +183,10 @@ impl Endpoint for CreateProjectMembership<'_> {
         format!("projects/{}/memberships.json", self.project_id_or_name).into()
     }
 
+    fn headers(&self) -> reqwest::header::HeaderMap {
+        crate::api::idempotency_key_header(self.idempotency_key.as_ref())
+    }
+
     fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, crate::Error> {
         Ok(Some((
             "application/json",
@@ -330,6 +359,37 @@ mod test {
         Ok(())
     }
 
+    #[function_name::named]
+    #[traced_test]
+    #[test]
+    fn test_get_project_with_members() -> Result<(), Box<dyn Error>> {
+        let _w_project_memberships = PROJECT_MEMBERSHIP_LOCK.write();
+        let name = format!("unittest_{}", function_name!());
+        with_project(&name, |redmine, project_id, name| {
+            let create_endpoint = super::CreateProjectMembership::builder()
+                .project_id_or_name(project_id.to_string())
+                .user_id(1)
+                .role_ids(vec![8])
+                .build()?;
+            redmine
+                .json_response_body::<_, MembershipWrapper<ProjectMembership>>(&create_endpoint)?;
+            let (project, memberships) = redmine.get_project_with_members(name)?;
+            assert_eq!(project.id, project_id);
+            assert_eq!(
+                memberships
+                    .iter()
+                    .filter_map(|m| match &m.principal {
+                        MembershipPrincipal::User(u) => Some(u.id),
+                        MembershipPrincipal::Group(_) => None,
+                    })
+                    .collect::<Vec<_>>(),
+                vec![1]
+            );
+            Ok(())
+        })?;
+        Ok(())
+    }
+
     #[function_name::named]
     #[traced_test]
     #[test]
@@ -354,6 +414,108 @@ mod test {
         Ok(())
     }
 
+    #[function_name::named]
+    #[traced_test]
+    #[test]
+    fn test_delete_project_membership() -> Result<(), Box<dyn Error>> {
+        let _w_project_memberships = PROJECT_MEMBERSHIP_LOCK.write();
+        let name = format!("unittest_{}", function_name!());
+        with_project(&name, |redmine, project_id, _name| {
+            let create_endpoint = super::CreateProjectMembership::builder()
+                .project_id_or_name(project_id.to_string())
+                .user_id(1)
+                .role_ids(vec![8])
+                .build()?;
+            let MembershipWrapper { membership } = redmine
+                .json_response_body::<_, MembershipWrapper<ProjectMembership>>(&create_endpoint)?;
+            let delete_endpoint = super::DeleteProjectMembership::builder()
+                .id(membership.id)
+                .build()?;
+            redmine.ignore_response_body::<_>(&delete_endpoint)?;
+            let list_endpoint = ListProjectMemberships::builder()
+                .project_id_or_name(project_id.to_string())
+                .build()?;
+            let memberships =
+                redmine.json_response_body_all_pages::<_, ProjectMembership>(&list_endpoint)?;
+            assert!(memberships.iter().all(|m| m.id != membership.id));
+            Ok(())
+        })?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_project_membership_principal() -> Result<(), Box<dyn Error>> {
+        let value = serde_json::json!({
+            "id": 5,
+            "project": { "id": 2, "name": "Child" },
+            "group": { "id": 7, "name": "Developers" },
+            "roles": [{ "id": 3, "name": "Developer", "inherited": true }]
+        });
+        let membership: ProjectMembership = serde_json::from_value(value.clone())?;
+        assert_eq!(
+            membership.principal,
+            MembershipPrincipal::Group(crate::api::groups::GroupEssentials {
+                id: 7,
+                name: "Developers".to_string(),
+            })
+        );
+        assert_eq!(membership.roles[0].inherited, Some(true));
+        assert_eq!(serde_json::to_value(membership)?, value);
+        let no_principal = serde_json::json!({
+            "id": 5,
+            "project": { "id": 2, "name": "Child" },
+            "roles": []
+        });
+        assert!(serde_json::from_value::<ProjectMembership>(no_principal).is_err());
+        Ok(())
+    }
+
+    /// this tests if any of the results contain a field we are not deserializing
+    ///
+    /// this will only catch fields we missed if they are part of the response but
+    /// it is better than nothing
+    ///
+    /// this version uses a subproject inheriting the members of its parent
+    /// so the response contains inherited roles
+    #[function_name::named]
+    #[traced_test]
+    #[test]
+    fn test_completeness_project_membership_type_inherited() -> Result<(), Box<dyn Error>> {
+        let _w_project_memberships = PROJECT_MEMBERSHIP_LOCK.write();
+        let name = format!("unittest_{}", function_name!());
+        with_project(&name, |redmine, project_id, name| {
+            let create_endpoint = super::CreateProjectMembership::builder()
+                .project_id_or_name(project_id.to_string())
+                .user_id(1)
+                .role_ids(vec![8])
+                .build()?;
+            redmine
+                .json_response_body::<_, MembershipWrapper<ProjectMembership>>(&create_endpoint)?;
+            let child_name = format!("{}_child", name);
+            let create_child_endpoint = crate::api::projects::CreateProject::builder()
+                .name(format!("Unittest redmine-api {}", child_name))
+                .identifier(child_name.as_str())
+                .parent_id(project_id)
+                .inherit_members(true)
+                .build()?;
+            redmine.ignore_response_body::<_>(&create_child_endpoint)?;
+            let endpoint = ListProjectMemberships::builder()
+                .project_id_or_name(child_name.as_str())
+                .build()?;
+            let values = redmine.json_response_body_all_pages::<_, serde_json::Value>(&endpoint)?;
+            let mut found_inherited = false;
+            for value in values {
+                let o: ProjectMembership = serde_json::from_value(value.clone())?;
+                found_inherited |= o.roles.iter().any(|r| r.inherited == Some(true));
+                let reserialized = serde_json::to_value(o)?;
+                assert_eq!(value, reserialized);
+            }
+            assert!(found_inherited);
+            Ok(())
+        })?;
+        Ok(())
+    }
+
     /// this tests if any of the results contain a field we are not deserializing
     ///
     /// this will only catch fields we missed if they are part of the response but
@@ -3,6 +3,10 @@
 //! [`Redmine Documentation`](https://www.redmine.org/projects/redmine/wiki/Rest_Projects)
 //!
 //! - [x] all projects endpoint
+//!   - [x] status filter
+//!   - [x] name filter
+//!   - [x] created_on and updated_on filters
+//!   - [x] sort
 //! - [x] specific project endpoint
 //! - [x] create project endpoint
 //! - [x] update project endpoint
@@ -14,12 +18,16 @@ use derive_builder::Builder;
 use reqwest::Method;
 use std::borrow::Cow;
 
+use crate::api::custom_fields::{CustomFieldEssentials, CustomFieldEssentialsWithValue};
 use crate::api::enumerations::TimeEntryActivityEssentials;
 use crate::api::issue_categories::IssueCategoryEssentials;
-use crate::api::issues::AssigneeEssentials;
+use crate::api::issues::{AssigneeEssentials, DateTimeFilterPast, SortByColumn};
+use crate::api::project_memberships::{ListProjectMemberships, ProjectMembership};
 use crate::api::trackers::TrackerEssentials;
 use crate::api::versions::VersionEssentials;
-use crate::api::{Endpoint, Pageable, QueryParams, ReturnsJsonResponse};
+use crate::api::{
+    Endpoint, Pageable, ProjectIdentifier, QueryParams, Redmine, RedmineAsync, ReturnsJsonResponse,
+};
 use serde::Serialize;
 use std::collections::HashMap;
 
@@ -102,10 +110,15 @@ pub struct Project {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub issue_custom_field_id: Option<Vec<u64>>,
     /// values for custom fields
+    ///
+    /// this is only used when writing, Redmine returns them in custom_fields
     #[serde(skip_serializing_if = "Option::is_none")]
     pub custom_field_values: Option<HashMap<u64, String>>,
-    /// archived or not?
-    pub status: u64,
+    /// custom fields with values as returned by Redmine
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custom_fields: Option<Vec<CustomFieldEssentialsWithValue>>,
+    /// active, closed or archived
+    pub status: ProjectStatus,
     /// The time when this project was created
     #[serde(
         serialize_with = "crate::api::serialize_rfc3339",
@@ -130,30 +143,125 @@ pub struct Project {
     /// trackers in this project (only with include parameter)
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub trackers: Option<Vec<TrackerEssentials>>,
+    /// issue custom fields enabled in this project (only with include parameter)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_custom_fields: Option<Vec<CustomFieldEssentials>>,
 }
 
-/// The types of associated data which can be fetched along with a project
+/// the status of a project, serialized as the numeric code Redmine uses
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+#[serde(from = "u64", into = "u64")]
+pub enum ProjectStatus {
+    /// the project is active (1)
+    Active,
+    /// the project is closed, it is read-only but still visible (5)
+    Closed,
+    /// the project is archived, it is only visible to administrators (9)
+    Archived,
+    /// the project is scheduled for deletion (10)
+    ScheduledForDeletion,
+    /// any other status code, e.g. from plugins or newer Redmine versions
+    Other(u64),
+}
+
+impl From<u64> for ProjectStatus {
+    fn from(v: u64) -> Self {
+        match v {
+            1 => ProjectStatus::Active,
+            5 => ProjectStatus::Closed,
+            9 => ProjectStatus::Archived,
+            10 => ProjectStatus::ScheduledForDeletion,
+            v => ProjectStatus::Other(v),
+        }
+    }
+}
+
+impl From<ProjectStatus> for u64 {
+    fn from(v: ProjectStatus) -> Self {
+        match v {
+            ProjectStatus::Active => 1,
+            ProjectStatus::Closed => 5,
+            ProjectStatus::Archived => 9,
+            ProjectStatus::ScheduledForDeletion => 10,
+            ProjectStatus::Other(v) => v,
+        }
+    }
+}
+
+/// ways to filter for project status
 #[derive(Debug, Clone)]
-pub enum ProjectsInclude {
-    /// Trackers enabled in the project
-    Trackers,
-    /// Issue categories in the project
-    IssueCategories,
-    /// Redmine Modules enabled in the project
-    EnabledModules,
+pub enum ProjectStatusFilter {
+    /// match active projects
+    Active,
+    /// match closed projects
+    Closed,
+    /// match archived projects
+    Archived,
+    /// match projects in any status
+    Any,
 }
 
-impl std::fmt::Display for ProjectsInclude {
+impl std::fmt::Display for ProjectStatusFilter {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            Self::Trackers => {
-                write!(f, "trackers")
+            ProjectStatusFilter::Active => {
+                write!(f, "1")
             }
-            Self::IssueCategories => {
-                write!(f, "issue_categories")
+            ProjectStatusFilter::Closed => {
+                write!(f, "5")
             }
-            Self::EnabledModules => {
-                write!(f, "enabled_modules")
+            ProjectStatusFilter::Archived => {
+                write!(f, "9")
+            }
+            ProjectStatusFilter::Any => {
+                write!(f, "*")
+            }
+        }
+    }
+}
+
+/// The columns projects can be sorted by
+#[derive(Debug, Clone)]
+pub enum ProjectSortColumn {
+    /// project name
+    Name,
+    /// project identifier
+    Identifier,
+    /// project status
+    Status,
+    /// public flag
+    IsPublic,
+    /// creation time
+    CreatedOn,
+    /// a custom field by id
+    CustomField(u64),
+    /// any other column name Redmine understands
+    Custom(String),
+}
+
+impl std::fmt::Display for ProjectSortColumn {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Name => {
+                write!(f, "name")
+            }
+            Self::Identifier => {
+                write!(f, "identifier")
+            }
+            Self::Status => {
+                write!(f, "status")
+            }
+            Self::IsPublic => {
+                write!(f, "is_public")
+            }
+            Self::CreatedOn => {
+                write!(f, "created_on")
+            }
+            Self::CustomField(id) => {
+                write!(f, "cf_{}", id)
+            }
+            Self::Custom(s) => {
+                write!(f, "{}", s)
             }
         }
     }
@@ -162,28 +270,43 @@ impl std::fmt::Display for ProjectsInclude {
 /// The endpoint for all Redmine projects
 #[derive(Debug, Clone, Builder)]
 #[builder(setter(strip_option))]
-pub struct ListProjects {
+pub struct ListProjects<'a> {
     /// the types of associate data to include
     #[builder(default)]
     include: Option<Vec<ProjectsInclude>>,
+    /// Filter by project status
+    #[builder(default)]
+    status: Option<ProjectStatusFilter>,
+    /// Filter by project name
+    #[builder(setter(into), default)]
+    name: Option<Cow<'a, str>>,
+    /// Filter by the date the project was created
+    #[builder(default)]
+    created_on: Option<DateTimeFilterPast>,
+    /// Filter by the date the project was last updated
+    #[builder(default)]
+    updated_on: Option<DateTimeFilterPast>,
+    /// Sort by column
+    #[builder(default)]
+    sort: Option<Vec<SortByColumn<ProjectSortColumn>>>,
 }
 
-impl ReturnsJsonResponse for ListProjects {}
-impl Pageable for ListProjects {
+impl ReturnsJsonResponse for ListProjects<'_> {}
+impl Pageable for ListProjects<'_> {
     fn response_wrapper_key(&self) -> String {
         "projects".to_string()
     }
 }
 
-impl ListProjects {
+impl<'a> ListProjects<'a> {
     /// Create a builder for the endpoint.
     #[must_use]
-    pub fn builder() -> ListProjectsBuilder {
+    pub fn builder() -> ListProjectsBuilder<'a> {
         ListProjectsBuilder::default()
     }
 }
 
-impl Endpoint for ListProjects {
+impl Endpoint for ListProjects<'_> {
     fn method(&self) -> Method {
         Method::GET
     }
@@ -192,14 +315,28 @@ impl Endpoint for ListProjects {
         "projects.json".into()
     }
 
-    fn parameters(&self) -> QueryParams {
+    fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
         params.push_opt("include", self.include.as_ref());
+        params.push_opt("status", self.status.as_ref().map(|s| s.to_string()));
+        params.push_opt("name", self.name.as_ref());
+        params.push_opt(
+            "created_on",
+            self.created_on.as_ref().map(|s| s.to_string()),
+        );
+        params.push_opt(
+            "updated_on",
+            self.updated_on.as_ref().map(|s| s.to_string()),
+        );
+        params.push_opt("sort", self.sort.as_ref());
         params
     }
 }
 
 /// The types of associated data which can be fetched along with a project
+///
+/// Redmine supports the same includes on the project list and on a single
+/// project
 #[derive(Debug, Clone)]
 pub enum ProjectInclude {
     /// Trackers enabled in the project
@@ -210,8 +347,14 @@ pub enum ProjectInclude {
     EnabledModules,
     /// Time Entry Activities enabled in the project
     TimeEntryActivities,
+    /// Issue custom fields enabled in the project
+    IssueCustomFields,
 }
 
+/// The types of associated data which can be fetched along with the projects
+/// in the project list, the same as [ProjectInclude]
+pub type ProjectsInclude = ProjectInclude;
+
 impl std::fmt::Display for ProjectInclude {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -227,6 +370,9 @@ impl std::fmt::Display for ProjectInclude {
             Self::TimeEntryActivities => {
                 write!(f, "time_entry_activities")
             }
+            Self::IssueCustomFields => {
+                write!(f, "issue_custom_fields")
+            }
         }
     }
 }
@@ -237,7 +383,7 @@ impl std::fmt::Display for ProjectInclude {
 pub struct GetProject<'a> {
     /// the project id or name as it appears in the URL
     #[builder(setter(into))]
-    project_id_or_name: Cow<'a, str>,
+    project_id_or_name: ProjectIdentifier<'a>,
     /// the types of associate data to include
     #[builder(default)]
     include: Option<Vec<ProjectInclude>>,
@@ -262,7 +408,7 @@ impl Endpoint for GetProject<'_> {
         format!("projects/{}.json", &self.project_id_or_name).into()
     }
 
-    fn parameters(&self) -> QueryParams {
+    fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
         params.push_opt("include", self.include.as_ref());
         params
@@ -275,7 +421,7 @@ impl Endpoint for GetProject<'_> {
 pub struct ArchiveProject<'a> {
     /// the project id or name as it appears in the URL of the project to archive
     #[builder(setter(into))]
-    project_id_or_name: Cow<'a, str>,
+    project_id_or_name: ProjectIdentifier<'a>,
 }
 
 impl<'a> ArchiveProject<'a> {
@@ -302,7 +448,7 @@ impl Endpoint for ArchiveProject<'_> {
 pub struct UnarchiveProject<'a> {
     /// the project id or name as it appears in the URL of the project to unarchive
     #[builder(setter(into))]
-    project_id_or_name: Cow<'a, str>,
+    project_id_or_name: ProjectIdentifier<'a>,
 }
 
 impl<'a> UnarchiveProject<'a> {
@@ -323,6 +469,70 @@ impl Endpoint for UnarchiveProject<'_> {
     }
 }
 
+/// The standard Redmine project modules, plugins can add more which can be
+/// enabled by name with the enabled_module_names setters
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RedmineModule {
+    /// issue tracking
+    IssueTracking,
+    /// time tracking
+    TimeTracking,
+    /// news
+    News,
+    /// documents
+    Documents,
+    /// files
+    Files,
+    /// wiki
+    Wiki,
+    /// repository (VCS integration)
+    Repository,
+    /// forums
+    Boards,
+    /// calendar
+    Calendar,
+    /// gantt chart
+    Gantt,
+}
+
+impl std::fmt::Display for RedmineModule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::IssueTracking => {
+                write!(f, "issue_tracking")
+            }
+            Self::TimeTracking => {
+                write!(f, "time_tracking")
+            }
+            Self::News => {
+                write!(f, "news")
+            }
+            Self::Documents => {
+                write!(f, "documents")
+            }
+            Self::Files => {
+                write!(f, "files")
+            }
+            Self::Wiki => {
+                write!(f, "wiki")
+            }
+            Self::Repository => {
+                write!(f, "repository")
+            }
+            Self::Boards => {
+                write!(f, "boards")
+            }
+            Self::Calendar => {
+                write!(f, "calendar")
+            }
+            Self::Gantt => {
+                write!(f, "gantt")
+            }
+        }
+    }
+}
+
 /// The endpoint to create a Redmine project
 #[serde_with::skip_serializing_none]
 #[derive(Debug, Clone, Builder, Serialize)]
@@ -367,6 +577,10 @@ pub struct CreateProject<'a> {
     /// values for custom fields
     #[builder(default)]
     custom_field_values: Option<HashMap<u64, Cow<'a, str>>>,
+    /// see [idempotency_key_header](crate::api::idempotency_key_header)
+    #[builder(default)]
+    #[serde(skip_serializing)]
+    idempotency_key: Option<reqwest::header::HeaderValue>,
 }
 
 impl ReturnsJsonResponse for CreateProject<'_> {}
@@ -379,6 +593,16 @@ impl<'a> CreateProject<'a> {
     }
 }
 
+impl CreateProjectBuilder<'_> {
+    /// modules to enable in the project, typed alternative to
+    /// enabled_module_names (which replaces this and vice versa)
+    pub fn enabled_modules(&mut self, modules: Vec<RedmineModule>) -> &mut Self {
+        self.enabled_module_names =
+            Some(Some(modules.iter().map(|m| m.to_string().into()).collect()));
+        self
+    }
+}
+
 impl Endpoint for CreateProject<'_> {
     fn method(&self) -> Method {
         Method::POST
@@ -388,6 +612,10 @@ impl Endpoint for CreateProject<'_> {
         "projects.json".into()
     }
 
+    fn headers(&self) -> reqwest::header::HeaderMap {
+        crate::api::idempotency_key_header(self.idempotency_key.as_ref())
+    }
+
     fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, crate::Error> {
         Ok(Some((
             "application/json",
@@ -406,7 +634,7 @@ pub struct UpdateProject<'a> {
     /// the project id or name as it appears in the URL of the project to update
     #[serde(skip_serializing)]
     #[builder(setter(into))]
-    project_id_or_name: Cow<'a, str>,
+    project_id_or_name: ProjectIdentifier<'a>,
     /// the name of the project
     #[builder(setter(into), default)]
     name: Option<Cow<'a, str>>,
@@ -456,6 +684,16 @@ impl<'a> UpdateProject<'a> {
     }
 }
 
+impl UpdateProjectBuilder<'_> {
+    /// modules to enable in the project, typed alternative to
+    /// enabled_module_names (which replaces this and vice versa)
+    pub fn enabled_modules(&mut self, modules: Vec<RedmineModule>) -> &mut Self {
+        self.enabled_module_names =
+            Some(Some(modules.iter().map(|m| m.to_string().into()).collect()));
+        self
+    }
+}
+
 impl Endpoint for UpdateProject<'_> {
     fn method(&self) -> Method {
         Method::PUT
@@ -481,7 +719,7 @@ impl Endpoint for UpdateProject<'_> {
 pub struct DeleteProject<'a> {
     /// the project id or name as it appears in the URL of the project to delete
     #[builder(setter(into))]
-    project_id_or_name: Cow<'a, str>,
+    project_id_or_name: ProjectIdentifier<'a>,
 }
 
 impl<'a> DeleteProject<'a> {
@@ -517,6 +755,66 @@ pub struct ProjectWrapper<T> {
     pub project: T,
 }
 
+impl Redmine {
+    /// fetches a project together with all of its memberships
+    ///
+    /// Redmine has no include for memberships on [GetProject] so this makes
+    /// two requests under the hood, one with [GetProject] and one per page of
+    /// memberships with [ListProjectMemberships]
+    ///
+    /// # Errors
+    ///
+    /// This can return an error if one of the web requests fails, when Redmine
+    /// returns an HTTP error status (e.g. 404 if the project does not exist) or
+    /// when a response can not be parsed
+    pub fn get_project_with_members<'a>(
+        &self,
+        project_id_or_name: impl Into<ProjectIdentifier<'a>>,
+    ) -> Result<(Project, Vec<ProjectMembership>), crate::Error> {
+        let project_id_or_name = project_id_or_name.into();
+        let memberships_endpoint = ListProjectMemberships::for_project(&project_id_or_name);
+        let ProjectWrapper { project } =
+            self.json_response_body::<_, ProjectWrapper<Project>>(&GetProject {
+                project_id_or_name,
+                include: None,
+            })?;
+        let memberships =
+            self.json_response_body_all_pages::<_, ProjectMembership>(&memberships_endpoint)?;
+        Ok((project, memberships))
+    }
+}
+
+impl RedmineAsync {
+    /// fetches a project together with all of its memberships
+    ///
+    /// Redmine has no include for memberships on [GetProject] so this makes
+    /// two requests under the hood, one with [GetProject] and one per page of
+    /// memberships with [ListProjectMemberships]
+    ///
+    /// # Errors
+    ///
+    /// This can return an error if one of the web requests fails, when Redmine
+    /// returns an HTTP error status (e.g. 404 if the project does not exist) or
+    /// when a response can not be parsed
+    pub async fn get_project_with_members<'a>(
+        &self,
+        project_id_or_name: impl Into<ProjectIdentifier<'a>>,
+    ) -> Result<(Project, Vec<ProjectMembership>), crate::Error> {
+        let project_id_or_name = project_id_or_name.into();
+        let memberships_endpoint = ListProjectMemberships::for_project(&project_id_or_name);
+        let ProjectWrapper { project } = self
+            .json_response_body::<_, ProjectWrapper<Project>>(&GetProject {
+                project_id_or_name,
+                include: None,
+            })
+            .await?;
+        let memberships = self
+            .json_response_body_all_pages::<_, ProjectMembership>(&memberships_endpoint)
+            .await?;
+        Ok((project, memberships))
+    }
+}
+
 #[cfg(test)]
 pub(crate) mod test {
     use super::*;
@@ -552,6 +850,139 @@ pub(crate) mod test {
         Ok(())
     }
 
+    #[traced_test]
+    #[test]
+    fn test_list_projects_status_filter() -> Result<(), Box<dyn Error>> {
+        let _r_project = PROJECT_LOCK.read();
+        dotenvy::dotenv()?;
+        let redmine = crate::api::Redmine::from_env()?;
+        let endpoint = ListProjects::builder()
+            .status(ProjectStatusFilter::Archived)
+            .build()?;
+        let page = redmine.json_response_body_page::<_, Project>(&endpoint, 0, 25)?;
+        for project in page.values {
+            assert_eq!(project.status, ProjectStatus::Archived);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_list_projects_created_on_timestamp_parameter() -> Result<(), Box<dyn Error>> {
+        let endpoint = ListProjects::builder()
+            .created_on(DateTimeFilterPast::GreaterThanOrEqual(
+                time::OffsetDateTime::from_unix_timestamp(1_714_564_800)?,
+            ))
+            .build()?;
+        let mut url = url::Url::parse("https://redmine.example.com/projects.json")?;
+        endpoint.parameters().add_to_url(&mut url);
+        assert_eq!(
+            url.query(),
+            Some("created_on=%3E%3D2024-05-01T12%3A00%3A00Z")
+        );
+        Ok(())
+    }
+
+    #[traced_test]
+    #[test]
+    fn test_list_projects_updated_on_filter() -> Result<(), Box<dyn Error>> {
+        let _r_project = PROJECT_LOCK.read();
+        dotenvy::dotenv()?;
+        let redmine = crate::api::Redmine::from_env()?;
+        let endpoint = ListProjects::builder()
+            .updated_on(DateTimeFilterPast::WithinPastDays(7))
+            .build()?;
+        let mut url = url::Url::parse("https://redmine.example.com/projects.json")?;
+        endpoint.parameters().add_to_url(&mut url);
+        assert_eq!(url.query(), Some("updated_on=%3E%3Ct-7"));
+        redmine.json_response_body_all_pages::<_, Project>(&endpoint)?;
+        Ok(())
+    }
+
+    #[traced_test]
+    #[test]
+    fn test_list_projects_sorted() -> Result<(), Box<dyn Error>> {
+        let _r_project = PROJECT_LOCK.read();
+        dotenvy::dotenv()?;
+        let redmine = crate::api::Redmine::from_env()?;
+        let endpoint = ListProjects::builder()
+            .sort(vec![
+                SortByColumn::Forward {
+                    column_name: ProjectSortColumn::Name,
+                },
+                SortByColumn::Reverse {
+                    column_name: ProjectSortColumn::CreatedOn,
+                },
+            ])
+            .build()?;
+        let mut url = url::Url::parse("https://redmine.example.com/projects.json")?;
+        endpoint.parameters().add_to_url(&mut url);
+        assert_eq!(url.query(), Some("sort=name%2Ccreated_on%3Adesc"));
+        redmine.json_response_body_page::<_, Project>(&endpoint, 0, 25)?;
+        Ok(())
+    }
+
+    /// checks if the field corresponding to an include is set
+    type IncludeCheck = fn(&Project) -> bool;
+
+    /// all includes with a check that the corresponding field is set
+    fn project_includes() -> Vec<(ProjectInclude, IncludeCheck)> {
+        vec![
+            (ProjectInclude::Trackers, |p| p.trackers.is_some()),
+            (ProjectInclude::IssueCategories, |p| {
+                p.issue_categories.is_some()
+            }),
+            (ProjectInclude::EnabledModules, |p| {
+                p.enabled_modules.is_some()
+            }),
+            (ProjectInclude::TimeEntryActivities, |p| {
+                p.time_entry_activities.is_some()
+            }),
+            (ProjectInclude::IssueCustomFields, |p| {
+                p.issue_custom_fields.is_some()
+            }),
+        ]
+    }
+
+    #[traced_test]
+    #[test]
+    fn test_list_projects_each_include() -> Result<(), Box<dyn Error>> {
+        let _r_project = PROJECT_LOCK.read();
+        dotenvy::dotenv()?;
+        let redmine = crate::api::Redmine::from_env()?;
+        for (include, is_included) in project_includes() {
+            let endpoint = ListProjects::builder()
+                .include(vec![include.clone()])
+                .build()?;
+            let projects = redmine.json_response_body_page::<_, Project>(&endpoint, 0, 25)?;
+            assert!(
+                projects.values.iter().all(is_included),
+                "include {} missing in project list",
+                include
+            );
+        }
+        Ok(())
+    }
+
+    #[function_name::named]
+    #[traced_test]
+    #[test]
+    fn test_get_project_each_include() -> Result<(), Box<dyn Error>> {
+        let name = format!("unittest_{}", function_name!());
+        with_project(&name, |redmine, _id, name| {
+            for (include, is_included) in project_includes() {
+                let endpoint = GetProject::builder()
+                    .project_id_or_name(name)
+                    .include(vec![include.clone()])
+                    .build()?;
+                let ProjectWrapper { project } =
+                    redmine.json_response_body::<_, ProjectWrapper<Project>>(&endpoint)?;
+                assert!(is_included(&project), "include {} missing", include);
+            }
+            Ok(())
+        })?;
+        Ok(())
+    }
+
     #[traced_test]
     #[test]
     fn test_list_projects_all_pages() -> Result<(), Box<dyn Error>> {
@@ -602,6 +1033,58 @@ pub(crate) mod test {
         Ok(())
     }
 
+    #[traced_test]
+    #[test]
+    fn test_list_projects_all_pages_iter() -> Result<(), Box<dyn Error>> {
+        let _r_project = PROJECT_LOCK.read();
+        dotenvy::dotenv()?;
+        let redmine = crate::api::Redmine::from_env()?;
+        let endpoint = ListProjects::builder().build()?;
+        let all_pages = redmine.json_response_body_all_pages::<_, Project>(&endpoint)?;
+        let mut iter = redmine
+            .json_response_body_all_pages_iter::<_, Project>(&endpoint)
+            .with_limit(10);
+        assert_eq!(iter.total_count(), None);
+        let first = iter.next().transpose()?;
+        assert_eq!(iter.total_count(), Some(all_pages.len() as u64));
+        let mut iterated = first.into_iter().collect::<Vec<_>>();
+        for project in iter {
+            iterated.push(project?);
+        }
+        assert_eq!(
+            all_pages.iter().map(|p| p.id).collect::<Vec<_>>(),
+            iterated.iter().map(|p| p.id).collect::<Vec<_>>()
+        );
+        Ok(())
+    }
+
+    #[traced_test]
+    #[tokio::test]
+    async fn test_list_projects_async_all_pages_stream() -> Result<(), Box<dyn Error>> {
+        use futures::TryStreamExt;
+        let _r_project = PROJECT_LOCK.read();
+        dotenvy::dotenv()?;
+        let redmine = crate::api::RedmineAsync::from_env()?;
+        let endpoint = ListProjects::builder().build()?;
+        let all_pages = redmine
+            .json_response_body_all_pages::<_, Project>(&endpoint)
+            .await?;
+        let mut stream = redmine
+            .json_response_body_all_pages_stream::<_, Project>(&endpoint)
+            .with_limit(10)
+            .with_concurrency(4);
+        assert_eq!(stream.total_count(), None);
+        let first = stream.try_next().await?;
+        assert_eq!(stream.total_count(), Some(all_pages.len() as u64));
+        let mut streamed = first.into_iter().collect::<Vec<_>>();
+        streamed.extend(stream.try_collect::<Vec<_>>().await?);
+        assert_eq!(
+            all_pages.iter().map(|p| p.id).collect::<Vec<_>>(),
+            streamed.iter().map(|p| p.id).collect::<Vec<_>>()
+        );
+        Ok(())
+    }
+
     #[traced_test]
     #[test]
     fn test_get_project() -> Result<(), Box<dyn Error>> {
@@ -615,6 +1098,38 @@ pub(crate) mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_project_identifier_endpoints() -> Result<(), Box<dyn Error>> {
+        let endpoint = GetProject::builder().project_id_or_name(42).build()?;
+        assert_eq!(endpoint.endpoint(), "projects/42.json");
+        let endpoint = GetProject::builder()
+            .project_id_or_name(crate::api::ProjectId(42))
+            .build()?;
+        assert_eq!(endpoint.endpoint(), "projects/42.json");
+        let endpoint = GetProject::builder()
+            .project_id_or_name("sandbox")
+            .build()?;
+        assert_eq!(endpoint.endpoint(), "projects/sandbox.json");
+        let endpoint = ArchiveProject::builder()
+            .project_id_or_name(ProjectIdentifier::Id(42))
+            .build()?;
+        assert_eq!(endpoint.endpoint(), "projects/42/archive.json");
+        let endpoint = UnarchiveProject::builder()
+            .project_id_or_name("sandbox".to_string())
+            .build()?;
+        assert_eq!(endpoint.endpoint(), "projects/sandbox/unarchive.json");
+        let endpoint = UpdateProject::builder()
+            .project_id_or_name(42)
+            .name("Sandbox")
+            .build()?;
+        assert_eq!(endpoint.endpoint(), "projects/42.json");
+        let endpoint = DeleteProject::builder()
+            .project_id_or_name(ProjectIdentifier::Name("sandbox".into()))
+            .build()?;
+        assert_eq!(endpoint.endpoint(), "projects/sandbox.json");
+        Ok(())
+    }
+
     #[function_name::named]
     #[traced_test]
     #[test]
@@ -624,6 +1139,27 @@ pub(crate) mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_create_project_enabled_modules_body() -> Result<(), Box<dyn Error>> {
+        let endpoint = CreateProject::builder()
+            .name("Test")
+            .identifier("test")
+            .enabled_modules(vec![RedmineModule::IssueTracking, RedmineModule::Wiki])
+            .build()?;
+        assert_eq!(
+            endpoint.body()?,
+            Some((
+                "application/json",
+                br#"{"project":{"name":"Test","identifier":"test","enabled_module_names":["issue_tracking","wiki"]}}"#.to_vec()
+            ))
+        );
+        assert_eq!(
+            serde_json::to_string(&RedmineModule::TimeTracking)?,
+            format!("\"{}\"", RedmineModule::TimeTracking)
+        );
+        Ok(())
+    }
+
     #[function_name::named]
     #[traced_test]
     #[test]
@@ -661,6 +1197,74 @@ pub(crate) mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_project_custom_fields_round_trip() -> Result<(), Box<dyn Error>> {
+        let value = serde_json::json!({
+            "id": 1,
+            "name": "Test",
+            "identifier": "test",
+            "description": "",
+            "is_public": true,
+            "inherit_members": false,
+            "status": 1,
+            "custom_fields": [
+                { "id": 1, "name": "Single", "value": "a" },
+                { "id": 2, "name": "Multiple", "multiple": true, "value": ["a", "b"] }
+            ],
+            "created_on": "2024-01-01T00:00:00Z",
+            "updated_on": "2024-01-01T00:00:00Z"
+        });
+        let o: Project = serde_json::from_value(value.clone())?;
+        assert_eq!(o.custom_fields.as_ref().map(|c| c.len()), Some(2));
+        let reserialized = serde_json::to_value(o)?;
+        assert_eq!(value, reserialized);
+        Ok(())
+    }
+
+    #[test]
+    fn test_project_status_deserialize() -> Result<(), Box<dyn Error>> {
+        let value = serde_json::json!({
+            "id": 1,
+            "name": "Test",
+            "identifier": "test",
+            "description": "",
+            "is_public": true,
+            "inherit_members": false,
+            "status": 9,
+            "created_on": "2024-01-01T00:00:00Z",
+            "updated_on": "2024-01-01T00:00:00Z"
+        });
+        let o: Project = serde_json::from_value(value.clone())?;
+        assert_eq!(o.status, ProjectStatus::Archived);
+        assert_eq!(serde_json::to_value(o)?, value);
+        let status: ProjectStatus = serde_json::from_str("42")?;
+        assert_eq!(status, ProjectStatus::Other(42));
+        assert_eq!(serde_json::to_string(&status)?, "42");
+        Ok(())
+    }
+
+    /// this tests if any of the projects with custom field values contain a
+    /// field we are not deserializing
+    #[traced_test]
+    #[test]
+    fn test_completeness_project_type_with_custom_fields() -> Result<(), Box<dyn Error>> {
+        let _r_project = PROJECT_LOCK.read();
+        dotenvy::dotenv()?;
+        let redmine = crate::api::Redmine::from_env()?;
+        let endpoint = ListProjects::builder().build()?;
+        let values = redmine.json_response_body_all_pages::<_, serde_json::Value>(&endpoint)?;
+        for value in values.into_iter().filter(|v| {
+            v.get("custom_fields")
+                .and_then(|c| c.as_array())
+                .is_some_and(|c| !c.is_empty())
+        }) {
+            let o: Project = serde_json::from_value(value.clone())?;
+            let reserialized = serde_json::to_value(o)?;
+            assert_eq!(value, reserialized);
+        }
+        Ok(())
+    }
+
     /// this tests if any of the results contain a field we are not deserializing
     ///
     /// this will only catch fields we missed if they are part of the response but
@@ -682,17 +1286,20 @@ pub(crate) mod test {
                 ProjectsInclude::Trackers,
                 ProjectsInclude::IssueCategories,
                 ProjectsInclude::EnabledModules,
+                ProjectsInclude::TimeEntryActivities,
+                ProjectsInclude::IssueCustomFields,
             ])
             .build()?;
         let projects = redmine.json_response_body_all_pages::<_, Project>(&endpoint)?;
         for project in projects {
             let get_endpoint = GetProject::builder()
-                .project_id_or_name(project.id.to_string())
+                .project_id_or_name(project.id)
                 .include(vec![
                     ProjectInclude::Trackers,
                     ProjectInclude::IssueCategories,
                     ProjectInclude::EnabledModules,
                     ProjectInclude::TimeEntryActivities,
+                    ProjectInclude::IssueCustomFields,
                 ])
                 .build()?;
             let ProjectWrapper { project: value } = redmine
@@ -703,4 +1310,15 @@ pub(crate) mod test {
         }
         Ok(())
     }
+
+    #[test]
+    fn test_project_with_members_endpoints() -> Result<(), Box<dyn Error>> {
+        let memberships = ListProjectMemberships::for_project(&"sandbox".into());
+        assert_eq!(memberships.method(), Method::GET);
+        assert_eq!(memberships.endpoint(), "projects/sandbox/memberships.json");
+        assert_eq!(memberships.response_wrapper_key(), "memberships");
+        let memberships = ListProjectMemberships::for_project(&42.into());
+        assert_eq!(memberships.endpoint(), "projects/42/memberships.json");
+        Ok(())
+    }
 }
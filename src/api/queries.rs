@@ -21,7 +21,8 @@ pub struct Query {
     pub name: String,
     /// is this query public
     pub is_public: bool,
-    /// the project for project-specific queries
+    /// the project for project-specific queries, absent for global queries
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub project_id: Option<u64>,
 }
 
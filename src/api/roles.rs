@@ -171,6 +171,7 @@ mod test {
         Ok(())
     }
 
+    #[traced_test]
     #[test]
     fn test_get_role() -> Result<(), Box<dyn Error>> {
         dotenvy::dotenv()?;
@@ -180,6 +181,26 @@ mod test {
         Ok(())
     }
 
+    /// this tests if any of the results contain a field we are not deserializing
+    ///
+    /// this will only catch fields we missed if they are part of the response but
+    /// it is better than nothing
+    #[traced_test]
+    #[test]
+    fn test_completeness_role_essentials_type() -> Result<(), Box<dyn Error>> {
+        dotenvy::dotenv()?;
+        let redmine = crate::api::Redmine::from_env()?;
+        let endpoint = ListRoles::builder().build()?;
+        let RolesWrapper { roles: values } =
+            redmine.json_response_body::<_, RolesWrapper<serde_json::Value>>(&endpoint)?;
+        for value in values {
+            let o: RoleEssentials = serde_json::from_value(value.clone())?;
+            let reserialized = serde_json::to_value(o)?;
+            assert_eq!(value, reserialized);
+        }
+        Ok(())
+    }
+
     /// this tests if any of the results contain a field we are not deserializing
     ///
     /// this will only catch fields we missed if they are part of the response but
@@ -2,4 +2,315 @@
 //!
 //! [Redmine Documentation](https://www.redmine.org/projects/redmine/wiki/Rest_Search)
 //!
-//! - [ ] search endpoint
+//! - [x] search endpoint
+//!   - [x] project specific search
+//!   - [x] scope
+//!   - [x] all words/titles only/open issues flags
+//!   - [x] object type flags
+//!   - [x] attachments
+
+use derive_builder::Builder;
+use reqwest::Method;
+use std::borrow::Cow;
+
+use crate::api::{Endpoint, Pageable, QueryParams, ReturnsJsonResponse};
+
+/// a type for search results to use as an API return type
+///
+/// alternatively you can use your own type limited to the fields you need
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct SearchResult {
+    /// numeric id of the object found
+    pub id: u64,
+    /// display title of the object found
+    pub title: String,
+    /// the type of the object found (e.g. issue, news, wiki-page,...)
+    #[serde(rename = "type")]
+    pub result_type: String,
+    /// the URL of the object found
+    pub url: String,
+    /// the description of the object found
+    pub description: String,
+    /// the time associated with the object found (e.g. when it was created)
+    #[serde(
+        serialize_with = "crate::api::serialize_rfc3339",
+        deserialize_with = "crate::api::deserialize_rfc3339"
+    )]
+    pub datetime: time::OffsetDateTime,
+}
+
+/// the projects to search in
+#[derive(Debug, Clone)]
+pub enum SearchScope {
+    /// search in all projects
+    All,
+    /// search only in projects the current user is a member of
+    MyProjects,
+    /// search only in the projects the current user bookmarked
+    Bookmarks,
+    /// search in the project and its subprojects, only used with a project
+    Subprojects,
+}
+
+impl std::fmt::Display for SearchScope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::All => {
+                write!(f, "all")
+            }
+            Self::MyProjects => {
+                write!(f, "my_projects")
+            }
+            Self::Bookmarks => {
+                write!(f, "bookmarks")
+            }
+            Self::Subprojects => {
+                write!(f, "subprojects")
+            }
+        }
+    }
+}
+
+/// whether to search attachments
+#[derive(Debug, Clone)]
+pub enum SearchAttachments {
+    /// do not search in attachments
+    No,
+    /// search in attachment filenames and descriptions too
+    Yes,
+    /// only search in attachment filenames and descriptions
+    Only,
+}
+
+impl std::fmt::Display for SearchAttachments {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::No => {
+                write!(f, "0")
+            }
+            Self::Yes => {
+                write!(f, "1")
+            }
+            Self::Only => {
+                write!(f, "only")
+            }
+        }
+    }
+}
+
+/// The endpoint to search Redmine
+///
+/// if none of the object type flags (issues, news,...) is set Redmine
+/// searches all of them
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(strip_option))]
+pub struct Search<'a> {
+    /// project id or name as it appears in the URL to limit the search to
+    ///
+    /// this uses projects/{project_id_or_name}/search.json which can be
+    /// allowed for users who can not use the global search
+    #[builder(default, setter(into))]
+    project_id_or_name: Option<Cow<'a, str>>,
+    /// the search query
+    #[builder(setter(into))]
+    q: Cow<'a, str>,
+    /// the projects to search in
+    #[builder(default)]
+    scope: Option<SearchScope>,
+    /// if true (the Redmine default) all words need to match, otherwise any one of them
+    #[builder(default)]
+    all_words: Option<bool>,
+    /// only search in titles
+    #[builder(default)]
+    titles_only: Option<bool>,
+    /// only search for open issues
+    #[builder(default)]
+    open_issues: Option<bool>,
+    /// whether to search attachments
+    #[builder(default)]
+    attachments: Option<SearchAttachments>,
+    /// search issues
+    #[builder(default)]
+    issues: Option<bool>,
+    /// search news
+    #[builder(default)]
+    news: Option<bool>,
+    /// search documents
+    #[builder(default)]
+    documents: Option<bool>,
+    /// search changesets
+    #[builder(default)]
+    changesets: Option<bool>,
+    /// search wiki pages
+    #[builder(default)]
+    wiki_pages: Option<bool>,
+    /// search forum messages
+    #[builder(default)]
+    messages: Option<bool>,
+    /// search projects
+    #[builder(default)]
+    projects: Option<bool>,
+}
+
+impl ReturnsJsonResponse for Search<'_> {}
+impl Pageable for Search<'_> {
+    fn response_wrapper_key(&self) -> String {
+        "results".to_string()
+    }
+}
+
+impl<'a> Search<'a> {
+    /// Create a builder for the endpoint.
+    #[must_use]
+    pub fn builder() -> SearchBuilder<'a> {
+        SearchBuilder::default()
+    }
+}
+
+/// Redmine only checks if these flags are present and non-empty so false
+/// needs to be sent as an empty value
+fn search_flag(flag: &bool) -> &'static str {
+    if *flag {
+        "1"
+    } else {
+        ""
+    }
+}
+
+impl Endpoint for Search<'_> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        if let Some(project_id_or_name) = &self.project_id_or_name {
+            format!("projects/{}/search.json", project_id_or_name).into()
+        } else {
+            "search.json".into()
+        }
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        params.push("q", &self.q);
+        params.push_opt("scope", self.scope.as_ref().map(|s| s.to_string()));
+        params.push_opt("all_words", self.all_words.as_ref().map(search_flag));
+        params.push_opt("titles_only", self.titles_only.as_ref().map(search_flag));
+        params.push_opt("open_issues", self.open_issues.as_ref().map(search_flag));
+        params.push_opt(
+            "attachments",
+            self.attachments.as_ref().map(|s| s.to_string()),
+        );
+        params.push_opt("issues", self.issues.as_ref().map(search_flag));
+        params.push_opt("news", self.news.as_ref().map(search_flag));
+        params.push_opt("documents", self.documents.as_ref().map(search_flag));
+        params.push_opt("changesets", self.changesets.as_ref().map(search_flag));
+        params.push_opt("wiki_pages", self.wiki_pages.as_ref().map(search_flag));
+        params.push_opt("messages", self.messages.as_ref().map(search_flag));
+        params.push_opt("projects", self.projects.as_ref().map(search_flag));
+        params
+    }
+}
+
+/// helper struct for outer layers with a results field holding the inner data
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct SearchResultsWrapper<T> {
+    /// to parse JSON with results key
+    pub results: Vec<T>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::api::test_helpers::with_project;
+    use pretty_assertions::assert_eq;
+    use std::error::Error;
+    use tracing_test::traced_test;
+
+    #[test]
+    fn test_search_parameters() -> Result<(), Box<dyn Error>> {
+        let endpoint = Search::builder()
+            .q("deadlock")
+            .scope(SearchScope::MyProjects)
+            .all_words(false)
+            .issues(true)
+            .build()?;
+        let mut url = url::Url::parse("https://redmine.example.com/search.json")?;
+        endpoint.parameters().add_to_url(&mut url);
+        assert_eq!(
+            url.query(),
+            Some("q=deadlock&scope=my_projects&all_words=&issues=1")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_search_endpoint_path() -> Result<(), Box<dyn Error>> {
+        let endpoint = Search::builder().q("deadlock").build()?;
+        assert_eq!(endpoint.endpoint(), "search.json");
+        let endpoint = Search::builder()
+            .project_id_or_name("sandbox")
+            .q("deadlock")
+            .build()?;
+        assert_eq!(endpoint.endpoint(), "projects/sandbox/search.json");
+        Ok(())
+    }
+
+    #[function_name::named]
+    #[traced_test]
+    #[test]
+    fn test_search_in_project() -> Result<(), Box<dyn Error>> {
+        let name = format!("unittest_{}", function_name!());
+        with_project(&name, |redmine, _id, name| {
+            let endpoint = Search::builder()
+                .project_id_or_name(name)
+                .q("deadlock")
+                .build()?;
+            let SearchResultsWrapper { results } =
+                redmine.json_response_body::<_, SearchResultsWrapper<SearchResult>>(&endpoint)?;
+            assert!(results.is_empty());
+            Ok(())
+        })?;
+        Ok(())
+    }
+
+    #[traced_test]
+    #[test]
+    fn test_search_no_pagination() -> Result<(), Box<dyn Error>> {
+        dotenvy::dotenv()?;
+        let redmine = crate::api::Redmine::from_env()?;
+        let endpoint = Search::builder().q("deadlock").issues(true).build()?;
+        redmine.json_response_body::<_, SearchResultsWrapper<SearchResult>>(&endpoint)?;
+        Ok(())
+    }
+
+    #[traced_test]
+    #[test]
+    fn test_search_first_page() -> Result<(), Box<dyn Error>> {
+        dotenvy::dotenv()?;
+        let redmine = crate::api::Redmine::from_env()?;
+        let endpoint = Search::builder().q("deadlock").issues(true).build()?;
+        redmine.json_response_body_page::<_, SearchResult>(&endpoint, 0, 25)?;
+        Ok(())
+    }
+
+    /// this tests if any of the results contain a field we are not deserializing
+    ///
+    /// this will only catch fields we missed if they are part of the response but
+    /// it is better than nothing
+    #[traced_test]
+    #[test]
+    fn test_completeness_search_result_type() -> Result<(), Box<dyn Error>> {
+        dotenvy::dotenv()?;
+        let redmine = crate::api::Redmine::from_env()?;
+        let endpoint = Search::builder().q("test").build()?;
+        let SearchResultsWrapper { results: values } =
+            redmine.json_response_body::<_, SearchResultsWrapper<serde_json::Value>>(&endpoint)?;
+        for value in values {
+            let o: SearchResult = serde_json::from_value(value.clone())?;
+            let reserialized = serde_json::to_value(o)?;
+            assert_eq!(value, reserialized);
+        }
+        Ok(())
+    }
+}
@@ -7,9 +7,14 @@
 //!   - [x] project_id filter
 //!   - [x] issue_id filter
 //!   - [x] activity_id filter
+//!     - [x] activity id (multiple are possible, comma separated)
+//!     - [x] negation of list
 //!   - [x] spent_on filter (date)
+//!     - [x] exact match, less than, greater than, range
+//!     - [x] relative (today, last week, this month,...)
 //!   - [x] from filter
 //!   - [x] to filter
+//!   - [x] sort
 //! - [x] specific time entry endpoint
 //! - [x] create time entry endpoint
 //! - [x] update time entry endpoint
@@ -18,10 +23,11 @@
 use derive_builder::Builder;
 use reqwest::Method;
 use std::borrow::Cow;
+use std::collections::BTreeMap;
 
 use crate::api::custom_fields::CustomFieldEssentialsWithValue;
 use crate::api::enumerations::TimeEntryActivityEssentials;
-use crate::api::issues::IssueEssentials;
+use crate::api::issues::{CustomField, DateFilter, IssueEssentials, SortByColumn};
 use crate::api::projects::ProjectEssentials;
 use crate::api::users::UserEssentials;
 use crate::api::{Endpoint, Pageable, QueryParams, ReturnsJsonResponse};
@@ -67,6 +73,115 @@ pub struct TimeEntry {
     pub updated_on: time::OffsetDateTime,
 }
 
+/// sums up the hours of the time entries grouped by the key returned by
+/// the key selector, e.g. `|e| e.user.id` for hours per user,
+/// `|e| e.activity.id` for hours per activity or `|e| e.spent_on` for hours
+/// per day
+///
+/// this is done client side so the entries should be all pages of a
+/// [ListTimeEntries] request with the desired filters
+#[must_use]
+pub fn sum_hours_by<K, F>(entries: &[TimeEntry], mut key: F) -> BTreeMap<K, f64>
+where
+    K: Ord,
+    F: FnMut(&TimeEntry) -> K,
+{
+    let mut sums = BTreeMap::new();
+    for entry in entries {
+        *sums.entry(key(entry)).or_insert(0.0) += entry.hours;
+    }
+    sums
+}
+
+/// ways to filter for time entry activities
+#[derive(Debug, Clone)]
+pub enum ActivityFilter {
+    /// match a specific list of activities
+    TheseActivities(Vec<u64>),
+    /// match any activity but a specific list of activities
+    NotTheseActivities(Vec<u64>),
+}
+
+impl std::fmt::Display for ActivityFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ActivityFilter::TheseActivities(ids) => {
+                let s: String = ids
+                    .iter()
+                    .map(|e| e.to_string())
+                    .collect::<Vec<_>>()
+                    .join(",");
+                write!(f, "{}", s)
+            }
+            ActivityFilter::NotTheseActivities(ids) => {
+                let s: String = ids
+                    .iter()
+                    .map(|e| format!("!{}", e))
+                    .collect::<Vec<_>>()
+                    .join(",");
+                write!(f, "{}", s)
+            }
+        }
+    }
+}
+
+/// The columns time entries can be sorted by
+#[derive(Debug, Clone)]
+pub enum TimeEntrySortColumn {
+    /// day the time was spent on
+    SpentOn,
+    /// creation time
+    CreatedOn,
+    /// user who spent the time
+    User,
+    /// activity
+    Activity,
+    /// project
+    Project,
+    /// issue
+    Issue,
+    /// hours spent
+    Hours,
+    /// a custom field by id
+    CustomField(u64),
+    /// any other column name Redmine understands
+    Custom(String),
+}
+
+impl std::fmt::Display for TimeEntrySortColumn {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::SpentOn => {
+                write!(f, "spent_on")
+            }
+            Self::CreatedOn => {
+                write!(f, "created_on")
+            }
+            Self::User => {
+                write!(f, "user")
+            }
+            Self::Activity => {
+                write!(f, "activity")
+            }
+            Self::Project => {
+                write!(f, "project")
+            }
+            Self::Issue => {
+                write!(f, "issue")
+            }
+            Self::Hours => {
+                write!(f, "hours")
+            }
+            Self::CustomField(id) => {
+                write!(f, "cf_{}", id)
+            }
+            Self::Custom(s) => {
+                write!(f, "{}", s)
+            }
+        }
+    }
+}
+
 /// The endpoint for all time entries
 #[derive(Debug, Clone, Builder)]
 #[builder(setter(strip_option))]
@@ -82,16 +197,19 @@ pub struct ListTimeEntries<'a> {
     issue_id: Option<u64>,
     /// activity for the spent time
     #[builder(default)]
-    activity_id: Option<u64>,
+    activity_id: Option<ActivityFilter>,
     /// day the time was spent on
     #[builder(default)]
-    spent_on: Option<time::Date>,
+    spent_on: Option<DateFilter>,
     /// from day filter for spent on
     #[builder(default)]
     from: Option<time::Date>,
     /// to day filter for spent on
     #[builder(default)]
     to: Option<time::Date>,
+    /// Sort by column
+    #[builder(default)]
+    sort: Option<Vec<SortByColumn<TimeEntrySortColumn>>>,
 }
 
 impl ReturnsJsonResponse for ListTimeEntries<'_> {}
@@ -118,15 +236,19 @@ impl Endpoint for ListTimeEntries<'_> {
         "time_entries.json".into()
     }
 
-    fn parameters(&self) -> QueryParams {
+    fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
         params.push_opt("user_id", self.user_id);
         params.push_opt("project_id", self.project_id_or_name.as_ref());
         params.push_opt("issue_id", self.issue_id);
-        params.push_opt("activity_id", self.activity_id);
-        params.push_opt("spent_on", self.spent_on);
+        params.push_opt(
+            "activity_id",
+            self.activity_id.as_ref().map(|s| s.to_string()),
+        );
+        params.push_opt("spent_on", self.spent_on.as_ref().map(|s| s.to_string()));
         params.push_opt("from", self.from);
         params.push_opt("to", self.to);
+        params.push_opt("sort", self.sort.as_ref());
         params
     }
 }
@@ -184,17 +306,28 @@ pub struct CreateTimeEntry<'a> {
     /// User Id is only required when posting time on behalf of another user, defaults to current user
     #[builder(default)]
     user_id: Option<u64>,
+    /// custom field values
+    #[builder(default)]
+    custom_fields: Option<Vec<CustomField<'a>>>,
+    /// see [idempotency_key_header](crate::api::idempotency_key_header)
+    #[builder(default)]
+    #[serde(skip_serializing)]
+    idempotency_key: Option<reqwest::header::HeaderValue>,
 }
 
 impl ReturnsJsonResponse for CreateTimeEntry<'_> {}
 
 impl CreateTimeEntryBuilder<'_> {
-    /// ensures that either issue_id or project_id is non-None when [Self::build()] is called
+    /// ensures that exactly one of issue_id or project_id is non-None when [Self::build()] is called
     fn validate(&self) -> Result<(), String> {
-        if self.issue_id.is_none() && self.project_id.is_none() {
-            Err("Either issue_id or project_id need to be specified".to_string())
-        } else {
-            Ok(())
+        match (&self.issue_id, &self.project_id) {
+            (None | Some(None), None | Some(None)) => {
+                Err("Either issue_id or project_id need to be specified".to_string())
+            }
+            (Some(Some(_)), Some(Some(_))) => {
+                Err("Only one of issue_id or project_id can be specified".to_string())
+            }
+            _ => Ok(()),
         }
     }
 }
@@ -216,6 +349,10 @@ impl Endpoint for CreateTimeEntry<'_> {
         "time_entries.json".into()
     }
 
+    fn headers(&self) -> reqwest::header::HeaderMap {
+        crate::api::idempotency_key_header(self.idempotency_key.as_ref())
+    }
+
     fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, crate::Error> {
         Ok(Some((
             "application/json",
@@ -255,6 +392,9 @@ pub struct UpdateTimeEntry<'a> {
     /// User Id is only required when posting time on behalf of another user, defaults to current user
     #[builder(default)]
     user_id: Option<u64>,
+    /// custom field values
+    #[builder(default)]
+    custom_fields: Option<Vec<CustomField<'a>>>,
 }
 
 impl<'a> UpdateTimeEntry<'a> {
@@ -337,6 +477,66 @@ mod test {
     /// creating/deleting or creating/updating/deleting
     static TIME_ENTRY_LOCK: RwLock<()> = RwLock::const_new(());
 
+    /// builds a time entry for the aggregation tests
+    fn time_entry_fixture(
+        id: u64,
+        user_id: u64,
+        activity_id: u64,
+        hours: f64,
+        spent_on: &str,
+    ) -> Result<TimeEntry, serde_json::Error> {
+        serde_json::from_value(serde_json::json!({
+            "id": id,
+            "user": { "id": user_id, "name": format!("User {}", user_id) },
+            "hours": hours,
+            "activity": { "id": activity_id, "name": format!("Activity {}", activity_id) },
+            "project": { "id": 1, "name": "Project" },
+            "spent_on": spent_on,
+            "created_on": "2024-01-01T12:00:00Z",
+            "updated_on": "2024-01-01T12:00:00Z"
+        }))
+    }
+
+    #[test]
+    fn test_sum_hours_by() -> Result<(), Box<dyn Error>> {
+        let entries = vec![
+            time_entry_fixture(1, 1, 8, 1.5, "2024-01-01")?,
+            time_entry_fixture(2, 2, 9, 2.0, "2024-01-01")?,
+            time_entry_fixture(3, 1, 8, 0.25, "2024-01-02")?,
+        ];
+        assert_eq!(
+            sum_hours_by(&entries, |e| e.activity.id),
+            BTreeMap::from([(8, 1.75), (9, 2.0)])
+        );
+        assert_eq!(
+            sum_hours_by(&entries, |e| e.user.id),
+            BTreeMap::from([(1, 1.75), (2, 2.0)])
+        );
+        assert_eq!(
+            sum_hours_by(&entries, |e| e.spent_on),
+            BTreeMap::from([
+                (
+                    Some(time::Date::from_calendar_date(
+                        2024,
+                        time::Month::January,
+                        1
+                    )?),
+                    3.5
+                ),
+                (
+                    Some(time::Date::from_calendar_date(
+                        2024,
+                        time::Month::January,
+                        2
+                    )?),
+                    0.25
+                )
+            ])
+        );
+        assert!(sum_hours_by(&[], |e| e.activity.id).is_empty());
+        Ok(())
+    }
+
     #[traced_test]
     #[test]
     fn test_list_time_entries_no_pagination() -> Result<(), Box<dyn Error>> {
@@ -359,6 +559,42 @@ mod test {
         Ok(())
     }
 
+    #[traced_test]
+    #[test]
+    fn test_list_time_entries_spent_on_filter() -> Result<(), Box<dyn Error>> {
+        let _r_time_entries = TIME_ENTRY_LOCK.read();
+        dotenvy::dotenv()?;
+        let redmine = crate::api::Redmine::from_env()?;
+        let endpoint = ListTimeEntries::builder()
+            .spent_on(DateFilter::ThisMonth)
+            .build()?;
+        redmine.json_response_body_page::<_, TimeEntry>(&endpoint, 0, 25)?;
+        Ok(())
+    }
+
+    #[traced_test]
+    #[test]
+    fn test_list_time_entries_sorted() -> Result<(), Box<dyn Error>> {
+        let _r_time_entries = TIME_ENTRY_LOCK.read();
+        dotenvy::dotenv()?;
+        let redmine = crate::api::Redmine::from_env()?;
+        let endpoint = ListTimeEntries::builder()
+            .sort(vec![
+                SortByColumn::Reverse {
+                    column_name: TimeEntrySortColumn::SpentOn,
+                },
+                SortByColumn::Forward {
+                    column_name: TimeEntrySortColumn::Hours,
+                },
+            ])
+            .build()?;
+        let mut url = url::Url::parse("https://redmine.example.com/time_entries.json")?;
+        endpoint.parameters().add_to_url(&mut url);
+        assert_eq!(url.query(), Some("sort=spent_on%3Adesc%2Chours"));
+        redmine.json_response_body_page::<_, TimeEntry>(&endpoint, 0, 25)?;
+        Ok(())
+    }
+
     /// this takes a long time and is not very useful given the relative uniformity of time entries
     // #[traced_test]
     // #[test]
@@ -398,6 +634,50 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_create_time_entry_requires_exactly_one_of_issue_or_project() {
+        assert!(CreateTimeEntry::builder().hours(1.0).build().is_err());
+        assert!(CreateTimeEntry::builder()
+            .issue_id(1)
+            .project_id(1)
+            .hours(1.0)
+            .build()
+            .is_err());
+        assert!(CreateTimeEntry::builder()
+            .project_id(1)
+            .hours(1.0)
+            .build()
+            .is_ok());
+    }
+
+    #[traced_test]
+    #[test]
+    fn test_create_get_delete_time_entry() -> Result<(), Box<dyn Error>> {
+        let _w_time_entries = TIME_ENTRY_LOCK.write();
+        dotenvy::dotenv()?;
+        let redmine = crate::api::Redmine::from_env()?;
+        let spent_on = time::Date::from_calendar_date(2024, time::Month::January, 15)?;
+        let create_endpoint = super::CreateTimeEntry::builder()
+            .issue_id(25095)
+            .spent_on(spent_on)
+            .hours(1.5)
+            .activity_id(8)
+            .comments("round trip".into())
+            .build()?;
+        let TimeEntryWrapper { time_entry } =
+            redmine.json_response_body::<_, TimeEntryWrapper<TimeEntry>>(&create_endpoint)?;
+        let get_endpoint = GetTimeEntry::builder().id(time_entry.id).build()?;
+        let TimeEntryWrapper {
+            time_entry: fetched,
+        } = redmine.json_response_body::<_, TimeEntryWrapper<TimeEntry>>(&get_endpoint)?;
+        assert_eq!(fetched.hours, 1.5);
+        assert_eq!(fetched.spent_on, Some(spent_on));
+        assert_eq!(fetched.comments.as_deref(), Some("round trip"));
+        let delete_endpoint = DeleteTimeEntry::builder().id(time_entry.id).build()?;
+        redmine.ignore_response_body::<_>(&delete_endpoint)?;
+        Ok(())
+    }
+
     #[traced_test]
     #[test]
     fn test_update_time_entry() -> Result<(), Box<dyn Error>> {
@@ -440,4 +720,25 @@ mod test {
         }
         Ok(())
     }
+
+    /// this tests if the single time entry endpoint returns any fields we are
+    /// not deserializing
+    #[traced_test]
+    #[test]
+    fn test_completeness_get_time_entry_type() -> Result<(), Box<dyn Error>> {
+        let _r_time_entries = TIME_ENTRY_LOCK.read();
+        dotenvy::dotenv()?;
+        let redmine = crate::api::Redmine::from_env()?;
+        let list_endpoint = ListTimeEntries::builder().build()?;
+        let time_entries = redmine.json_response_body_page::<_, TimeEntry>(&list_endpoint, 0, 5)?;
+        for time_entry in time_entries.values {
+            let endpoint = GetTimeEntry::builder().id(time_entry.id).build()?;
+            let TimeEntryWrapper { time_entry: value } =
+                redmine.json_response_body::<_, TimeEntryWrapper<serde_json::Value>>(&endpoint)?;
+            let o: TimeEntry = serde_json::from_value(value.clone())?;
+            let reserialized = serde_json::to_value(o)?;
+            assert_eq!(value, reserialized);
+        }
+        Ok(())
+    }
 }
@@ -42,7 +42,7 @@ impl From<&Tracker> for TrackerEssentials {
 /// a type for tracker to use as an API return type
 ///
 /// alternatively you can use your own type limited to the fields you need
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub struct Tracker {
     /// numeric id
     pub id: u64,
@@ -3,6 +3,8 @@
 //! [Redmine Documentation](https://www.redmine.org/projects/redmine/wiki/Rest_api#Attaching-files)
 //!
 //! - [x] upload file endpoint
+//!   - [x] streaming upload from a reader (Redmine::upload_file_from_reader, RedmineAsync::upload_file_from_reader)
+//! - [x] upload and attach a local file to an issue (Redmine::attach_file_to_issue, RedmineAsync::attach_file_to_issue)
 //! - [ ] create project file endpoint (in api::files)
 //! - [x] [CreateIssue|crate::api::issues::CreateIssue] parameter for attachments (in api::issues)
 //! - [x] [UpdateIssue|crate::api::issues::UpdateIssue] parameter for attachments (in api::issues)
@@ -15,7 +17,7 @@ use derive_builder::Builder;
 use reqwest::Method;
 use std::borrow::Cow;
 use std::io::Read;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use crate::api::{Endpoint, QueryParams, ReturnsJsonResponse};
 
@@ -25,7 +27,7 @@ use crate::api::{Endpoint, QueryParams, ReturnsJsonResponse};
 #[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub struct FileUploadToken {
     /// the file upload token to be used in other endpoints
-    token: String,
+    pub token: String,
 }
 
 /// endpoint to upload a file for use in either project files or issue attachments
@@ -63,7 +65,7 @@ impl Endpoint for UploadFile<'_> {
         "uploads.json".into()
     }
 
-    fn parameters(&self) -> QueryParams {
+    fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
         if let Some(ref filename) = self.filename {
             params.push("filename", filename);
@@ -86,6 +88,56 @@ impl Endpoint for UploadFile<'_> {
     }
 }
 
+/// guesses the MIME content type of a file from its extension for use in
+/// [UploadedAttachment|crate::api::issues::UploadedAttachment]
+///
+/// only common extensions are known, everything else (including files
+/// without an extension) is application/octet-stream
+#[must_use]
+pub fn content_type_for_path(path: &Path) -> &'static str {
+    let extension = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(|e| e.to_ascii_lowercase());
+    match extension.as_deref() {
+        Some("txt" | "log") => "text/plain",
+        Some("md") => "text/markdown",
+        Some("csv") => "text/csv",
+        Some("html" | "htm") => "text/html",
+        Some("xml") => "application/xml",
+        Some("json") => "application/json",
+        Some("pdf") => "application/pdf",
+        Some("zip") => "application/zip",
+        Some("gz") => "application/gzip",
+        Some("png") => "image/png",
+        Some("jpg" | "jpeg") => "image/jpeg",
+        Some("gif") => "image/gif",
+        Some("svg") => "image/svg+xml",
+        _ => "application/octet-stream",
+    }
+}
+
+/// returns the filename part of the path to send to Redmine
+///
+/// # Errors
+///
+/// This returns [crate::Error::UploadFileError] if the path has no
+/// filename or it is not valid UTF-8
+pub(crate) fn upload_filename(path: &Path) -> Result<String, crate::Error> {
+    path.file_name()
+        .and_then(|f| f.to_str())
+        .map(ToString::to_string)
+        .ok_or_else(|| {
+            crate::Error::UploadFileError(
+                path.to_path_buf(),
+                std::io::Error::new(
+                    std::io::ErrorKind::InvalidInput,
+                    "path has no valid UTF-8 filename",
+                ),
+            )
+        })
+}
+
 /// A lot of APIs in Redmine wrap their data in an extra layer, this is a
 /// helper struct for outer layers with a upload field holding the inner data
 #[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
@@ -98,7 +150,8 @@ pub struct UploadWrapper<T> {
 pub(crate) mod test {
     use super::*;
     use crate::api::issues::{
-        test::ISSUES_LOCK, CreateIssue, Issue, IssueWrapper, UpdateIssue, UploadedAttachment,
+        test::ISSUES_LOCK, CreateIssue, GetIssue, Issue, IssueInclude, IssueWrapper, UpdateIssue,
+        UploadedAttachment,
     };
     use crate::api::test_helpers::with_project;
     use std::error::Error;
@@ -132,6 +185,89 @@ pub(crate) mod test {
         Ok(())
     }
 
+    #[function_name::named]
+    #[traced_test]
+    #[test]
+    fn test_create_issue_with_streamed_attachment() -> Result<(), Box<dyn Error>> {
+        let _w_issues = ISSUES_LOCK.write();
+        let name = format!("unittest_{}", function_name!());
+        with_project(&name, |redmine, project_id, _| {
+            let file = std::fs::File::open("README.md")?;
+            let FileUploadToken { token } = redmine.upload_file_from_reader(file, "README.md")?;
+            let create_endpoint = CreateIssue::builder()
+                .project_id(project_id)
+                .subject("Streamed Attachment Test Issue")
+                .uploads(vec![UploadedAttachment {
+                    token: token.into(),
+                    filename: "README.md".into(),
+                    description: Some("Uploaded as part of unit test for redmine-api".into()),
+                    content_type: "text/markdown".into(),
+                }])
+                .build()?;
+            redmine.json_response_body::<_, IssueWrapper<Issue>>(&create_endpoint)?;
+            Ok(())
+        })?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_content_type_for_path() {
+        assert_eq!(content_type_for_path(Path::new("notes.txt")), "text/plain");
+        assert_eq!(
+            content_type_for_path(Path::new("dir/Photo.JPG")),
+            "image/jpeg"
+        );
+        assert_eq!(
+            content_type_for_path(Path::new("data.unknown")),
+            "application/octet-stream"
+        );
+        assert_eq!(
+            content_type_for_path(Path::new("Makefile")),
+            "application/octet-stream"
+        );
+    }
+
+    #[test]
+    fn test_upload_filename() -> Result<(), Box<dyn Error>> {
+        assert_eq!(upload_filename(Path::new("/tmp/notes.txt"))?, "notes.txt");
+        assert!(upload_filename(Path::new("/")).is_err());
+        Ok(())
+    }
+
+    #[function_name::named]
+    #[traced_test]
+    #[test]
+    fn test_attach_file_to_issue() -> Result<(), Box<dyn Error>> {
+        let _w_issues = ISSUES_LOCK.write();
+        let name = format!("unittest_{}", function_name!());
+        with_project(&name, |redmine, project_id, _| {
+            let path = std::env::temp_dir().join(format!("{}.txt", name));
+            std::fs::write(&path, "attached as part of unit test for redmine-api")?;
+            let create_endpoint = CreateIssue::builder()
+                .project_id(project_id)
+                .subject("Attach File Test Issue")
+                .build()?;
+            let IssueWrapper { issue }: IssueWrapper<Issue> =
+                redmine.json_response_body::<_, _>(&create_endpoint)?;
+            let result = redmine.attach_file_to_issue(issue.id, &path, Some("attached file"));
+            std::fs::remove_file(&path)?;
+            result?;
+            let get_endpoint = GetIssue::builder()
+                .id(issue.id)
+                .include(vec![IssueInclude::Attachments])
+                .build()?;
+            let IssueWrapper { issue }: IssueWrapper<Issue> =
+                redmine.json_response_body::<_, _>(&get_endpoint)?;
+            let attachments = issue.attachments.unwrap_or_default();
+            assert_eq!(attachments.len(), 1);
+            assert_eq!(attachments[0].filename, format!("{}.txt", name));
+            assert_eq!(attachments[0].content_type.as_deref(), Some("text/plain"));
+            assert_eq!(attachments[0].description.as_deref(), Some("attached file"));
+            Ok(())
+        })?;
+        Ok(())
+    }
+
     #[function_name::named]
     #[traced_test]
     #[test]
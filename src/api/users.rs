@@ -5,7 +5,11 @@
 //! - [x] all users endpoint
 //!   - [x] status filter
 //!   - [x] name filter
-//!   - [x] group_id filter
+//!   - [x] login, firstname, lastname and mail filters (exact or substring)
+//!   - [x] sort
+//!   - [x] group_id filter (multiple groups)
+//!   - [x] include memberships and groups
+//!   - [x] created_on, updated_on and last_login_on filters
 //! - [x] specific user endpoint
 //!   - [x] by user id
 //!   - [x] current
@@ -19,8 +23,13 @@ use std::borrow::Cow;
 
 use crate::api::custom_fields::CustomFieldEssentialsWithValue;
 use crate::api::groups::GroupEssentials;
+use crate::api::issues::{DateTimeFilterPast, Issue, SortByColumn, StringFieldFilter};
 use crate::api::project_memberships::UserProjectMembership;
-use crate::api::{Endpoint, Pageable, QueryParams, ReturnsJsonResponse};
+use crate::api::{
+    Endpoint, Pageable, QueryParams, Redmine, RedmineAsync, ReturnsJsonResponse,
+    MAX_CONCURRENT_REQUESTS,
+};
+use futures::{StreamExt, TryStreamExt};
 use serde::Serialize;
 
 /// a minimal type for Redmine users used in
@@ -64,7 +73,7 @@ pub struct User {
     pub twofa_scheme: Option<String>,
     /// allows setting users to be e.g. LDAP users
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    auth_source_id: Option<u64>,
+    pub auth_source_id: Option<u64>,
     /// The time when this user was created
     #[serde(
         serialize_with = "crate::api::serialize_rfc3339",
@@ -132,6 +141,58 @@ impl std::fmt::Display for UserStatus {
     }
 }
 
+/// The columns users can be sorted by
+#[derive(Debug, Clone)]
+pub enum UserSortColumn {
+    /// login name
+    Login,
+    /// first name
+    Firstname,
+    /// last name
+    Lastname,
+    /// email address
+    Mail,
+    /// admin flag
+    Admin,
+    /// creation time
+    CreatedOn,
+    /// time of the last login
+    LastLoginOn,
+    /// any other column name Redmine understands
+    Custom(String),
+}
+
+impl std::fmt::Display for UserSortColumn {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Login => {
+                write!(f, "login")
+            }
+            Self::Firstname => {
+                write!(f, "firstname")
+            }
+            Self::Lastname => {
+                write!(f, "lastname")
+            }
+            Self::Mail => {
+                write!(f, "mail")
+            }
+            Self::Admin => {
+                write!(f, "admin")
+            }
+            Self::CreatedOn => {
+                write!(f, "created_on")
+            }
+            Self::LastLoginOn => {
+                write!(f, "last_login_on")
+            }
+            Self::Custom(s) => {
+                write!(f, "{}", s)
+            }
+        }
+    }
+}
+
 /// The endpoint for all users
 #[derive(Debug, Clone, Builder)]
 #[builder(setter(strip_option))]
@@ -142,11 +203,48 @@ pub struct ListUsers<'a> {
     status: Option<UserStatus>,
     #[builder(default)]
     /// Filter by name, this matches login, firstname, lastname and if it contains a space also firstname and lastname
+    ///
+    /// this is always a substring match, use the more specific filters below
+    /// for exact matches
     #[builder(setter(into))]
     name: Option<Cow<'a, str>>,
-    /// Users need to be members of this group
+    /// Filter by login, plain strings are converted to a substring match
+    #[builder(default, setter(into))]
+    login: Option<StringFieldFilter>,
+    /// Filter by first name, plain strings are converted to a substring match
+    #[builder(default, setter(into))]
+    firstname: Option<StringFieldFilter>,
+    /// Filter by last name, plain strings are converted to a substring match
+    #[builder(default, setter(into))]
+    lastname: Option<StringFieldFilter>,
+    /// Filter by email address, plain strings are converted to a substring match
+    #[builder(default, setter(into))]
+    mail: Option<StringFieldFilter>,
+    /// Sort by column
+    #[builder(default)]
+    sort: Option<Vec<SortByColumn<UserSortColumn>>>,
+    /// Users need to be members of one of these groups
+    ///
+    /// use e.g. `.group_id([5])` for a single group
+    ///
+    /// Redmine before 5.1 only honours a single group id here
+    #[builder(default, setter(into))]
+    group_id: Option<Vec<u64>>,
+    /// associated data to include for each user
+    ///
+    /// older Redmine versions ignore this on the list endpoint, use
+    /// [GetUser] for each user there
+    #[builder(default)]
+    include: Option<Vec<UserInclude>>,
+    /// Filter by the time the user was created
+    #[builder(default)]
+    created_on: Option<DateTimeFilterPast>,
+    /// Filter by the time the user was last updated
+    #[builder(default)]
+    updated_on: Option<DateTimeFilterPast>,
+    /// Filter by the time the user last logged in
     #[builder(default)]
-    group_id: Option<u64>,
+    last_login_on: Option<DateTimeFilterPast>,
 }
 
 impl ReturnsJsonResponse for ListUsers<'_> {}
@@ -173,11 +271,29 @@ impl Endpoint for ListUsers<'_> {
         "users.json".into()
     }
 
-    fn parameters(&self) -> QueryParams {
+    fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
         params.push_opt("status", self.status.as_ref().map(|s| s.to_string()));
         params.push_opt("name", self.name.as_ref());
-        params.push_opt("group_id", self.group_id);
+        params.push_opt("login", self.login.as_ref().map(|s| s.to_string()));
+        params.push_opt("firstname", self.firstname.as_ref().map(|s| s.to_string()));
+        params.push_opt("lastname", self.lastname.as_ref().map(|s| s.to_string()));
+        params.push_opt("mail", self.mail.as_ref().map(|s| s.to_string()));
+        params.push_opt("sort", self.sort.as_ref());
+        params.push_opt("group_id", self.group_id.as_ref());
+        params.push_opt("include", self.include.as_ref());
+        params.push_opt(
+            "created_on",
+            self.created_on.as_ref().map(|s| s.to_string()),
+        );
+        params.push_opt(
+            "updated_on",
+            self.updated_on.as_ref().map(|s| s.to_string()),
+        );
+        params.push_opt(
+            "last_login_on",
+            self.last_login_on.as_ref().map(|s| s.to_string()),
+        );
         params
     }
 }
@@ -238,7 +354,7 @@ impl Endpoint for GetUser {
         }
     }
 
-    fn parameters(&self) -> QueryParams {
+    fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
         params.push_opt("include", self.include.as_ref());
         params
@@ -305,6 +421,10 @@ pub struct CreateUser<'a> {
     /// Make the user a Redmine administrator
     #[builder(default)]
     admin: Option<bool>,
+    /// see [idempotency_key_header](crate::api::idempotency_key_header)
+    #[builder(default)]
+    #[serde(skip_serializing)]
+    idempotency_key: Option<reqwest::header::HeaderValue>,
 }
 
 impl ReturnsJsonResponse for CreateUser<'_> {}
@@ -326,6 +446,10 @@ impl Endpoint for CreateUser<'_> {
         "users.json".into()
     }
 
+    fn headers(&self) -> reqwest::header::HeaderMap {
+        crate::api::idempotency_key_header(self.idempotency_key.as_ref())
+    }
+
     fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, crate::Error> {
         Ok(Some((
             "application/json",
@@ -464,9 +588,85 @@ pub struct UserWrapperWithSendInformation<T> {
     pub send_information: Option<bool>,
 }
 
+/// the distinct ids of the assignees of the issues in ascending order
+fn assignee_ids(issues: &[Issue]) -> std::collections::BTreeSet<u64> {
+    issues
+        .iter()
+        .filter_map(|issue| issue.assigned_to.as_ref().map(|a| a.id))
+        .collect()
+}
+
+impl Redmine {
+    /// fetches the full user for every distinct assignee of the issues, one
+    /// [GetUser] request per user
+    ///
+    /// Redmine has no include to return more than the id and name of the
+    /// assignee, assignees which are groups are not in the result since
+    /// Redmine returns 404 for them
+    ///
+    /// # Errors
+    ///
+    /// This can return an error if a web request fails, when Redmine returns
+    /// an HTTP error status other than 404 or when a response can not be parsed
+    pub fn resolve_assignees(
+        &self,
+        issues: &[Issue],
+    ) -> Result<std::collections::HashMap<u64, User>, crate::Error> {
+        let mut users = std::collections::HashMap::new();
+        for user_id in assignee_ids(issues) {
+            let endpoint = GetUser {
+                id: Some(user_id),
+                include: None,
+            };
+            if let Some(UserWrapper { user }) =
+                self.json_response_body_optional::<_, UserWrapper<User>>(&endpoint)?
+            {
+                users.insert(user_id, user);
+            }
+        }
+        Ok(users)
+    }
+}
+
+impl RedmineAsync {
+    /// fetches the full user for every distinct assignee of the issues, one
+    /// [GetUser] request per user
+    ///
+    /// Redmine has no include to return more than the id and name of the
+    /// assignee, assignees which are groups are not in the result since
+    /// Redmine returns 404 for them
+    ///
+    /// up to [MAX_CONCURRENT_REQUESTS] requests are sent concurrently
+    ///
+    /// # Errors
+    ///
+    /// This can return an error if a web request fails, when Redmine returns
+    /// an HTTP error status other than 404 or when a response can not be parsed
+    pub async fn resolve_assignees(
+        &self,
+        issues: &[Issue],
+    ) -> Result<std::collections::HashMap<u64, User>, crate::Error> {
+        let users = futures::stream::iter(assignee_ids(issues))
+            .map(|user_id| async move {
+                let endpoint = GetUser {
+                    id: Some(user_id),
+                    include: None,
+                };
+                self.json_response_body_optional::<_, UserWrapper<User>>(&endpoint)
+                    .await
+                    .map(|wrapper| wrapper.map(|UserWrapper { user }| (user_id, user)))
+            })
+            .buffer_unordered(MAX_CONCURRENT_REQUESTS)
+            .try_collect::<Vec<_>>()
+            .await?;
+        Ok(users.into_iter().flatten().collect())
+    }
+}
+
 #[cfg(test)]
-mod test {
+pub(crate) mod test {
     use super::*;
+    use crate::api::issues::test::issue_json;
     use pretty_assertions::assert_eq;
     use std::error::Error;
     use tokio::sync::RwLock;
@@ -474,7 +674,7 @@ mod test {
 
     /// needed so we do not get 404s when listing while
     /// creating/deleting or creating/updating/deleting
-    static USER_LOCK: RwLock<()> = RwLock::const_new(());
+    pub static USER_LOCK: RwLock<()> = RwLock::const_new(());
 
     #[traced_test]
     #[test]
@@ -487,6 +687,175 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_user_auth_source_id_deserialize() -> Result<(), Box<dyn Error>> {
+        let user: User = serde_json::from_value(serde_json::json!({
+            "id": 5,
+            "login": "ldapuser",
+            "admin": false,
+            "firstname": "LDAP",
+            "lastname": "User",
+            "auth_source_id": 2,
+            "created_on": "2024-03-01T12:00:00Z",
+            "updated_on": "2024-03-01T12:00:00Z",
+            "passwd_changed_on": null,
+            "last_login_on": null
+        }))?;
+        assert_eq!(user.auth_source_id, Some(2));
+        Ok(())
+    }
+
+    #[test]
+    fn test_list_users_group_id_parameter() -> Result<(), Box<dyn Error>> {
+        let endpoint = ListUsers::builder().group_id([3, 4]).build()?;
+        let mut url = url::Url::parse("https://redmine.example.com/users.json")?;
+        endpoint.parameters().add_to_url(&mut url);
+        assert_eq!(url.query(), Some("group_id=3%2C4"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_list_users_include_parameter() -> Result<(), Box<dyn Error>> {
+        let endpoint = ListUsers::builder()
+            .include(vec![UserInclude::Memberships, UserInclude::Groups])
+            .build()?;
+        let mut url = url::Url::parse("https://redmine.example.com/users.json")?;
+        endpoint.parameters().add_to_url(&mut url);
+        assert_eq!(url.query(), Some("include=memberships%2Cgroups"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_list_users_time_filter_parameters() -> Result<(), Box<dyn Error>> {
+        let endpoint = ListUsers::builder()
+            .created_on(DateTimeFilterPast::WithinPastDays(7))
+            .updated_on(DateTimeFilterPast::LastMonth)
+            .last_login_on(DateTimeFilterPast::None)
+            .build()?;
+        let mut url = url::Url::parse("https://redmine.example.com/users.json")?;
+        endpoint.parameters().add_to_url(&mut url);
+        assert_eq!(
+            url.query(),
+            Some("created_on=%3E%3Ct-7&updated_on=lm&last_login_on=%21*")
+        );
+        Ok(())
+    }
+
+    #[traced_test]
+    #[test]
+    fn test_list_users_updated_last_month() -> Result<(), Box<dyn Error>> {
+        let _r_user = USER_LOCK.read();
+        dotenvy::dotenv()?;
+        let redmine = crate::api::Redmine::from_env()?;
+        let endpoint = ListUsers::builder()
+            .updated_on(DateTimeFilterPast::LastMonth)
+            .build()?;
+        let page = redmine.json_response_body_page::<_, User>(&endpoint, 0, 25)?;
+        let start_of_this_month = time::OffsetDateTime::now_utc().date().replace_day(1)?;
+        let start_of_last_month = (start_of_this_month - time::Duration::DAY).replace_day(1)?;
+        for user in page.values {
+            let updated_on = user.updated_on.to_offset(time::UtcOffset::UTC).date();
+            assert!(
+                start_of_last_month <= updated_on && updated_on < start_of_this_month,
+                "user {} was updated on {}, not last month",
+                user.id,
+                updated_on
+            );
+        }
+        Ok(())
+    }
+
+    #[traced_test]
+    #[test]
+    fn test_list_users_include_groups() -> Result<(), Box<dyn Error>> {
+        let _r_user = USER_LOCK.read();
+        dotenvy::dotenv()?;
+        let redmine = crate::api::Redmine::from_env()?;
+        let endpoint = ListUsers::builder()
+            .include(vec![UserInclude::Groups])
+            .build()?;
+        let values = redmine.json_response_body_all_pages::<_, serde_json::Value>(&endpoint)?;
+        for value in values {
+            let o: User = serde_json::from_value(value.clone())?;
+            let reserialized = serde_json::to_value(o)?;
+            assert_eq!(value, reserialized);
+        }
+        Ok(())
+    }
+
+    #[traced_test]
+    #[test]
+    fn test_list_users_group_id_filter() -> Result<(), Box<dyn Error>> {
+        let _r_user = USER_LOCK.read();
+        dotenvy::dotenv()?;
+        let redmine = crate::api::Redmine::from_env()?;
+        let endpoint = ListUsers::builder()
+            .group_id(vec![1, 2])
+            .include(vec![UserInclude::Groups])
+            .build()?;
+        let page = redmine.json_response_body_page::<_, User>(&endpoint, 0, 25)?;
+        for user in page.values {
+            let groups = user.groups.unwrap_or_default();
+            assert!(
+                groups.iter().any(|g| g.id == 1 || g.id == 2),
+                "user {} is not a member of group 1 or 2",
+                user.id
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_list_users_login_parameter() -> Result<(), Box<dyn Error>> {
+        let mut url = url::Url::parse("https://redmine.example.com/users.json")?;
+        let endpoint = ListUsers::builder().login("adm").build()?;
+        endpoint.parameters().add_to_url(&mut url);
+        assert_eq!(url.query(), Some("login=%7Eadm"));
+        let mut url = url::Url::parse("https://redmine.example.com/users.json")?;
+        let endpoint = ListUsers::builder()
+            .login(StringFieldFilter::ExactMatch("admin".to_string()))
+            .build()?;
+        endpoint.parameters().add_to_url(&mut url);
+        assert_eq!(url.query(), Some("login=admin"));
+        Ok(())
+    }
+
+    #[traced_test]
+    #[test]
+    fn test_list_users_login_filter() -> Result<(), Box<dyn Error>> {
+        let _r_user = USER_LOCK.read();
+        dotenvy::dotenv()?;
+        let redmine = crate::api::Redmine::from_env()?;
+        let exact_endpoint = ListUsers::builder()
+            .login(StringFieldFilter::ExactMatch("admin".to_string()))
+            .build()?;
+        let exact = redmine.json_response_body_all_pages::<_, User>(&exact_endpoint)?;
+        assert!(exact.iter().all(|u| u.login == "admin"));
+        let substring_endpoint = ListUsers::builder().login("dmi").build()?;
+        let substring = redmine.json_response_body_all_pages::<_, User>(&substring_endpoint)?;
+        assert!(substring.iter().all(|u| u.login.contains("dmi")));
+        assert!(substring.len() >= exact.len());
+        Ok(())
+    }
+
+    #[traced_test]
+    #[test]
+    fn test_list_users_sorted() -> Result<(), Box<dyn Error>> {
+        let _r_user = USER_LOCK.read();
+        dotenvy::dotenv()?;
+        let redmine = crate::api::Redmine::from_env()?;
+        let endpoint = ListUsers::builder()
+            .sort(vec![SortByColumn::Reverse {
+                column_name: UserSortColumn::LastLoginOn,
+            }])
+            .build()?;
+        let mut url = url::Url::parse("https://redmine.example.com/users.json")?;
+        endpoint.parameters().add_to_url(&mut url);
+        assert_eq!(url.query(), Some("sort=last_login_on%3Adesc"));
+        redmine.json_response_body_page::<_, User>(&endpoint, 0, 25)?;
+        Ok(())
+    }
+
     #[traced_test]
     #[test]
     fn test_list_users_first_page() -> Result<(), Box<dyn Error>> {
@@ -509,6 +878,20 @@ mod test {
         Ok(())
     }
 
+    #[traced_test]
+    #[test]
+    fn test_list_users_all_pages_with_limit() -> Result<(), Box<dyn Error>> {
+        let _r_user = USER_LOCK.read();
+        dotenvy::dotenv()?;
+        let redmine = crate::api::Redmine::from_env()?;
+        let endpoint = ListUsers::builder().build()?;
+        let all_pages = redmine.json_response_body_all_pages::<_, User>(&endpoint)?;
+        let small_pages =
+            redmine.json_response_body_all_pages_with_limit::<_, User>(&endpoint, 10)?;
+        assert_eq!(all_pages.len(), small_pages.len());
+        Ok(())
+    }
+
     #[traced_test]
     #[test]
     fn test_get_user() -> Result<(), Box<dyn Error>> {
@@ -696,4 +1079,26 @@ mod test {
         }
         Ok(())
     }
+
+    #[test]
+    fn test_assignee_ids() -> Result<(), Box<dyn Error>> {
+        let issue = |id: u64, assigned_to: Option<u64>| {
+            let mut value = issue_json(id);
+            if let Some(assigned_to) = assigned_to {
+                value["assigned_to"] = serde_json::json!({ "id": assigned_to, "name": "Someone" });
+            }
+            serde_json::from_value::<Issue>(value)
+        };
+        let issues = vec![
+            issue(1, Some(5))?,
+            issue(2, None)?,
+            issue(3, Some(3))?,
+            issue(4, Some(5))?,
+        ];
+        assert_eq!(
+            assignee_ids(&issues).into_iter().collect::<Vec<_>>(),
+            vec![3, 5]
+        );
+        Ok(())
+    }
 }
@@ -77,10 +77,22 @@ pub struct Version {
     pub updated_on: time::OffsetDateTime,
     /// The title of the wiki page for this version
     #[serde(default)]
-    wiki_page_title: Option<String>,
+    pub wiki_page_title: Option<String>,
 }
 
 /// The endpoint for all versions in a Redmine project
+///
+/// Redmine does not filter versions on the server side and there is no
+/// parameter to control sharing, the response always contains
+///
+/// - the versions of the project itself
+/// - versions of other projects shared with it (e.g. versions of ancestor
+///   projects with [VersionSharing::Hierarchy] or [VersionSharing::Tree] and
+///   versions with [VersionSharing::System])
+/// - the versions of its subprojects
+///
+/// in any status. Use [Version::status], [Version::sharing] and
+/// [Version::project] to filter the result on the client side.
 #[derive(Debug, Clone, Builder)]
 #[builder(setter(strip_option))]
 pub struct ListVersions<'a> {
@@ -139,7 +151,7 @@ impl Endpoint for GetVersion {
 
 /// The status of a version restricts if issues can be assigned to this
 /// version and if assigned issues can be reopened
-#[derive(Debug, Clone, serde::Deserialize, Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Deserialize, Serialize)]
 #[serde(rename_all = "snake_case")]
 pub enum VersionStatus {
     /// no restrictions, default
@@ -151,7 +163,7 @@ pub enum VersionStatus {
 }
 
 /// Version sharing determines the cross-project visibility of the version
-#[derive(Debug, Clone, serde::Deserialize, Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Deserialize, Serialize)]
 #[serde(rename_all = "snake_case")]
 pub enum VersionSharing {
     /// default
@@ -188,11 +200,15 @@ pub struct CreateVersion<'a> {
     #[builder(default)]
     due_date: Option<time::Date>,
     /// Description of the version
-    #[builder(default)]
+    #[builder(default, setter(into))]
     description: Option<Cow<'a, str>>,
     /// The title of the wiki page for this version
-    #[builder(default)]
+    #[builder(default, setter(into))]
     wiki_page_title: Option<Cow<'a, str>>,
+    /// see [idempotency_key_header](crate::api::idempotency_key_header)
+    #[builder(default)]
+    #[serde(skip_serializing)]
+    idempotency_key: Option<reqwest::header::HeaderValue>,
 }
 
 impl ReturnsJsonResponse for CreateVersion<'_> {}
@@ -214,6 +230,10 @@ impl Endpoint for CreateVersion<'_> {
         format!("projects/{}/versions.json", self.project_id_or_name).into()
     }
 
+    fn headers(&self) -> reqwest::header::HeaderMap {
+        crate::api::idempotency_key_header(self.idempotency_key.as_ref())
+    }
+
     fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, crate::Error> {
         Ok(Some((
             "application/json",
@@ -245,10 +265,10 @@ pub struct UpdateVersion<'a> {
     #[builder(default)]
     due_date: Option<time::Date>,
     /// Description of the version
-    #[builder(default)]
+    #[builder(default, setter(into))]
     description: Option<Cow<'a, str>>,
     /// The title of the wiki page for this version
-    #[builder(default)]
+    #[builder(default, setter(into))]
     wiki_page_title: Option<Cow<'a, str>>,
 }
 
@@ -395,6 +415,85 @@ mod test {
         Ok(())
     }
 
+    #[function_name::named]
+    #[traced_test]
+    #[test]
+    fn test_delete_version() -> Result<(), Box<dyn Error>> {
+        let _w_versions = VERSION_LOCK.write();
+        let name = format!("unittest_{}", function_name!());
+        with_project(&name, |redmine, _, name| {
+            let create_endpoint = CreateVersion::builder()
+                .project_id_or_name(name)
+                .name("Test Version")
+                .status(VersionStatus::Locked)
+                .sharing(VersionSharing::Descendants)
+                .description("Test Version Description")
+                .build()?;
+            let VersionWrapper { version } =
+                redmine.json_response_body::<_, VersionWrapper<Version>>(&create_endpoint)?;
+            let delete_endpoint = super::DeleteVersion::builder().id(version.id).build()?;
+            redmine.ignore_response_body::<_>(&delete_endpoint)?;
+            let get_endpoint = GetVersion::builder().id(version.id).build()?;
+            let deleted =
+                redmine.json_response_body_optional::<_, VersionWrapper<Version>>(&get_endpoint)?;
+            assert!(deleted.is_none());
+            Ok(())
+        })?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_version_status_and_sharing_deserialize() -> Result<(), Box<dyn Error>> {
+        let status: VersionStatus = serde_json::from_str(r#""locked""#)?;
+        assert_eq!(status, VersionStatus::Locked);
+        let sharing: VersionSharing = serde_json::from_str(r#""hierarchy""#)?;
+        assert_eq!(sharing, VersionSharing::Hierarchy);
+        Ok(())
+    }
+
+    #[function_name::named]
+    #[traced_test]
+    #[test]
+    fn test_list_versions_status() -> Result<(), Box<dyn Error>> {
+        let _w_versions = VERSION_LOCK.write();
+        let name = format!("unittest_{}", function_name!());
+        with_project(&name, |redmine, project_id, name| {
+            for (version_name, status) in [
+                ("Open Version", VersionStatus::Open),
+                ("Closed Version", VersionStatus::Closed),
+            ] {
+                let create_endpoint = CreateVersion::builder()
+                    .project_id_or_name(name)
+                    .name(version_name)
+                    .status(status)
+                    .build()?;
+                redmine.json_response_body::<_, VersionWrapper<Version>>(&create_endpoint)?;
+            }
+            let endpoint = ListVersions::builder().project_id_or_name(name).build()?;
+            let VersionsWrapper { versions } =
+                redmine.json_response_body::<_, VersionsWrapper<Version>>(&endpoint)?;
+            let mut statuses = versions
+                .iter()
+                .filter(|v| v.project.id == project_id)
+                .map(|v| (v.name.as_str(), v.status, v.sharing))
+                .collect::<Vec<_>>();
+            statuses.sort_by_key(|(name, _, _)| *name);
+            assert_eq!(
+                statuses,
+                vec![
+                    (
+                        "Closed Version",
+                        VersionStatus::Closed,
+                        VersionSharing::None
+                    ),
+                    ("Open Version", VersionStatus::Open, VersionSharing::None),
+                ]
+            );
+            Ok(())
+        })?;
+        Ok(())
+    }
+
     /// this tests if any of the results contain a field we are not deserializing
     ///
     /// this will only catch fields we missed if they are part of the response but
@@ -2,9 +2,758 @@
 //!
 //! [Redmine Documentation](https://www.redmine.org/projects/redmine/wiki/Rest_WikiPages)
 //!
-//! - [ ] project specific wiki page endpoint
-//! - [ ] specific wiki page endpoint
-//! - [ ] specific wiki page old version endpoint
-//! - [ ] create or update wiki page endpoint
-//! - [ ] delete wiki page endpoint
-//! - [ ] attachments
+//! - [x] project specific wiki page endpoint
+//! - [x] specific wiki page endpoint
+//! - [x] specific wiki page old version endpoint
+//! - [x] create or update wiki page endpoint
+//! - [x] delete wiki page endpoint
+//! - [x] attachments
+//! - [x] client side page hierarchy (wiki_page_tree)
+
+use derive_builder::Builder;
+use reqwest::Method;
+use serde::Serialize;
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+use crate::api::attachments::Attachment;
+use crate::api::issues::UploadedAttachment;
+use crate::api::users::UserEssentials;
+use crate::api::{Endpoint, QueryParams, ReturnsJsonResponse};
+
+/// the parent of a wiki page, identified by its title
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct WikiPageParent {
+    /// the title of the parent page
+    pub title: String,
+}
+
+/// a minimal type for wiki pages as returned by the wiki page index
+/// ([ListProjectWikiPages])
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct WikiPageEssentials {
+    /// the title of the wiki page, this is also used in the URL
+    pub title: String,
+    /// the parent page
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent: Option<WikiPageParent>,
+    /// the current version of the wiki page
+    pub version: u64,
+    /// The time when this wiki page was created
+    #[serde(
+        serialize_with = "crate::api::serialize_rfc3339",
+        deserialize_with = "crate::api::deserialize_rfc3339"
+    )]
+    pub created_on: time::OffsetDateTime,
+    /// The time when this wiki page was last updated
+    #[serde(
+        serialize_with = "crate::api::serialize_rfc3339",
+        deserialize_with = "crate::api::deserialize_rfc3339"
+    )]
+    pub updated_on: time::OffsetDateTime,
+}
+
+/// a wiki page with its child pages, built by [wiki_page_tree]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WikiPageNode {
+    /// the wiki page
+    pub page: WikiPageEssentials,
+    /// the child pages (those whose parent is this page)
+    pub children: Vec<WikiPageNode>,
+}
+
+/// assembles the flat list of wiki pages returned by [ListProjectWikiPages]
+/// into a tree by matching parent titles to page titles
+///
+/// pages whose parent is not in the list become roots, if the parents form
+/// a cycle the page of the cycle which comes first in the list becomes a root.
+/// Roots and children keep the order of the input list.
+#[must_use]
+pub fn wiki_page_tree(pages: Vec<WikiPageEssentials>) -> Vec<WikiPageNode> {
+    let index_by_title: HashMap<&str, usize> = pages
+        .iter()
+        .enumerate()
+        .map(|(i, p)| (p.title.as_str(), i))
+        .collect();
+    let mut parents: Vec<Option<usize>> = pages
+        .iter()
+        .map(|p| {
+            p.parent
+                .as_ref()
+                .and_then(|parent| index_by_title.get(parent.title.as_str()).copied())
+        })
+        .collect();
+    break_wiki_page_cycles(&mut parents);
+    let mut children: Vec<Vec<usize>> = vec![Vec::new(); pages.len()];
+    let mut roots = Vec::new();
+    for (i, parent) in parents.iter().enumerate() {
+        match parent {
+            Some(parent) => children[*parent].push(i),
+            None => roots.push(i),
+        }
+    }
+    let mut pages: Vec<Option<WikiPageEssentials>> = pages.into_iter().map(Some).collect();
+    roots
+        .into_iter()
+        .filter_map(|root| build_wiki_page_node(root, &mut pages, &children))
+        .collect()
+}
+
+/// turns every cycle in the parent indices into a chain by removing the parent
+/// of the cycle member with the lowest index
+fn break_wiki_page_cycles(parents: &mut [Option<usize>]) {
+    /// the state of a page during the cycle detection
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    enum State {
+        /// not visited yet
+        Unvisited,
+        /// on the path currently being followed
+        OnPath,
+        /// visited and known not to be part of an unbroken cycle
+        Done,
+    }
+    let mut states = vec![State::Unvisited; parents.len()];
+    for start in 0..parents.len() {
+        let mut path = Vec::new();
+        let mut current = Some(start);
+        while let Some(i) = current {
+            match states[i] {
+                State::Done => break,
+                State::OnPath => {
+                    let cycle_start = path.iter().position(|p| *p == i).unwrap_or(0);
+                    if let Some(lowest) = path[cycle_start..].iter().min() {
+                        parents[*lowest] = None;
+                    }
+                    break;
+                }
+                State::Unvisited => {
+                    states[i] = State::OnPath;
+                    path.push(i);
+                    current = parents[i];
+                }
+            }
+        }
+        for i in path {
+            states[i] = State::Done;
+        }
+    }
+}
+
+/// recursively builds the node for the page at index i
+fn build_wiki_page_node(
+    i: usize,
+    pages: &mut [Option<WikiPageEssentials>],
+    children: &[Vec<usize>],
+) -> Option<WikiPageNode> {
+    let page = pages[i].take()?;
+    Some(WikiPageNode {
+        page,
+        children: children[i]
+            .iter()
+            .filter_map(|child| build_wiki_page_node(*child, pages, children))
+            .collect(),
+    })
+}
+
+/// a type for wiki pages to use as an API return type
+///
+/// alternatively you can use your own type limited to the fields you need
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct WikiPage {
+    /// the title of the wiki page, this is also used in the URL
+    pub title: String,
+    /// the parent page
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent: Option<WikiPageParent>,
+    /// the content of the wiki page
+    pub text: String,
+    /// the version of the wiki page
+    pub version: u64,
+    /// the author of this version of the wiki page
+    pub author: UserEssentials,
+    /// the comment for this version of the wiki page
+    pub comments: Option<String>,
+    /// The time when this wiki page was created
+    #[serde(
+        serialize_with = "crate::api::serialize_rfc3339",
+        deserialize_with = "crate::api::deserialize_rfc3339"
+    )]
+    pub created_on: time::OffsetDateTime,
+    /// The time when this version of the wiki page was created
+    #[serde(
+        serialize_with = "crate::api::serialize_rfc3339",
+        deserialize_with = "crate::api::deserialize_rfc3339"
+    )]
+    pub updated_on: time::OffsetDateTime,
+    /// attachments (only with include parameter)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attachments: Option<Vec<Attachment>>,
+}
+
+/// The endpoint for the index of all wiki pages in a Redmine project
+///
+/// Redmine does not paginate the wiki index, it always returns all pages
+/// and ignores offset and limit, so this is not [Pageable](crate::api::Pageable)
+///
+/// Projects without the wiki module return a 404 which is reported as
+/// [crate::Error::HttpErrorResponse] with the NOT_FOUND status (see
+/// [crate::Error::is_not_found] and
+/// [Redmine::json_response_body_optional](crate::api::Redmine::json_response_body_optional)),
+/// an empty wiki returns an empty list
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(strip_option))]
+pub struct ListProjectWikiPages<'a> {
+    /// project id or name as it appears in the URL
+    #[builder(setter(into))]
+    project_id_or_name: Cow<'a, str>,
+}
+
+impl ReturnsJsonResponse for ListProjectWikiPages<'_> {}
+
+impl<'a> ListProjectWikiPages<'a> {
+    /// Create a builder for the endpoint.
+    #[must_use]
+    pub fn builder() -> ListProjectWikiPagesBuilder<'a> {
+        ListProjectWikiPagesBuilder::default()
+    }
+}
+
+impl Endpoint for ListProjectWikiPages<'_> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/wiki/index.json", self.project_id_or_name).into()
+    }
+}
+
+/// The types of associated data which can be fetched along with a wiki page
+#[derive(Debug, Clone)]
+pub enum WikiPageInclude {
+    /// Wiki page attachments
+    Attachments,
+}
+
+impl std::fmt::Display for WikiPageInclude {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Attachments => {
+                write!(f, "attachments")
+            }
+        }
+    }
+}
+
+/// The endpoint for a specific Redmine wiki page
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(strip_option))]
+pub struct GetProjectWikiPage<'a> {
+    /// project id or name as it appears in the URL
+    #[builder(setter(into))]
+    project_id_or_name: Cow<'a, str>,
+    /// the title of the wiki page as it appears in the URL
+    #[builder(setter(into))]
+    title: Cow<'a, str>,
+    /// the types of associate data to include
+    #[builder(default)]
+    include: Option<Vec<WikiPageInclude>>,
+}
+
+impl ReturnsJsonResponse for GetProjectWikiPage<'_> {}
+
+impl<'a> GetProjectWikiPage<'a> {
+    /// Create a builder for the endpoint.
+    #[must_use]
+    pub fn builder() -> GetProjectWikiPageBuilder<'a> {
+        GetProjectWikiPageBuilder::default()
+    }
+}
+
+impl Endpoint for GetProjectWikiPage<'_> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/wiki/{}.json",
+            self.project_id_or_name, self.title
+        )
+        .into()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        params.push_opt("include", self.include.as_ref());
+        params
+    }
+}
+
+/// The endpoint for a specific old version of a Redmine wiki page
+///
+/// attachments belong to the wiki page, not to a version, so with the
+/// attachments include the current attachments of the page are returned
+/// for every version
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(strip_option))]
+pub struct GetProjectWikiPageVersion<'a> {
+    /// project id or name as it appears in the URL
+    #[builder(setter(into))]
+    project_id_or_name: Cow<'a, str>,
+    /// the title of the wiki page as it appears in the URL
+    #[builder(setter(into))]
+    title: Cow<'a, str>,
+    /// the version of the wiki page to retrieve
+    version: u64,
+    /// the types of associate data to include
+    #[builder(default)]
+    include: Option<Vec<WikiPageInclude>>,
+}
+
+impl ReturnsJsonResponse for GetProjectWikiPageVersion<'_> {}
+
+impl<'a> GetProjectWikiPageVersion<'a> {
+    /// Create a builder for the endpoint.
+    #[must_use]
+    pub fn builder() -> GetProjectWikiPageVersionBuilder<'a> {
+        GetProjectWikiPageVersionBuilder::default()
+    }
+}
+
+impl Endpoint for GetProjectWikiPageVersion<'_> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/wiki/{}/{}.json",
+            self.project_id_or_name, self.title, self.version
+        )
+        .into()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        params.push_opt("include", self.include.as_ref());
+        params
+    }
+}
+
+/// The endpoint to create or update a Redmine wiki page
+///
+/// Redmine only returns a response body (the new wiki page) if the page
+/// was created, use ignore_response_body when updating an existing page
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Clone, Builder, Serialize)]
+#[builder(setter(strip_option))]
+pub struct CreateOrUpdateProjectWikiPage<'a> {
+    /// project id or name as it appears in the URL
+    #[builder(setter(into))]
+    #[serde(skip_serializing)]
+    project_id_or_name: Cow<'a, str>,
+    /// the title of the wiki page as it appears in the URL
+    #[builder(setter(into))]
+    #[serde(skip_serializing)]
+    title: Cow<'a, str>,
+    /// the content of the wiki page
+    #[builder(setter(into))]
+    text: Cow<'a, str>,
+    /// the comment for this version of the wiki page
+    #[builder(default, setter(into))]
+    comments: Option<Cow<'a, str>>,
+    /// the version of the wiki page this update is based on, if set Redmine
+    /// will refuse the update if the page was changed in the meantime
+    #[builder(default)]
+    version: Option<u64>,
+    /// the title of the parent wiki page
+    #[builder(default, setter(into))]
+    parent_title: Option<Cow<'a, str>>,
+    /// attachments (files)
+    #[builder(default)]
+    uploads: Option<Vec<UploadedAttachment<'a>>>,
+}
+
+impl ReturnsJsonResponse for CreateOrUpdateProjectWikiPage<'_> {}
+
+impl<'a> CreateOrUpdateProjectWikiPage<'a> {
+    /// Create a builder for the endpoint.
+    #[must_use]
+    pub fn builder() -> CreateOrUpdateProjectWikiPageBuilder<'a> {
+        CreateOrUpdateProjectWikiPageBuilder::default()
+    }
+}
+
+impl Endpoint for CreateOrUpdateProjectWikiPage<'_> {
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/wiki/{}.json",
+            self.project_id_or_name, self.title
+        )
+        .into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, crate::Error> {
+        Ok(Some((
+            "application/json",
+            serde_json::to_vec(&WikiPageWrapper::<CreateOrUpdateProjectWikiPage> {
+                wiki_page: (*self).to_owned(),
+            })?,
+        )))
+    }
+}
+
+/// The endpoint to delete a Redmine wiki page
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(strip_option))]
+pub struct DeleteProjectWikiPage<'a> {
+    /// project id or name as it appears in the URL
+    #[builder(setter(into))]
+    project_id_or_name: Cow<'a, str>,
+    /// the title of the wiki page as it appears in the URL
+    #[builder(setter(into))]
+    title: Cow<'a, str>,
+}
+
+impl<'a> DeleteProjectWikiPage<'a> {
+    /// Create a builder for the endpoint.
+    #[must_use]
+    pub fn builder() -> DeleteProjectWikiPageBuilder<'a> {
+        DeleteProjectWikiPageBuilder::default()
+    }
+}
+
+impl Endpoint for DeleteProjectWikiPage<'_> {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/wiki/{}.json",
+            self.project_id_or_name, self.title
+        )
+        .into()
+    }
+}
+
+/// helper struct for outer layers with a wiki_pages field holding the inner data
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct WikiPagesWrapper<T> {
+    /// to parse JSON with wiki_pages key
+    pub wiki_pages: Vec<T>,
+}
+
+/// A lot of APIs in Redmine wrap their data in an extra layer, this is a
+/// helper struct for outer layers with a wiki_page field holding the inner data
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct WikiPageWrapper<T> {
+    /// to parse JSON with wiki_page key
+    pub wiki_page: T,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::api::projects::{test::PROJECT_LOCK, ListProjects, Project};
+    use crate::api::test_helpers::with_project;
+    use crate::api::uploads::{FileUploadToken, UploadFile, UploadWrapper};
+    use pretty_assertions::assert_eq;
+    use std::error::Error;
+    use tracing_test::traced_test;
+
+    /// a wiki page for the tree fixtures
+    fn page(title: &str, parent: Option<&str>) -> WikiPageEssentials {
+        WikiPageEssentials {
+            title: title.to_string(),
+            parent: parent.map(|title| WikiPageParent {
+                title: title.to_string(),
+            }),
+            version: 1,
+            created_on: time::OffsetDateTime::UNIX_EPOCH,
+            updated_on: time::OffsetDateTime::UNIX_EPOCH,
+        }
+    }
+
+    /// the titles of a tree as nested (title, children) tuples
+    fn titles(nodes: &[WikiPageNode]) -> Vec<(String, Vec<String>)> {
+        nodes
+            .iter()
+            .map(|n| {
+                (
+                    n.page.title.clone(),
+                    n.children.iter().map(|c| c.page.title.clone()).collect(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_wiki_page_tree() {
+        let tree = wiki_page_tree(vec![
+            page("Wiki", None),
+            page("Install", Some("Wiki")),
+            page("Linux", Some("Install")),
+            page("Usage", Some("Wiki")),
+            page("Orphan", Some("Deleted")),
+        ]);
+        assert_eq!(
+            titles(&tree),
+            vec![
+                (
+                    "Wiki".to_string(),
+                    vec!["Install".to_string(), "Usage".to_string()]
+                ),
+                ("Orphan".to_string(), vec![]),
+            ]
+        );
+        assert_eq!(
+            titles(&tree[0].children[0].children),
+            vec![("Linux".to_string(), vec![])]
+        );
+    }
+
+    #[test]
+    fn test_wiki_page_tree_breaks_cycles() {
+        let tree = wiki_page_tree(vec![
+            page("Wiki", None),
+            page("A", Some("C")),
+            page("B", Some("A")),
+            page("C", Some("B")),
+            page("Self", Some("Self")),
+        ]);
+        assert_eq!(
+            titles(&tree),
+            vec![
+                ("Wiki".to_string(), vec![]),
+                ("A".to_string(), vec!["B".to_string()]),
+                ("Self".to_string(), vec![]),
+            ]
+        );
+        assert_eq!(
+            titles(&tree[1].children[0].children),
+            vec![("C".to_string(), vec![])]
+        );
+    }
+
+    #[test]
+    fn test_create_or_update_project_wiki_page_body() -> Result<(), Box<dyn Error>> {
+        let endpoint = CreateOrUpdateProjectWikiPage::builder()
+            .project_id_or_name("sandbox")
+            .title("Test_Page")
+            .text("text")
+            .uploads(vec![UploadedAttachment {
+                token: "1.abc".into(),
+                filename: "README.md".into(),
+                description: None,
+                content_type: "text/markdown".into(),
+            }])
+            .build()?;
+        let (_, body) = endpoint.body()?.ok_or("no body")?;
+        let body: serde_json::Value = serde_json::from_slice(&body)?;
+        assert_eq!(
+            body,
+            serde_json::json!({
+                "wiki_page": {
+                    "text": "text",
+                    "uploads": [{
+                        "token": "1.abc",
+                        "filename": "README.md",
+                        "content_type": "text/markdown"
+                    }]
+                }
+            })
+        );
+        Ok(())
+    }
+
+    #[traced_test]
+    #[test]
+    fn test_list_project_wiki_pages_no_pagination() -> Result<(), Box<dyn Error>> {
+        dotenvy::dotenv()?;
+        let redmine = crate::api::Redmine::from_env()?;
+        let endpoint = ListProjectWikiPages::builder()
+            .project_id_or_name("sandbox")
+            .build()?;
+        redmine.json_response_body::<_, WikiPagesWrapper<WikiPageEssentials>>(&endpoint)?;
+        Ok(())
+    }
+
+    #[function_name::named]
+    #[traced_test]
+    #[test]
+    fn test_create_update_and_delete_project_wiki_page() -> Result<(), Box<dyn Error>> {
+        let name = format!("unittest_{}", function_name!());
+        with_project(&name, |redmine, _, name| {
+            let create_endpoint = CreateOrUpdateProjectWikiPage::builder()
+                .project_id_or_name(name)
+                .title("Test_Page")
+                .text("Created as part of unit test for redmine-api")
+                .build()?;
+            let WikiPageWrapper { wiki_page } =
+                redmine.json_response_body::<_, WikiPageWrapper<WikiPage>>(&create_endpoint)?;
+            assert_eq!(wiki_page.version, 1);
+            let update_endpoint = CreateOrUpdateProjectWikiPage::builder()
+                .project_id_or_name(name)
+                .title("Test_Page")
+                .text("Updated as part of unit test for redmine-api")
+                .comments("test update")
+                .version(wiki_page.version)
+                .build()?;
+            redmine.ignore_response_body::<_>(&update_endpoint)?;
+            let get_endpoint = GetProjectWikiPage::builder()
+                .project_id_or_name(name)
+                .title("Test_Page")
+                .build()?;
+            let WikiPageWrapper { wiki_page } =
+                redmine.json_response_body::<_, WikiPageWrapper<WikiPage>>(&get_endpoint)?;
+            assert_eq!(wiki_page.version, 2);
+            assert_eq!(
+                wiki_page.text,
+                "Updated as part of unit test for redmine-api"
+            );
+            let delete_endpoint = DeleteProjectWikiPage::builder()
+                .project_id_or_name(name)
+                .title("Test_Page")
+                .build()?;
+            redmine.ignore_response_body::<_>(&delete_endpoint)?;
+            Ok(())
+        })?;
+        Ok(())
+    }
+
+    #[function_name::named]
+    #[traced_test]
+    #[test]
+    fn test_create_project_wiki_page_with_attachment() -> Result<(), Box<dyn Error>> {
+        let name = format!("unittest_{}", function_name!());
+        with_project(&name, |redmine, _, name| {
+            let upload_endpoint = UploadFile::builder().file("README.md").build()?;
+            let UploadWrapper {
+                upload: FileUploadToken { token },
+            } = redmine
+                .json_response_body::<_, UploadWrapper<FileUploadToken>>(&upload_endpoint)?;
+            let create_endpoint = CreateOrUpdateProjectWikiPage::builder()
+                .project_id_or_name(name)
+                .title("Test_Page")
+                .text("Created as part of unit test for redmine-api")
+                .uploads(vec![UploadedAttachment {
+                    token: token.into(),
+                    filename: "README.md".into(),
+                    description: Some("Uploaded as part of unit test for redmine-api".into()),
+                    content_type: "text/markdown".into(),
+                }])
+                .build()?;
+            redmine.json_response_body::<_, WikiPageWrapper<WikiPage>>(&create_endpoint)?;
+            let get_endpoint = GetProjectWikiPage::builder()
+                .project_id_or_name(name)
+                .title("Test_Page")
+                .include(vec![WikiPageInclude::Attachments])
+                .build()?;
+            let WikiPageWrapper { wiki_page } =
+                redmine.json_response_body::<_, WikiPageWrapper<WikiPage>>(&get_endpoint)?;
+            let attachments = wiki_page.attachments.unwrap_or_default();
+            assert_eq!(attachments.len(), 1);
+            assert_eq!(attachments[0].filename, "README.md");
+            Ok(())
+        })?;
+        Ok(())
+    }
+
+    #[function_name::named]
+    #[traced_test]
+    #[test]
+    fn test_get_project_wiki_page_version_with_attachment() -> Result<(), Box<dyn Error>> {
+        let name = format!("unittest_{}", function_name!());
+        with_project(&name, |redmine, _, name| {
+            let upload_endpoint = UploadFile::builder().file("README.md").build()?;
+            let UploadWrapper {
+                upload: FileUploadToken { token },
+            } = redmine
+                .json_response_body::<_, UploadWrapper<FileUploadToken>>(&upload_endpoint)?;
+            let create_endpoint = CreateOrUpdateProjectWikiPage::builder()
+                .project_id_or_name(name)
+                .title("Test_Page")
+                .text("Created as part of unit test for redmine-api")
+                .uploads(vec![UploadedAttachment {
+                    token: token.into(),
+                    filename: "README.md".into(),
+                    description: Some("Uploaded as part of unit test for redmine-api".into()),
+                    content_type: "text/markdown".into(),
+                }])
+                .build()?;
+            redmine.json_response_body::<_, WikiPageWrapper<WikiPage>>(&create_endpoint)?;
+            let update_endpoint = CreateOrUpdateProjectWikiPage::builder()
+                .project_id_or_name(name)
+                .title("Test_Page")
+                .text("Updated as part of unit test for redmine-api")
+                .build()?;
+            redmine.ignore_response_body::<_>(&update_endpoint)?;
+            let get_endpoint = GetProjectWikiPageVersion::builder()
+                .project_id_or_name(name)
+                .title("Test_Page")
+                .version(1)
+                .include(vec![WikiPageInclude::Attachments])
+                .build()?;
+            let WikiPageWrapper { wiki_page: value } = redmine
+                .json_response_body::<_, WikiPageWrapper<serde_json::Value>>(&get_endpoint)?;
+            let wiki_page: WikiPage = serde_json::from_value(value.clone())?;
+            assert_eq!(value, serde_json::to_value(&wiki_page)?);
+            assert_eq!(wiki_page.version, 1);
+            assert_eq!(
+                wiki_page.text,
+                "Created as part of unit test for redmine-api"
+            );
+            let attachments = wiki_page.attachments.unwrap_or_default();
+            assert_eq!(attachments.len(), 1);
+            assert_eq!(attachments[0].filename, "README.md");
+            Ok(())
+        })?;
+        Ok(())
+    }
+
+    /// this tests if any of the results contain a field we are not deserializing
+    ///
+    /// this will only catch fields we missed if they are part of the response but
+    /// it is better than nothing
+    #[traced_test]
+    #[test]
+    fn test_completeness_wiki_page_type() -> Result<(), Box<dyn Error>> {
+        let _r_project = PROJECT_LOCK.read();
+        dotenvy::dotenv()?;
+        let redmine = crate::api::Redmine::from_env()?;
+        let projects_endpoint = ListProjects::builder().build()?;
+        let projects = redmine.json_response_body_all_pages::<_, Project>(&projects_endpoint)?;
+        for project in projects {
+            let list_endpoint = ListProjectWikiPages::builder()
+                .project_id_or_name(project.identifier.as_str())
+                .build()?;
+            let Some(WikiPagesWrapper { wiki_pages }) = redmine
+                .json_response_body_optional::<_, WikiPagesWrapper<WikiPageEssentials>>(
+                    &list_endpoint,
+                )?
+            else {
+                // project without the wiki module
+                continue;
+            };
+            for wiki_page in wiki_pages {
+                let endpoint = GetProjectWikiPage::builder()
+                    .project_id_or_name(project.identifier.as_str())
+                    .title(wiki_page.title)
+                    .include(vec![WikiPageInclude::Attachments])
+                    .build()?;
+                let WikiPageWrapper { wiki_page: value } = redmine
+                    .json_response_body::<_, WikiPageWrapper<serde_json::Value>>(&endpoint)?;
+                let o: WikiPage = serde_json::from_value(value.clone())?;
+                let reserialized = serde_json::to_value(o)?;
+                assert_eq!(value, reserialized);
+            }
+        }
+        Ok(())
+    }
+}
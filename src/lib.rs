@@ -48,6 +48,9 @@ pub enum Error {
     #[error("error when reading environment variables: {0}")]
     EnvyError(#[from] envy::Error),
     /// Response body was empty so we can not deserialize it as JSON
+    ///
+    /// use [api::Redmine::json_response_body_or_no_content] for endpoints
+    /// which can legitimately respond with 204 No Content
     #[error("empty response body with status: {0}")]
     EmptyResponseBody(reqwest::StatusCode),
     /// Response body was valid JSON but not an object
@@ -65,4 +68,112 @@ pub enum Error {
     /// Error reading a file we are supposed to upload
     #[error("Error when opening or reading file {0} to upload: {1}")]
     UploadFileError(std::path::PathBuf, std::io::Error),
+    /// Redmine returned an HTTP error status (4xx or 5xx, except for 429 which is
+    /// RateLimited and 4xx with a JSON list of error messages which is Validation)
+    #[error("HTTP error response with status {status}: {}", truncate_body(.body))]
+    HttpErrorResponse {
+        /// the HTTP status code
+        status: reqwest::StatusCode,
+        /// the response body (lossily converted to UTF-8)
+        body: String,
+        /// the error messages from a JSON body like {"errors": [...]}, empty if there were none
+        errors: RedmineErrors,
+    },
+    /// Redmine returned a client error status (usually 422 Unprocessable Entity
+    /// on create/update) with a JSON body like {"errors": [...]}
+    #[error("Redmine rejected the request with status {status}: {errors}")]
+    Validation {
+        /// the HTTP status code
+        status: reqwest::StatusCode,
+        /// the error messages from the response body
+        errors: RedmineErrors,
+    },
+    /// Redmine returned 429 Too Many Requests, retry_after is the delay requested
+    /// in the Retry-After header (either in seconds or as an HTTP date) if any
+    #[error("rate limited by Redmine, retry after: {retry_after:?}")]
+    RateLimited {
+        /// the delay after which the request can be retried
+        retry_after: Option<std::time::Duration>,
+    },
+    /// One of the requests in a batch (e.g. create_issues or add_issue_watchers) failed
+    #[error("request {index}{} in batch failed: {source}", batch_request_id(.id))]
+    BatchRequestFailed {
+        /// the index of the failed request in the batch
+        index: usize,
+        /// the id the failed request was about if the batch is over existing
+        /// objects (e.g. the user id in add_issue_watchers)
+        id: Option<u64>,
+        /// the error returned for that request
+        source: Box<Error>,
+    },
+    /// The issue can not transition to the requested status according to the
+    /// allowed statuses Redmine returned for it (or the status is not closed
+    /// when closing an issue or closed when reopening one)
+    #[error("issue {issue_id} can not transition to status {status_id}")]
+    IssueStatusTransitionNotAllowed {
+        /// the id of the issue
+        issue_id: u64,
+        /// the id of the requested status
+        status_id: u64,
+    },
+    /// No issue status has the requested name (e.g. in issue_status_filter)
+    #[error("no issue status with the name {0}")]
+    UnknownIssueStatus(String),
+    /// The content URL of an attachment points to a different origin than the
+    /// Redmine URL so we refuse to send the API key there
+    #[error("attachment content URL {0} is not on the Redmine origin")]
+    ForeignAttachmentUrl(url::Url),
+}
+
+impl Error {
+    /// returns true if Redmine responded with 404 Not Found, e.g. because
+    /// the requested object does not exist (anymore) or the module providing
+    /// it is disabled in the project
+    #[must_use]
+    pub fn is_not_found(&self) -> bool {
+        matches!(
+            self,
+            Self::HttpErrorResponse {
+                status: reqwest::StatusCode::NOT_FOUND,
+                ..
+            } | Self::Validation {
+                status: reqwest::StatusCode::NOT_FOUND,
+                ..
+            }
+        )
+    }
+}
+
+/// the id of a failed batch request for the Display output of
+/// [Error::BatchRequestFailed]
+fn batch_request_id(id: &Option<u64>) -> String {
+    id.map(|id| format!(" (id {})", id)).unwrap_or_default()
+}
+
+/// the maximum number of bytes of a response body shown in the Display output
+/// of [Error::HttpErrorResponse]
+const DISPLAYED_BODY_LENGTH: usize = 200;
+
+/// shortens a response body for the Display output of
+/// [Error::HttpErrorResponse], cutting at a character boundary
+fn truncate_body(body: &str) -> std::borrow::Cow<'_, str> {
+    if body.len() <= DISPLAYED_BODY_LENGTH {
+        return body.into();
+    }
+    let mut end = DISPLAYED_BODY_LENGTH;
+    while !body.is_char_boundary(end) {
+        end -= 1;
+    }
+    format!("{}...", &body[..end]).into()
+}
+
+/// the error messages Redmine returns in the body of some error responses
+/// (e.g. validation errors on create/update)
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
+pub struct RedmineErrors(pub Vec<String>);
+
+impl std::fmt::Display for RedmineErrors {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0.join("; "))
+    }
 }